@@ -1,7 +1,7 @@
 use std::{collections::HashMap, str::FromStr};
 
 use syn::{
-    ext::IdentExt, parenthesized, parse::Parse, parse_macro_input, punctuated::Punctuated, Attribute, Block, Ident, LitStr, PatType, Token, Type
+    braced, bracketed, ext::IdentExt, parenthesized, parse::Parse, parse_macro_input, punctuated::Punctuated, Attribute, Block, Ident, LitInt, LitStr, Pat, PatType, Token, Type
 };
 
 enum Method {
@@ -16,37 +16,382 @@ enum Method {
     Connect,
 }
 
+impl Method {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Method::Get => "GET",
+            Method::Post => "POST",
+            Method::Put => "PUT",
+            Method::Patch => "PATCH",
+            Method::Delete => "DELETE",
+            Method::Head => "HEAD",
+            Method::Options => "OPTIONS",
+            Method::Trace => "TRACE",
+            Method::Connect => "CONNECT",
+        }
+    }
+
+    /// The variant of the core crate's `Method`
+    fn variant(&self, krate: &syn::Path) -> proc_macro2::TokenStream {
+        let variant = match self {
+            Method::Get => quote::quote! { Get },
+            Method::Post => quote::quote! { Post },
+            Method::Put => quote::quote! { Put },
+            Method::Patch => quote::quote! { Patch },
+            Method::Delete => quote::quote! { Delete },
+            Method::Head => quote::quote! { Head },
+            Method::Options => quote::quote! { Options },
+            Method::Trace => quote::quote! { Trace },
+            Method::Connect => quote::quote! { Connect },
+        };
+        quote::quote! { #krate::Method::#variant }
+    }
+}
+
+enum HandlerKind {
+    Async,
+    WebSocket(Pat),
+    Sse,
+}
+
+enum Body {
+    Type(Box<Type>),
+    Multipart,
+    Stream,
+    Bytes,
+}
+
+enum Status {
+    Code(syn::LitInt),
+    Name(Ident),
+}
+
+impl Status {
+    /// The status as a `u16` constant expression
+    fn as_u16(&self, krate: &syn::Path) -> proc_macro2::TokenStream {
+        match self {
+            Status::Code(code) => quote::quote! { #code },
+            Status::Name(name) => {
+                quote::quote! { { #krate::__private::axum::http::StatusCode::#name.as_u16() } }
+            }
+        }
+    }
+}
+
+enum ErrorType {
+    GroupDefault,
+    Type(Box<Type>),
+    /// `error = anyhow`, answered by the `AnyhowError` of the core crate
+    Anyhow,
+}
+
+/// The crate used by the `validate` key
+enum Validation {
+    Validator,
+    /// `garde`, with the context of `garde(context)` or `garde(context = ...)`
+    Garde(Option<GardeContext>),
+}
+
+/// The validation context of `validate = garde(...)`
+enum GardeContext {
+    /// `garde(context)`, taken from the state with `FromRef`
+    State,
+    /// `garde(context = path::to::function)`, called with the state
+    Function(syn::Path),
+    /// `garde(context = ...)` with any other expression
+    Expr(Box<syn::Expr>),
+}
+
+impl Parse for GardeContext {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        if ident != "context" {
+            return Err(syn::Error::new(ident.span(), "Expected `context`"));
+        }
+        if !input.peek(syn::Token![=]) {
+            return Ok(GardeContext::State);
+        }
+
+        input.parse::<syn::Token![=]>()?;
+        Ok(match input.parse()? {
+            syn::Expr::Path(syn::ExprPath {
+                qself: None, path, ..
+            }) => GardeContext::Function(path),
+            expr => GardeContext::Expr(Box::new(expr)),
+        })
+    }
+}
+
+impl Validation {
+    /// The extractor wrapping the validated query and body
+    fn extractor(&self, krate: &syn::Path) -> proc_macro2::TokenStream {
+        match self {
+            Validation::Validator => quote::quote! { #krate::Valid },
+            Validation::Garde(None) => quote::quote! { #krate::Garde },
+            Validation::Garde(Some(_)) => quote::quote! { #krate::GardeWith },
+        }
+    }
+
+    /// The provider of the context of `GardeWith`, if not the default one
+    fn context_provider(&self, args: &Args) -> Option<Ident> {
+        match self {
+            Validation::Garde(Some(GardeContext::Function(_) | GardeContext::Expr(_))) => Some(
+                Ident::new(&format!("__garde_context_{}", args.name), args.name.span()),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Wraps an extractor pattern, or the expression building it, and its type
+    /// in the extractor
+    fn wrap(
+        &self,
+        args: &Args,
+        pat: proc_macro2::TokenStream,
+        ty: proc_macro2::TokenStream,
+    ) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+        let valid = self.extractor(&args.krate);
+        match self {
+            Validation::Garde(Some(_)) => {
+                let provider = match self.context_provider(args) {
+                    Some(provider) => quote::quote! { #provider },
+                    None => {
+                        let krate = &args.krate;
+                        quote::quote! { #krate::GardeStateContext }
+                    }
+                };
+                (
+                    quote::quote! { #valid(#pat, ::std::marker::PhantomData) },
+                    quote::quote! { #valid<#ty, #provider> },
+                )
+            }
+            _ => (
+                quote::quote! { #valid(#pat) },
+                quote::quote! { #valid<#ty> },
+            ),
+        }
+    }
+}
+
+enum CookieJar {
+    Plain,
+    Signed,
+    Private,
+}
+
+/// A `"Name": "value"` pair of the `response_headers` key, `append` adds the
+/// value instead of replacing existing ones (`"Name": append "value"`)
+struct ResponseHeader {
+    name: LitStr,
+    value: LitStr,
+    append: bool,
+}
+
+impl Parse for ResponseHeader {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let name: LitStr = input.parse()?;
+        if name.value().is_empty()
+            || !name
+                .value()
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b))
+        {
+            return Err(syn::Error::new(name.span(), "Invalid header name"));
+        }
+
+        input.parse::<Token![:]>()?;
+
+        let append = if input.peek(Ident) {
+            let mode: Ident = input.parse()?;
+            if mode != "append" {
+                return Err(syn::Error::new(
+                    mode.span(),
+                    format!("Unknown header mode {}, expected `append`", mode),
+                ));
+            }
+            true
+        } else {
+            false
+        };
+
+        let value: LitStr = input.parse()?;
+        if !value
+            .value()
+            .bytes()
+            .all(|b| b == b'\t' || (0x20..0x7f).contains(&b))
+        {
+            return Err(syn::Error::new(value.span(), "Invalid header value"));
+        }
+
+        Ok(ResponseHeader {
+            name,
+            value,
+            append,
+        })
+    }
+}
+
+/// A `name: Type` pair, used by keys that declare a list of bindings
+struct Binding {
+    name: Ident,
+    ty: Type,
+}
+
+impl Parse for Binding {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let ty = input.parse()?;
+        Ok(Binding { name, ty })
+    }
+}
+
+/// A parameter of a path, e.g. `{id:u64 as user_id}`
+struct PathParam {
+    /// The name of the capture in the URL, e.g. `id`
+    name: String,
+    /// The variable the value is bound to in the handler, e.g. `user_id`,
+    /// which is the name of the capture unless renamed with `as`
+    binding: Ident,
+    ty: Type,
+}
+
 struct Args {
     path: String,
-    path_params: HashMap<Ident, Type>,
+    #[cfg_attr(not(feature = "utoipa"), allow(dead_code))]
+    template: String,
+    path_params: Vec<PathParam>,
+    /// The path parameters captured by the paths the group is nested at,
+    /// extracted before those of the route
+    inherited: Vec<PathParam>,
     query_params: Option<Type>,
-    body_params: Option<Type>,
+    body_params: Option<Body>,
+    body_limit: Option<syn::Expr>,
+    request: bool,
+    status: Option<Status>,
+    response_headers: Vec<ResponseHeader>,
+    cache_control: Option<LitStr>,
+    compression: Option<syn::LitBool>,
+    summary: Option<LitStr>,
+    description: Option<LitStr>,
+    tags: Vec<LitStr>,
+    state: Option<Ident>,
+    extensions: Vec<Binding>,
+    headers: Vec<Binding>,
+    parts: Vec<Binding>,
+    /// The binding and the type of the connection info, `SocketAddr` by
+    /// default
+    connect_info: Option<(Ident, Option<Type>)>,
+    matched_path: Option<Ident>,
+    nested_path: Option<Ident>,
+    cookies: Option<(CookieJar, Ident)>,
+    handler_kind: HandlerKind,
+    /// Whether the block of the handler runs on the blocking pool
+    blocking: bool,
+    keep_alive: Option<syn::Expr>,
+    priority: Option<syn::Expr>,
+    static_name: Option<Ident>,
+    validate: Option<Validation>,
+    rejection: Option<Type>,
+    cfg: Option<syn::Meta>,
+    enabled_if: Option<syn::Path>,
+    deprecated: Option<LitStr>,
+    sunset: Option<LitStr>,
+    successor: Option<LitStr>,
+    versions: Vec<LitInt>,
+    aliases: Vec<LitStr>,
+    alias_templates: Vec<String>,
+    head: Option<Block>,
+    auto_head: bool,
+    guards: Vec<syn::Path>,
+    instrument: bool,
+    metrics: bool,
+    /// Whether the handler is annotated with `#[utoipa::path]`
+    #[cfg_attr(not(feature = "utoipa"), allow(dead_code))]
+    utoipa: bool,
+    /// Whether the route is registered as an aide operation
+    #[cfg_attr(not(feature = "aide"), allow(dead_code))]
+    aide: bool,
+    krate: syn::Path,
     parameters: Punctuated<PatType, Token![,]>,
     name: Ident,
     group: Type,
-    return_type: Type,
+    return_type: Option<Type>,
+    error: Option<ErrorType>,
     method: Method,
     handler_attributes: Vec<Attribute>,
-    handler: Block,
+    handler: Option<Block>,
+    handler_path: Option<syn::Path>,
 }
 
 impl Parse for Args {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let mut path = None;
-        let mut path_params = HashMap::new();
+        let mut template = String::new();
+        let mut path_params = Vec::new();
+        let mut inherited = Vec::new();
+        let mut path_span = proc_macro2::Span::call_site();
         let mut query_params = None;
         let mut body_params = None;
+        let mut body_limit = None;
+        let mut request = false;
+        let mut status = None;
+        let mut response_headers = Vec::new();
+        let mut cache_control = None;
+        let mut compression = None;
+        let mut summary = None;
+        let mut description = None;
+        let mut tags = Vec::new();
+        let mut state = None;
+        let mut extensions = Vec::new();
+        let mut headers = Vec::new();
+        let mut parts = Vec::new();
+        let mut connect_info = None;
+        let mut matched_path = None;
+        let mut nested_path = None;
+        let mut cookies = None;
+        let mut handler_kind = HandlerKind::Async;
+        let mut blocking = false;
+        let mut keep_alive = None;
+        let mut priority = None;
+        let mut static_name = None;
+        let mut validate = None;
+        let mut rejection = None;
+        let mut cfg = None;
+        let mut enabled_if = None;
+        let mut deprecated = None;
+        let mut sunset = None;
+        let mut successor: Option<LitStr> = None;
+        let mut versions = Vec::new();
+        let mut aliases = Vec::new();
+        let mut head = None;
+        let mut auto_head = true;
+        let mut guards = Vec::new();
+        let mut instrument = true;
+        let mut metrics = true;
+        let mut utoipa = true;
+        let mut aide = true;
+        let mut krate = None;
         let mut parameters: Punctuated<PatType, Token![,]> = Punctuated::new();
         let mut name = None;
         let mut return_type = None;
+        let mut error = None;
         let mut handler = None;
         let mut method = None;
         let mut group = None;
         let mut handler_attributes = Vec::new();
+        let mut handler_path: Option<syn::Path> = None;
+        // The span of each key, used to point errors at the offending key
+        let mut keys = HashMap::new();
 
         while !input.is_empty() {
-            if input.peek(Token![#]) || input.peek(Token![async]) {
-                if handler.is_some() {
+            let start = input.cursor();
+            if input.peek(Token![#])
+                || input.peek(Token![async])
+                || Self::peek_keyword(input, "ws")
+                || Self::peek_keyword(input, "sse")
+            {
+                if handler.is_some() || handler_path.is_some() {
                     return Err(syn::Error::new(
                         input.span(),
                         "Handler is already defined",
@@ -54,24 +399,55 @@ impl Parse for Args {
                 }
 
                 handler_attributes = input.call(Attribute::parse_outer)?;
-                input.parse::<Token![async]>()?;
 
-                name = Some(input.parse()?);
-                
-                if input.peek(syn::token::Paren) {
+                if Self::peek_keyword(input, "ws") {
+                    input.parse::<Ident>()?;
+
+                    name = Some(input.parse()?);
+
+                    // The parentheses hold the upgraded socket, not extra parameters
                     let content;
                     let _ = parenthesized!(content in input);
-                    parameters = Punctuated::parse_terminated(&content)?;
-                }
+                    handler_kind = HandlerKind::WebSocket(Pat::parse_single(&content)?);
+                } else if Self::peek_keyword(input, "sse") {
+                    input.parse::<Ident>()?;
+
+                    name = Some(input.parse()?);
+                    handler_kind = HandlerKind::Sse;
 
-                if input.peek(Token![->]) {
-                    input.parse::<Token![->]>()?;
-                    return_type = Some(input.parse()?);
+                    if input.peek(syn::token::Paren) {
+                        let content;
+                        let _ = parenthesized!(content in input);
+                        parameters = Punctuated::parse_terminated(&content)?;
+                    }
+                } else {
+                    input.parse::<Token![async]>()?;
+
+                    name = Some(input.parse()?);
+
+                    if input.peek(syn::token::Paren) {
+                        let content;
+                        let _ = parenthesized!(content in input);
+                        parameters = Punctuated::parse_terminated(&content)?;
+                    }
+
+                    if input.peek(Token![->]) {
+                        input.parse::<Token![->]>()?;
+                        return_type = Some(input.parse()?);
+                    }
                 }
+
                 handler = Some(input.parse()?);
             } else {
                 let ident: Ident = input.call(Ident::parse_any)?;
 
+                if keys.insert(ident.to_string(), ident.span()).is_some() {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        format!("Duplicate key `{}`", ident),
+                    ));
+                }
+
                 match ident.to_string().as_str() {
                     "method" => {
                         // Expects equal sign
@@ -90,7 +466,7 @@ impl Parse for Args {
                             "CONNECT" => method = Some(Method::Connect),
                             m => {
                                 return Err(syn::Error::new(
-                                    ident.span(),
+                                    method_ident.span(),
                                     format!("Unknown method {}", m),
                                 ));
                             }
@@ -107,282 +483,4227 @@ impl Parse for Args {
                         input.parse::<syn::Token![=]>()?;
 
                         let path_str: LitStr = input.parse()?;
-                        let (path_, path_params_) = Self::parse_path(path_str)?;
+                        path_span = path_str.span();
+                        let (path_, template_, path_params_) = Self::parse_path(path_str)?;
                         path = Some(path_);
+                        template = template_;
                         path_params = path_params_;
                     }
-                    "query" => {
+                    "aliases" => {
                         // Expects equal sign
                         input.parse::<syn::Token![=]>()?;
 
-                        query_params = Some(input.parse()?);
+                        let content;
+                        let _ = bracketed!(content in input);
+                        aliases = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?
+                            .into_iter()
+                            .collect();
                     }
-                    "body" => {
+                    "head" => {
                         // Expects equal sign
                         input.parse::<syn::Token![=]>()?;
 
-                        body_params = Some(input.parse()?);
+                        head = Some(input.parse()?);
                     }
-                    _ => {
-                        return Err(syn::Error::new(
-                            ident.span(),
-                            format!(
-                                "Unknown attribute '{}'. Allowed attributes are: 'method', 'group', 'path', 'query', 'body'.",
-                                ident
-                            ),
-                        ));
+                    "auto_head" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
+
+                        auto_head = input.parse::<syn::LitBool>()?.value;
                     }
-                }
-            }
+                    "require" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
 
-            if !input.is_empty() {
-                input.parse::<syn::Token![,]>()?;
-            }
-        }
+                        // Either a single guard or a list of guards
+                        if input.peek(syn::token::Bracket) {
+                            let content;
+                            let _ = bracketed!(content in input);
+                            guards =
+                                Punctuated::<syn::Path, Token![,]>::parse_terminated(&content)?
+                                    .into_iter()
+                                    .collect();
+                        } else {
+                            guards.push(input.parse()?);
+                        }
+                    }
+                    "instrument" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
 
-        if path.is_none() {
-            return Err(syn::Error::new(
-                proc_macro2::Span::call_site(),
-                "Missing path",
-            ));
-        }
+                        instrument = input.parse::<syn::LitBool>()?.value;
+                    }
+                    "metrics" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
 
-        if name.is_none() {
-            return Err(syn::Error::new(
-                proc_macro2::Span::call_site(),
-                "Missing name",
-            ));
-        }
+                        metrics = input.parse::<syn::LitBool>()?.value;
+                    }
+                    "query" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
 
-        if return_type.is_none() {
-            return Err(syn::Error::new(
-                proc_macro2::Span::call_site(),
-                "Missing return type",
-            ));
-        }
+                        query_params = Some(input.parse()?);
+                    }
+                    "body" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
 
-        if handler.is_none() {
-            return Err(syn::Error::new(
-                proc_macro2::Span::call_site(),
-                "Missing handler",
-            ));
-        }
+                        if Self::peek_value_keyword(input, "multipart") {
+                            input.parse::<Ident>()?;
+                            body_params = Some(Body::Multipart);
+                        } else if Self::peek_value_keyword(input, "stream") {
+                            input.parse::<Ident>()?;
+                            body_params = Some(Body::Stream);
+                        } else if Self::peek_value_keyword(input, "bytes") {
+                            input.parse::<Ident>()?;
+                            body_params = Some(Body::Bytes);
+                        } else {
+                            body_params = Some(Body::Type(Box::new(input.parse()?)));
+                        }
+                    }
+                    "body_limit" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
 
-        if method.is_none() {
-            return Err(syn::Error::new(
-                proc_macro2::Span::call_site(),
-                "Missing method",
-            ));
-        }
+                        body_limit = Some(input.parse()?);
+                    }
+                    "request" => {
+                        request = true;
+                    }
+                    "blocking" => {
+                        blocking = true;
+                    }
+                    "utoipa" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
 
-        if group.is_none() {
-            return Err(syn::Error::new(
-                proc_macro2::Span::call_site(),
-                "Missing group",
-            ));
-        }
+                        utoipa = input.parse::<syn::LitBool>()?.value;
+                    }
+                    "aide" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
 
-        Ok(Args {
-            name: name.unwrap(),
-            return_type: return_type.unwrap(),
-            group: group.unwrap(),
-            method: method.unwrap(),
-            handler_attributes,
-            handler: handler.unwrap(),
-            path: path.unwrap(),
-            path_params,
-            query_params,
-            body_params,
-            parameters,
-        })
-    }
-}
+                        aide = input.parse::<syn::LitBool>()?.value;
+                    }
+                    "state" => {
+                        // The binding name is optional and defaults to `state`
+                        if input.peek(syn::Token![=]) {
+                            input.parse::<syn::Token![=]>()?;
+                            state = Some(input.parse()?);
+                        } else {
+                            state = Some(ident);
+                        }
+                    }
+                    "validate" => {
+                        // The crate is optional and defaults to `validator`
+                        let validation = if input.peek(syn::Token![=]) {
+                            input.parse::<syn::Token![=]>()?;
+                            let krate: Ident = input.parse()?;
+                            match krate.to_string().as_str() {
+                                "validator" => Validation::Validator,
+                                "garde" if input.peek(syn::token::Paren) => {
+                                    let content;
+                                    let _ = parenthesized!(content in input);
+                                    Validation::Garde(Some(content.parse()?))
+                                }
+                                "garde" => Validation::Garde(None),
+                                k => {
+                                    return Err(syn::Error::new(
+                                        krate.span(),
+                                        format!(
+                                            "Unknown validation crate {}, expected `validator` or `garde`",
+                                            k
+                                        ),
+                                    ));
+                                }
+                            }
+                        } else {
+                            Validation::Validator
+                        };
+                        validate = Some((ident, validation));
+                    }
+                    "rejection" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
 
-#[derive(PartialEq)]
-enum ParsePathState {
-    Path,
-    PathParamName,
-    PathParamType,
-}
+                        rejection = Some(input.parse()?);
+                    }
+                    "extensions" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
 
-impl Args {
-    fn parse_path(literal: LitStr) -> syn::Result<(String, HashMap<Ident, Type>)> {
-        let path = literal.value();
-        let mut real_path = String::new();
-        let mut path_params = HashMap::new();
-        let mut state = ParsePathState::Path;
-        let mut current_name = String::new();
-        let mut current_type = String::new();
+                        let content;
+                        let _ = bracketed!(content in input);
+                        extensions = Punctuated::<Binding, Token![,]>::parse_terminated(&content)?
+                            .into_iter()
+                            .collect();
+                    }
+                    "inherit" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
 
-        for c in path.chars() {
-            match c {
-                '{' => {
-                    if state == ParsePathState::Path {
-                        state = ParsePathState::PathParamName;
-                    } else {
-                        return Err(syn::Error::new(
-                            literal.span(),
-                            "Expected one of character, `:` or `}`, found `{`",
-                        ));
+                        let content;
+                        let _ = bracketed!(content in input);
+                        inherited = Punctuated::<Binding, Token![,]>::parse_terminated(&content)?
+                            .into_iter()
+                            .map(|Binding { name, ty }| PathParam {
+                                name: name.to_string(),
+                                binding: name,
+                                ty,
+                            })
+                            .collect();
                     }
-                }
-                '}' => {
-                    if state == ParsePathState::PathParamType {
-                        let param_name = proc_macro2::TokenStream::from_str(&current_name)
-                            .map_err(|_| {
-                                syn::Error::new(literal.span(), "Invalid path parameter name")
-                            })?;
-                        let param_type = proc_macro2::TokenStream::from_str(&current_type)
-                            .map_err(|_| {
-                                syn::Error::new(literal.span(), "Invalid path parameter type")
-                            })?;
-                        path_params.insert(syn::parse2(param_name)?, syn::parse2(param_type)?);
-
-                        real_path.push(':');
-                        real_path.push_str(&current_name);
+                    "parts" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
 
-                        current_name = String::new();
-                        current_type = String::new();
-                        state = ParsePathState::Path;
-                    } else if state == ParsePathState::PathParamName {
-                        return Err(syn::Error::new(
-                            literal.span(),
-                            "Expected one of character or `:`, found `}`",
-                        ));
-                    } else {
-                        return Err(syn::Error::new(
-                            literal.span(),
-                            "Expected one of character or `{`, found `}`",
-                        ));
+                        let content;
+                        let _ = bracketed!(content in input);
+                        parts = Punctuated::<Binding, Token![,]>::parse_terminated(&content)?
+                            .into_iter()
+                            .collect();
                     }
-                }
-                ':' => {
-                    if state == ParsePathState::PathParamName {
-                        state = ParsePathState::PathParamType;
-                    } else if state != ParsePathState::Path {
-                        return Err(syn::Error::new(
-                            literal.span(),
-                            "Expected one of character or `{`, found `:`",
-                        ));
-                    } else {
-                        return Err(syn::Error::new(
-                            literal.span(),
-                            "Expected one of character or `}`, found `:`",
-                        ));
+                    "headers" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
+
+                        if input.peek(syn::token::Brace) {
+                            let content;
+                            let _ = braced!(content in input);
+                            headers = Punctuated::<Binding, Token![,]>::parse_terminated(&content)?
+                                .into_iter()
+                                .collect();
+                        } else {
+                            // A single type (e.g. `HeaderMap`) bound as `headers`
+                            headers = vec![Binding {
+                                name: ident,
+                                ty: input.parse()?,
+                            }];
+                        }
                     }
-                }
-                _ => match state {
-                    ParsePathState::Path => {
-                        real_path.push(c);
+                    "connect_info" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
+
+                        // The type is optional and defaults to `SocketAddr`
+                        let name: Ident = input.parse()?;
+                        let ty = if input.peek(Token![:]) {
+                            input.parse::<Token![:]>()?;
+                            Some(input.parse()?)
+                        } else {
+                            None
+                        };
+                        connect_info = Some((name, ty));
                     }
-                    ParsePathState::PathParamName => {
-                        current_name.push(c);
+                    "matched_path" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
+
+                        matched_path = Some(input.parse::<Ident>()?);
                     }
-                    ParsePathState::PathParamType => {
-                        current_type.push(c);
+                    "nested_path" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
+
+                        nested_path = Some(input.parse::<Ident>()?);
                     }
-                },
-            }
-        }
+                    "cookies" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
 
-        if state != ParsePathState::Path {
-            return Err(syn::Error::new(
-                literal.span(),
-                "Expected one of character or `}`, found EOF",
+                        let first = input.parse::<Ident>()?;
+                        if input.peek(Ident) {
+                            let kind = match first.to_string().as_str() {
+                                "signed" => CookieJar::Signed,
+                                "private" => CookieJar::Private,
+                                k => {
+                                    return Err(syn::Error::new(
+                                        first.span(),
+                                        format!(
+                                            "Unknown cookie jar kind {}, expected `signed` or `private`",
+                                            k
+                                        ),
+                                    ));
+                                }
+                            };
+                            cookies = Some((kind, input.parse()?));
+                        } else {
+                            cookies = Some((CookieJar::Plain, first));
+                        }
+                    }
+                    "error" => {
+                        // Without a type, the group's default error type is used
+                        if input.peek(syn::Token![=]) {
+                            input.parse::<syn::Token![=]>()?;
+                            let ty: Type = input.parse()?;
+                            error = Some(match &ty {
+                                Type::Path(path)
+                                    if path.qself.is_none() && path.path.is_ident("anyhow") =>
+                                {
+                                    ErrorType::Anyhow
+                                }
+                                _ => ErrorType::Type(Box::new(ty)),
+                            });
+                        } else {
+                            error = Some(ErrorType::GroupDefault);
+                        }
+                    }
+                    "status" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
+
+                        // Either a numeric code or a `StatusCode` constant name
+                        if input.peek(syn::LitInt) {
+                            let code = input.parse::<syn::LitInt>()?;
+                            if !(100..=999).contains(&code.base10_parse::<u16>()?) {
+                                return Err(syn::Error::new(
+                                    code.span(),
+                                    "Status codes must be between 100 and 999",
+                                ));
+                            }
+                            status = Some(Status::Code(code));
+                        } else {
+                            status = Some(Status::Name(input.parse()?));
+                        }
+                    }
+                    "response_headers" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
+
+                        let content;
+                        let _ = braced!(content in input);
+                        response_headers =
+                            Punctuated::<ResponseHeader, Token![,]>::parse_terminated(&content)?
+                                .into_iter()
+                                .collect();
+                    }
+                    "cache_control" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
+
+                        let value: LitStr = input.parse()?;
+                        if value.value().is_empty()
+                            || !value
+                                .value()
+                                .bytes()
+                                .all(|b| b == b'\t' || (0x20..0x7f).contains(&b))
+                        {
+                            return Err(syn::Error::new(value.span(), "Invalid header value"));
+                        }
+                        cache_control = Some(value);
+                    }
+                    "compression" => {
+                        if !cfg!(feature = "compression") {
+                            return Err(syn::Error::new(
+                                ident.span(),
+                                "`compression` requires the `compression` feature",
+                            ));
+                        }
+
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
+
+                        compression = Some(input.parse::<syn::LitBool>()?);
+                    }
+                    "summary" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
+
+                        summary = Some(input.parse()?);
+                    }
+                    "description" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
+
+                        description = Some(input.parse()?);
+                    }
+                    "tags" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
+
+                        let content;
+                        let _ = bracketed!(content in input);
+                        tags = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?
+                            .into_iter()
+                            .collect();
+                    }
+                    "keep_alive" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
+
+                        keep_alive = Some(input.parse()?);
+                    }
+                    "priority" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
+
+                        priority = Some(input.parse()?);
+                    }
+                    "cfg" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
+
+                        cfg = Some(input.parse()?);
+                    }
+                    "enabled_if" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
+
+                        enabled_if = Some(input.parse()?);
+                    }
+                    "deprecated" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
+
+                        let date: LitStr = input.parse()?;
+                        let (_, _, _, days) = Self::parse_date(&date)?;
+                        response_headers.push(ResponseHeader {
+                            name: LitStr::new("Deprecation", date.span()),
+                            value: LitStr::new(&format!("@{}", days * 86400), date.span()),
+                            append: false,
+                        });
+                        deprecated = Some(date);
+                    }
+                    "sunset" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
+
+                        let date: LitStr = input.parse()?;
+                        response_headers.push(ResponseHeader {
+                            name: LitStr::new("Sunset", date.span()),
+                            value: LitStr::new(&Self::http_date(&date)?, date.span()),
+                            append: false,
+                        });
+                        sunset = Some(date);
+                    }
+                    "successor" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
+
+                        let path: LitStr = input.parse()?;
+                        let value = path.value();
+                        if !value.bytes().all(|b| b.is_ascii_graphic() && b != b'>') {
+                            return Err(syn::Error::new(path.span(), "Invalid successor path"));
+                        }
+                        response_headers.push(ResponseHeader {
+                            name: LitStr::new("Link", path.span()),
+                            value: LitStr::new(
+                                &format!("<{}>; rel=\"successor-version\"", value),
+                                path.span(),
+                            ),
+                            append: true,
+                        });
+                        successor = Some(path);
+                    }
+                    "static_name" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
+
+                        static_name = Some(input.parse()?);
+                    }
+                    "versions" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
+
+                        versions = parse_versions(input)?;
+                    }
+                    "crate" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
+
+                        krate = Some(input.call(syn::Path::parse_mod_style)?);
+                    }
+                    "handler" => {
+                        // Expects equal sign
+                        input.parse::<syn::Token![=]>()?;
+
+                        if handler.is_some() || handler_path.is_some() {
+                            return Err(syn::Error::new(
+                                ident.span(),
+                                "Handler is already defined",
+                            ));
+                        }
+
+                        handler_path = Some(input.parse()?);
+                    }
+                    key => {
+                        if input.peek(syn::token::Paren)
+                            || input.peek(Token![->])
+                            || input.peek(syn::token::Brace)
+                        {
+                            return Err(syn::Error::new(
+                                ident.span(),
+                                format!(
+                                    "Expected `async`, `ws` or `sse` before the handler name `{}`",
+                                    key
+                                ),
+                            ));
+                        }
+
+                        let message = match Self::similar_key(key) {
+                            Some(similar) => {
+                                format!("Unknown key `{}`, did you mean `{}`?", key, similar)
+                            }
+                            None => format!(
+                                "Unknown key `{}`. Allowed keys are: {}.",
+                                key,
+                                KEYS.iter()
+                                    .map(|key| format!("`{}`", key))
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            ),
+                        };
+                        return Err(syn::Error::new(ident.span(), message));
+                    }
+                }
+            }
+
+            // The comma after the last item is optional
+            if !input.is_empty() {
+                if !input.peek(Token![,]) {
+                    return Err(syn::Error::new(
+                        Self::last_span(start, input),
+                        "expected `,` between route! items",
+                    ));
+                }
+                input.parse::<syn::Token![,]>()?;
+            }
+        }
+
+        // Missing keys are reported at the end of the invocation
+        let end = input.span();
+
+        if path.is_none() {
+            return Err(syn::Error::new(end, "Missing `path` key"));
+        }
+
+        if let Some(handler_path) = &handler_path {
+            if query_params.is_some()
+                || body_params.is_some()
+                || request
+                || state.is_some()
+                || !extensions.is_empty()
+                || !headers.is_empty()
+                || !parts.is_empty()
+                || connect_info.is_some()
+                || matched_path.is_some()
+                || nested_path.is_some()
+                || cookies.is_some()
+                || validate.is_some()
+                || rejection.is_some()
+            {
+                return Err(syn::Error::new(
+                    keys["handler"],
+                    "`handler` cannot be combined with `query`, `body`, `request`, `state`, `extensions`, `headers`, `parts`, `connect_info`, `matched_path`, `nested_path`, `cookies`, `validate` or `rejection`, the handler function declares its own extractors",
+                ));
+            }
+
+            if blocking {
+                return Err(syn::Error::new(
+                    keys["blocking"],
+                    "`blocking` cannot be combined with `handler`, the handler function decides where it runs",
+                ));
+            }
+
+            if let Some(span) = keys.get("utoipa")
+                && utoipa
+            {
+                return Err(syn::Error::new(
+                    *span,
+                    "`utoipa = true` cannot be combined with `handler`, annotate the handler function with `#[utoipa::path]` instead",
+                ));
+            }
+
+            // The route is named after the last segment of the handler path
+            name = handler_path.segments.last().map(|s| s.ident.clone());
+        } else {
+            if name.is_none() || handler.is_none() {
+                return Err(syn::Error::new(end, "Missing handler"));
+            }
+
+            if matches!(handler_kind, HandlerKind::WebSocket(_)) && body_params.is_some() {
+                return Err(syn::Error::new(
+                    keys["body"],
+                    "WebSocket routes cannot have a body",
+                ));
+            }
+
+            if matches!(handler_kind, HandlerKind::WebSocket(_)) && request {
+                return Err(syn::Error::new(
+                    keys["request"],
+                    "WebSocket routes cannot take the request",
+                ));
+            }
+
+            if request && body_params.is_some() {
+                return Err(syn::Error::new(
+                    keys["request"],
+                    "`request` cannot be combined with `body`, the body is part of the request and can only be extracted once, read it from the request instead (e.g. with `axum::body::to_bytes`)",
+                ));
+            }
+
+            if error.is_some() && !matches!(handler_kind, HandlerKind::Async) {
+                return Err(syn::Error::new(
+                    keys["error"],
+                    "`error` is only allowed on `async` routes",
+                ));
+            }
+
+            if blocking && !matches!(handler_kind, HandlerKind::Async) {
+                return Err(syn::Error::new(
+                    keys["blocking"],
+                    "`blocking` is only allowed on `async` routes",
+                ));
+            }
+
+            // Compressing buffers the events or frames until a block fills up
+            if compression
+                .as_ref()
+                .is_some_and(|compression| compression.value)
+                && !matches!(handler_kind, HandlerKind::Async)
+            {
+                return Err(syn::Error::new(
+                    keys["compression"],
+                    "`compression = true` is only allowed on `async` routes, the responses of `sse` and `ws` routes are streamed",
+                ));
+            }
+
+            if keep_alive.is_some() && !matches!(handler_kind, HandlerKind::Sse) {
+                return Err(syn::Error::new(
+                    keys["keep_alive"],
+                    "`keep_alive` is only allowed on `sse` routes",
+                ));
+            }
+        }
+
+        if let Some((validate, _)) = &validate
+            && matches!(&body_params, Some(Body::Type(ty)) if unwrap_type(ty, "Option").is_some())
+        {
+            return Err(syn::Error::new(
+                validate.span(),
+                "`validate` cannot be combined with an optional body",
+            ));
+        }
+
+        // Only the last extractor can consume the body, which is where `body`
+        // and `request` are generated
+        let body_key = if body_params.is_some() {
+            Some("body")
+        } else if request {
+            Some("request")
+        } else {
+            None
+        };
+        for (i, parameter) in parameters.iter().enumerate() {
+            let Some(extractor) = body_extractor(&parameter.ty) else {
+                continue;
+            };
+            if let Some(key) = body_key {
+                let mut err = syn::Error::new_spanned(
+                    &parameter.ty,
+                    format!(
+                        "`{}` consumes the request body, which is already extracted by `{}`",
+                        extractor, key
+                    ),
+                );
+                err.combine(syn::Error::new(
+                    keys[key],
+                    format!("the body is extracted by `{}` here", key),
+                ));
+                return Err(err);
+            }
+            if i + 1 != parameters.len() {
+                return Err(syn::Error::new_spanned(
+                    &parameter.ty,
+                    format!(
+                        "`{}` consumes the request body, it must be the last parameter",
+                        extractor
+                    ),
+                ));
+            }
+        }
+
+        if let Some((validate, _)) = &validate
+            && query_params.is_none()
+            && !matches!(body_params, Some(Body::Type(_)))
+        {
+            return Err(syn::Error::new(
+                validate.span(),
+                "`validate` requires a `query` or a `body` type",
+            ));
+        }
+
+        if deprecated.is_none() {
+            for key in ["sunset", "successor"] {
+                if let Some(span) = keys.get(key) {
+                    return Err(syn::Error::new(
+                        *span,
+                        format!("`{}` requires the `deprecated` key", key),
+                    ));
+                }
+            }
+        }
+
+        if method.is_none() {
+            return Err(syn::Error::new(end, "Missing `method` key"));
+        }
+
+        // axum answers HEAD requests with the GET handler of the path
+        for key in ["head", "auto_head"] {
+            if let Some(span) = keys.get(key)
+                && !matches!(method, Some(Method::Get))
+            {
+                return Err(syn::Error::new(
+                    *span,
+                    format!("`{}` requires `method = GET`", key),
+                ));
+            }
+        }
+        if let Some(span) = keys.get("head")
+            && !auto_head
+        {
+            return Err(syn::Error::new(
+                *span,
+                "`head` cannot be used with `auto_head = false`",
+            ));
+        }
+
+        if group.is_none() {
+            return Err(syn::Error::new(end, "Missing `group` key"));
+        }
+
+        let mut parameter_bindings = Vec::new();
+        for parameter in &parameters {
+            pat_bindings(&parameter.pat, &mut parameter_bindings)?;
+        }
+
+        // The path parameters are bound next to the other extractors of the
+        // handler
+        let bindings = query_params
+            .iter()
+            .map(|_| ("query".to_owned(), "the `query` key"))
+            .chain(
+                body_params
+                    .iter()
+                    .map(|_| ("body".to_owned(), "the `body` key")),
+            )
+            .chain(request.then(|| ("request".to_owned(), "the `request` key")))
+            .chain(
+                state
+                    .iter()
+                    .map(|state| (state.to_string(), "the `state` key")),
+            )
+            .chain(
+                extensions
+                    .iter()
+                    .map(|Binding { name, .. }| (name.to_string(), "the `extensions` key")),
+            )
+            .chain(
+                headers
+                    .iter()
+                    .map(|Binding { name, .. }| (name.to_string(), "the `headers` key")),
+            )
+            .chain(
+                parts
+                    .iter()
+                    .map(|Binding { name, .. }| (name.to_string(), "the `parts` key")),
+            )
+            .chain(
+                connect_info
+                    .iter()
+                    .map(|(name, _)| (name.to_string(), "the `connect_info` key")),
+            )
+            .chain(
+                matched_path
+                    .iter()
+                    .map(|name| (name.to_string(), "the `matched_path` key")),
+            )
+            .chain(
+                nested_path
+                    .iter()
+                    .map(|name| (name.to_string(), "the `nested_path` key")),
+            )
+            .chain(
+                cookies
+                    .iter()
+                    .map(|(_, name)| (name.to_string(), "the `cookies` key")),
+            )
+            .chain(
+                parameter_bindings
+                    .iter()
+                    .map(|ident| (ident.to_string(), "a parameter of the handler")),
+            );
+        for (binding, origin) in bindings {
+            if path_params.iter().any(|param| param.binding == binding) {
+                return Err(syn::Error::new(
+                    path_span,
+                    format!(
+                        "The path parameter `{}` is shadowed by the binding of {}",
+                        binding, origin
+                    ),
+                ));
+            }
+            if inherited.iter().any(|param| param.binding == binding) {
+                return Err(syn::Error::new(
+                    keys["inherit"],
+                    format!(
+                        "The inherited path parameter `{}` is shadowed by the binding of {}",
+                        binding, origin
+                    ),
+                ));
+            }
+        }
+
+        // The inherited captures and those of the route are extracted together
+        for (i, param) in inherited.iter().enumerate() {
+            if inherited[..i]
+                .iter()
+                .any(|other| other.binding == param.binding)
+            {
+                return Err(syn::Error::new(
+                    param.binding.span(),
+                    format!("Duplicate inherited path parameter `{}`", param.binding),
+                ));
+            }
+            if path_params
+                .iter()
+                .any(|own| own.name == param.name || own.binding == param.binding)
+            {
+                return Err(syn::Error::new(
+                    param.binding.span(),
+                    format!(
+                        "The inherited path parameter `{}` collides with a path parameter of the route",
+                        param.binding
+                    ),
+                ));
+            }
+        }
+
+        // Aliases are extracted with the handler of the primary path, so their
+        // captures must be the same, in the same order
+        let mut alias_templates = Vec::new();
+        if path.is_some() {
+            let captures = Self::path_captures(&path_params);
+            for alias in &aliases {
+                let (_, alias_template, alias_params) = Self::parse_path(alias.clone())?;
+                if Self::path_captures(&alias_params) != captures {
+                    return Err(syn::Error::new(
+                        alias.span(),
+                        format!(
+                            "The path parameters of an alias must match those of `path`: {{{}}}",
+                            captures.join("}, {")
+                        ),
+                    ));
+                }
+                alias_templates.push(alias_template);
+            }
+        }
+
+        if let Some(span) = keys.get("versions")
+            && !matches!(&group, Some(Type::Path(path)) if path.qself.is_none())
+        {
+            return Err(syn::Error::new(
+                *span,
+                "`versions` requires the group to be a path, e.g. `group = Api`",
+            ));
+        }
+
+        Ok(Args {
+            name: name.unwrap(),
+            return_type,
+            error,
+            group: group.unwrap(),
+            method: method.unwrap(),
+            handler_attributes,
+            handler,
+            handler_path,
+            path: path.unwrap(),
+            template,
+            path_params,
+            inherited,
+            query_params,
+            body_params,
+            body_limit,
+            request,
+            status,
+            response_headers,
+            cache_control,
+            compression,
+            summary,
+            description,
+            tags,
+            state,
+            extensions,
+            headers,
+            parts,
+            connect_info,
+            matched_path,
+            nested_path,
+            cookies,
+            handler_kind,
+            blocking,
+            keep_alive,
+            priority,
+            static_name,
+            validate: validate.map(|(_, validation)| validation),
+            rejection,
+            cfg,
+            enabled_if,
+            deprecated,
+            sunset,
+            successor,
+            versions,
+            aliases,
+            alias_templates,
+            head,
+            auto_head,
+            guards,
+            instrument,
+            metrics,
+            utoipa,
+            aide,
+            krate: krate.unwrap_or_else(|| syn::parse_quote! { axum_distributed_routing }),
+            parameters,
+        })
+    }
+}
+
+/// The keys accepted by `route!`
+const KEYS: &[&str] = &[
+    "method",
+    "group",
+    "path",
+    "inherit",
+    "query",
+    "body",
+    "body_limit",
+    "request",
+    "blocking",
+    "state",
+    "extensions",
+    "headers",
+    "parts",
+    "connect_info",
+    "matched_path",
+    "nested_path",
+    "cookies",
+    "error",
+    "status",
+    "response_headers",
+    "cache_control",
+    "compression",
+    "summary",
+    "description",
+    "tags",
+    "keep_alive",
+    "priority",
+    "static_name",
+    "validate",
+    "rejection",
+    "cfg",
+    "enabled_if",
+    "deprecated",
+    "sunset",
+    "successor",
+    "versions",
+    "aliases",
+    "head",
+    "auto_head",
+    "require",
+    "instrument",
+    "metrics",
+    "utoipa",
+    "aide",
+    "crate",
+    "handler",
+];
+
+impl Args {
+    /// The path parameters extracted by the handler, the inherited ones first
+    fn all_path_params(&self) -> impl Iterator<Item = &PathParam> {
+        self.inherited.iter().chain(&self.path_params)
+    }
+
+    /// The known key closest to an unknown one, if it is close enough to be a
+    /// typo (at most 2 edits)
+    fn similar_key(key: &str) -> Option<&'static str> {
+        KEYS.iter()
+            .map(|known| (edit_distance(key, known), *known))
+            .filter(|(distance, _)| *distance <= 2)
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, known)| known)
+    }
+
+    /// Whether the input starts a handler introduced by `keyword` (e.g.
+    /// `ws name(socket) { ... }`), as opposed to a `key = value` pair
+    fn peek_keyword(input: syn::parse::ParseStream, keyword: &str) -> bool {
+        input.peek(Ident)
+            && input.peek2(Ident)
+            && input
+                .fork()
+                .parse::<Ident>()
+                .is_ok_and(|ident| ident == keyword)
+    }
+
+    /// Parses a `YYYY-MM-DD` date into its year, month, day and number of days
+    /// since the Unix epoch
+    fn parse_date(literal: &LitStr) -> syn::Result<(i64, usize, u32, i64)> {
+        let error = || syn::Error::new(literal.span(), "Expected a `YYYY-MM-DD` date");
+
+        let value = literal.value();
+        let parts = value.split('-').collect::<Vec<_>>();
+        let [year, month, day] = parts[..] else {
+            return Err(error());
+        };
+        if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+            return Err(error());
+        }
+        let (Ok(year), Ok(month), Ok(day)) = (
+            year.parse::<i64>(),
+            month.parse::<usize>(),
+            day.parse::<u32>(),
+        ) else {
+            return Err(error());
+        };
+
+        let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+        let february = if leap { 29 } else { 28 };
+        let month_days = [31, february, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+        if !(1..=12).contains(&month) || day == 0 || day > month_days[month - 1] {
+            return Err(error());
+        }
+
+        // Days from the civil calendar, starting the year in March so that
+        // leap days come last
+        let y = if month <= 2 { year - 1 } else { year };
+        let era = y.div_euclid(400);
+        let year_of_era = y - era * 400;
+        let day_of_year = (153 * ((month as i64 + 9) % 12) + 2) / 5 + day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        Ok((year, month, day, era * 146097 + day_of_era - 719468))
+    }
+
+    /// Formats a `YYYY-MM-DD` date as an HTTP date, at midnight
+    fn http_date(literal: &LitStr) -> syn::Result<String> {
+        const WEEKDAYS: [&str; 7] = ["Thu", "Fri", "Sat", "Sun", "Mon", "Tue", "Wed"];
+        const MONTHS: [&str; 12] = [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ];
+
+        let (year, month, day, days) = Self::parse_date(literal)?;
+        Ok(format!(
+            "{}, {:02} {} {} 00:00:00 GMT",
+            WEEKDAYS[days.rem_euclid(7) as usize],
+            day,
+            MONTHS[month - 1],
+            year
+        ))
+    }
+
+    /// The span of the last token parsed since `start`, i.e. the end of the
+    /// previous item
+    fn last_span(start: syn::buffer::Cursor, input: syn::parse::ParseStream) -> proc_macro2::Span {
+        let (mut cursor, mut span) = (start, input.span());
+        while cursor != input.cursor() {
+            let Some((token, next)) = cursor.token_tree() else {
+                break;
+            };
+            (cursor, span) = (next, token.span());
+        }
+        span
+    }
+
+    /// Whether the input is exactly the value `keyword` (e.g. `body = multipart`),
+    /// as opposed to a type
+    fn peek_value_keyword(input: syn::parse::ParseStream, keyword: &str) -> bool {
+        let fork = input.fork();
+        fork.parse::<Ident>().is_ok_and(|ident| ident == keyword)
+            && (fork.is_empty() || fork.peek(Token![,]))
+    }
+}
+
+#[derive(PartialEq)]
+enum ParsePathState {
+    Path,
+    PathParamName,
+    PathParamType,
+}
+
+impl Args {
+    /// Parses a path, returning it along with its template (the path without
+    /// the parameter types, e.g. `/users/{id}`) and its parameters
+    fn parse_path(literal: LitStr) -> syn::Result<(String, String, Vec<PathParam>)> {
+        let path = literal.value();
+        let mut real_path = String::new();
+        let mut path_params: Vec<PathParam> = Vec::new();
+        let mut state = ParsePathState::Path;
+        let mut current_name = String::new();
+        let mut current_type = String::new();
+        let mut param_start = 0;
+
+        if !path.starts_with('/') {
+            return Err(syn::Error::new(
+                literal.span(),
+                "The path must start with `/`",
+            ));
+        }
+
+        for (i, c) in path.char_indices() {
+            let span = || Self::path_span(&literal, i..i + c.len_utf8());
+            match c {
+                // Captures are checked when their name and type are parsed
+                _ if state != ParsePathState::Path => {}
+                '/' if path[..i].ends_with('/') => {
+                    return Err(syn::Error::new(
+                        Self::path_span(&literal, i - 1..i + 1),
+                        "Empty path segment",
+                    ));
+                }
+                '?' | '#' => {
+                    return Err(syn::Error::new(
+                        span(),
+                        format!(
+                            "`{}` is not allowed in a path, the path must not contain a query or a fragment",
+                            c
+                        ),
+                    ));
+                }
+                _ if c.is_whitespace() => {
+                    return Err(syn::Error::new(
+                        span(),
+                        "Whitespace is not allowed in a path",
+                    ));
+                }
+                _ => {}
+            }
+            match c {
+                '{' => {
+                    if state == ParsePathState::Path {
+                        state = ParsePathState::PathParamName;
+                        param_start = i;
+                    } else {
+                        return Err(syn::Error::new(
+                            span(),
+                            "Expected one of character, `:` or `}`, found `{`",
+                        ));
+                    }
+                }
+                '}' => {
+                    if state == ParsePathState::PathParamType {
+                        let param_span = Self::path_span(&literal, param_start..i + 1);
+                        // `Type` or `Type as binding`
+                        let parse_type = |input: syn::parse::ParseStream| {
+                            let ty: Type = input.parse()?;
+                            let binding = if input.parse::<Option<Token![as]>>()?.is_some() {
+                                Some(input.parse::<Ident>()?)
+                            } else {
+                                None
+                            };
+                            Ok((ty, binding))
+                        };
+                        let (param_type, binding) =
+                            proc_macro2::TokenStream::from_str(&current_type)
+                                .ok()
+                                .and_then(|ty| syn::parse::Parser::parse2(parse_type, ty).ok())
+                                .ok_or_else(|| {
+                                    syn::Error::new(param_span, "Invalid path parameter type")
+                                })?;
+                        let binding = match binding {
+                            Some(binding) => {
+                                if current_name.is_empty()
+                                    || current_name.contains(|c: char| c == '/' || c.is_whitespace())
+                                {
+                                    return Err(syn::Error::new(
+                                        param_span,
+                                        "Invalid path parameter name",
+                                    ));
+                                }
+                                binding
+                            }
+                            None => proc_macro2::TokenStream::from_str(&current_name)
+                                .ok()
+                                .and_then(|name| syn::parse2(name).ok())
+                                .ok_or_else(|| {
+                                    syn::Error::new(
+                                        param_span,
+                                        format!(
+                                            "Invalid path parameter name, bind it to a valid identifier with `{{{}:{} as name}}`",
+                                            current_name, current_type
+                                        ),
+                                    )
+                                })?,
+                        };
+                        if path_params.iter().any(|param| param.name == current_name) {
+                            return Err(syn::Error::new(
+                                param_span,
+                                format!("Duplicate path parameter `{}`", current_name),
+                            ));
+                        }
+                        if path_params.iter().any(|param| param.binding == binding) {
+                            return Err(syn::Error::new(
+                                param_span,
+                                format!("Duplicate path parameter binding `{}`", binding),
+                            ));
+                        }
+                        path_params.push(PathParam {
+                            name: current_name.clone(),
+                            binding,
+                            ty: param_type,
+                        });
+
+                        real_path.push('{');
+                        real_path.push_str(&current_name);
+                        real_path.push('}');
+
+                        current_name = String::new();
+                        current_type = String::new();
+                        state = ParsePathState::Path;
+                    } else if state == ParsePathState::PathParamName {
+                        return Err(syn::Error::new(
+                            span(),
+                            "Expected one of character or `:`, found `}`",
+                        ));
+                    } else {
+                        return Err(syn::Error::new(
+                            span(),
+                            "Expected one of character or `{`, found `}`",
+                        ));
+                    }
+                }
+                ':' => {
+                    if state == ParsePathState::PathParamName {
+                        state = ParsePathState::PathParamType;
+                    } else if state != ParsePathState::Path {
+                        return Err(syn::Error::new(
+                            span(),
+                            "Expected one of character or `{`, found `:`",
+                        ));
+                    } else {
+                        return Err(syn::Error::new(
+                            span(),
+                            "Expected one of character or `}`, found `:`",
+                        ));
+                    }
+                }
+                _ => match state {
+                    ParsePathState::Path => {
+                        real_path.push(c);
+                    }
+                    ParsePathState::PathParamName => {
+                        current_name.push(c);
+                    }
+                    ParsePathState::PathParamType => {
+                        current_type.push(c);
+                    }
+                },
+            }
+        }
+
+        if state != ParsePathState::Path {
+            return Err(syn::Error::new(
+                Self::path_span(&literal, param_start..path.len()),
+                "Expected one of character or `}`, found EOF",
+            ));
+        }
+
+        Ok((path, real_path, path_params))
+    }
+
+    /// The path parameters of a path, as `name:type` without whitespace, in
+    /// order
+    ///
+    /// The bindings are left out, the handler only uses those of the primary
+    /// path.
+    fn path_captures(path_params: &[PathParam]) -> Vec<String> {
+        path_params
+            .iter()
+            .map(|param| format!("{}:{}", param.name, type_name(&param.ty)))
+            .collect()
+    }
+
+    /// The span of a byte range of the value of a path literal
+    ///
+    /// Falls back to the span of the whole literal when the compiler doesn't
+    /// support sub-spans, or when the literal isn't a plain string without
+    /// escapes (the range wouldn't match the source).
+    fn path_span(literal: &LitStr, range: std::ops::Range<usize>) -> proc_macro2::Span {
+        let token = literal.token();
+        let source = token.to_string();
+        if !source.starts_with('"') || source.contains('\\') {
+            return literal.span();
+        }
+
+        // Skips the opening quote
+        token
+            .subspan(range.start + 1..range.end + 1)
+            .unwrap_or_else(|| literal.span())
+    }
+}
+
+/// The Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// Returns the type parameter of `ty` if it is `wrapper<T>` (e.g. `Json<T>`)
+fn unwrap_type<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != wrapper {
+        return None;
+    }
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+            match args.args.first()? {
+                syn::GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Whether a type is a path ending with `name`, whatever its generics, e.g.
+/// `Pagination<50>` for `Pagination`
+#[cfg(feature = "utoipa")]
+fn is_named(ty: &Type, name: &str) -> bool {
+    matches!(ty, Type::Path(path) if path.path.segments.last().is_some_and(|segment| segment.ident == name))
+}
+
+/// Whether a return type sets the status of the response itself, i.e. is
+/// `StatusCode` or a tuple starting with it, e.g. `(StatusCode, Json<User>)`
+///
+/// Only the success type of a `Result` is looked at, the status of errors
+/// being always kept.
+fn sets_status_code(ty: &Type) -> bool {
+    match ty {
+        Type::Paren(ty) => sets_status_code(&ty.elem),
+        Type::Group(ty) => sets_status_code(&ty.elem),
+        Type::Tuple(tuple) => matches!(
+            tuple.elems.first(),
+            Some(Type::Path(path)) if path.path.segments.last().is_some_and(|segment| segment.ident == "StatusCode")
+        ),
+        Type::Path(path) => {
+            let Some(segment) = path.path.segments.last() else {
+                return false;
+            };
+            if segment.ident != "Result" {
+                return segment.ident == "StatusCode";
+            }
+            match &segment.arguments {
+                syn::PathArguments::AngleBracketed(args) => match args.args.first() {
+                    Some(syn::GenericArgument::Type(ty)) => sets_status_code(ty),
+                    _ => false,
+                },
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Collects the variables bound by the pattern of an extra parameter of the
+/// handler, e.g. `state` for `State(state)`
+///
+/// Parameters of a function only accept irrefutable patterns, so the pattern
+/// forms that can only be refutable are rejected here rather than by rustc in
+/// the generated handler.
+fn pat_bindings(pat: &Pat, bindings: &mut Vec<Ident>) -> syn::Result<()> {
+    let refutable = match pat {
+        Pat::Ident(pat) => {
+            bindings.push(pat.ident.clone());
+            if let Some((_, subpat)) = &pat.subpat {
+                pat_bindings(subpat, bindings)?;
+            }
+            return Ok(());
+        }
+        Pat::Tuple(pat) => {
+            return pat
+                .elems
+                .iter()
+                .try_for_each(|pat| pat_bindings(pat, bindings));
+        }
+        Pat::TupleStruct(pat) => {
+            return pat
+                .elems
+                .iter()
+                .try_for_each(|pat| pat_bindings(pat, bindings));
+        }
+        Pat::Struct(pat) => {
+            return pat
+                .fields
+                .iter()
+                .try_for_each(|field| pat_bindings(&field.pat, bindings));
+        }
+        Pat::Slice(pat) => {
+            return pat
+                .elems
+                .iter()
+                .try_for_each(|pat| pat_bindings(pat, bindings));
+        }
+        Pat::Reference(pat) => return pat_bindings(&pat.pat, bindings),
+        Pat::Paren(pat) => return pat_bindings(&pat.pat, bindings),
+        Pat::Type(pat) => return pat_bindings(&pat.pat, bindings),
+        Pat::Wild(_) | Pat::Rest(_) | Pat::Path(_) => return Ok(()),
+        Pat::Lit(_) => "Literal",
+        Pat::Range(_) => "Range",
+        Pat::Or(_) => "Or",
+        Pat::Const(_) => "Const block",
+        _ => {
+            return Err(syn::Error::new_spanned(
+                pat,
+                "Unsupported pattern in the parameters of the handler",
+            ));
+        }
+    };
+    Err(syn::Error::new_spanned(
+        pat,
+        format!(
+            "{} patterns are refutable, the parameters of the handler only accept irrefutable patterns (e.g. `state`, `State(state)`, `mut count` or `_`)",
+            refutable
+        ),
+    ))
+}
+
+/// The name of the extractor of a parameter if it consumes the request body,
+/// e.g. `Json`, looking through `Option`
+fn body_extractor(ty: &Type) -> Option<String> {
+    let ty = unwrap_type(ty, "Option").unwrap_or(ty);
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let ident = &path.path.segments.last()?.ident;
+    [
+        "Request",
+        "Json",
+        "Form",
+        "RawForm",
+        "FormOrJson",
+        "Multipart",
+        "Bytes",
+        "String",
+        "Body",
+    ]
+    .contains(&ident.to_string().as_str())
+    .then(|| ident.to_string())
+}
+
+/// Generates the `#[utoipa::path]` attribute documenting an inline handler,
+/// unless the route opts out with `utoipa = false`
+#[cfg(feature = "utoipa")]
+fn openapi_attribute(args: &Args) -> proc_macro2::TokenStream {
+    if !args.utoipa {
+        return quote::quote! {};
+    }
+
+    let method = match args.method {
+        Method::Get => quote::quote! { get },
+        Method::Post => quote::quote! { post },
+        Method::Put => quote::quote! { put },
+        Method::Patch => quote::quote! { patch },
+        Method::Delete => quote::quote! { delete },
+        Method::Head => quote::quote! { head },
+        Method::Options => quote::quote! { options },
+        Method::Trace => quote::quote! { trace },
+        // CONNECT cannot be described in OpenAPI
+        Method::Connect => return quote::quote! {},
+    };
+
+    let path = &args.template;
+    let mut items = vec![method, quote::quote! { path = #path }];
+
+    let params = args
+        .all_path_params()
+        .map(|PathParam { name, ty, .. }| quote::quote! { (#name = #ty, Path) })
+        .chain(args.query_params.iter().map(|ty| {
+            let ty = unwrap_type(ty, "Option").unwrap_or(ty);
+            if is_named(ty, "Pagination") {
+                quote::quote! {
+                    ("page" = Option<u32>, Query), ("per_page" = Option<u32>, Query)
+                }
+            } else {
+                quote::quote! { #ty }
+            }
+        }))
+        .collect::<Vec<_>>();
+    if !params.is_empty() {
+        items.push(quote::quote! { params(#(#params),*) });
+    }
+
+    match &args.body_params {
+        Some(Body::Type(ty)) => {
+            // utoipa documents `Option` bodies as not required
+            let optional = unwrap_type(ty, "Option");
+            let body = |ty: &Type| match optional {
+                Some(_) => quote::quote! { Option<#ty> },
+                None => quote::quote! { #ty },
+            };
+            let ty = optional.map_or(&**ty, |ty| ty);
+            if let Some(ty) = unwrap_type(ty, "Json") {
+                let ty = body(ty);
+                items.push(quote::quote! { request_body = #ty });
+            } else if let Some(ty) = unwrap_type(ty, "Form") {
+                let ty = body(ty);
+                items.push(quote::quote! {
+                    request_body(content = #ty, content_type = "application/x-www-form-urlencoded")
+                });
+            } else if let Some(ty) = unwrap_type(ty, "FormOrJson") {
+                let ty = body(ty);
+                items.push(quote::quote! {
+                    request_body(content(
+                        (#ty = "application/json"),
+                        (#ty = "application/x-www-form-urlencoded"),
+                    ))
+                });
+            } else if is_named(ty, "Bytes") {
+                items.push(quote::quote! {
+                    request_body(content = Vec<u8>, content_type = "application/octet-stream")
+                });
+            } else if is_named(ty, "String") {
+                items.push(quote::quote! {
+                    request_body(content = String, content_type = "text/plain")
+                });
+            } else {
+                let ty = body(ty);
+                items.push(quote::quote! { request_body = #ty });
+            }
+        }
+        Some(Body::Multipart) => items.push(quote::quote! {
+            request_body(content = Vec<u8>, content_type = "multipart/form-data")
+        }),
+        Some(Body::Stream) | Some(Body::Bytes) => items.push(quote::quote! {
+            request_body(content = Vec<u8>, content_type = "application/octet-stream")
+        }),
+        None => {}
+    }
+
+    let status = match &args.status {
+        Some(Status::Code(code)) => quote::quote! { #code },
+        Some(Status::Name(name)) => {
+            let krate = &args.krate;
+            quote::quote! { #krate::__private::axum::http::StatusCode::#name }
+        }
+        None => quote::quote! { 200 },
+    };
+    let response_body = args
+        .return_type
+        .as_ref()
+        .filter(|_| matches!(args.handler_kind, HandlerKind::Async))
+        .and_then(|ty| unwrap_type(ty, "Json"))
+        .map(|ty| quote::quote! { , body = #ty });
+    items.push(quote::quote! { responses((status = #status #response_body)) });
+
+    if let Some(summary) = &args.summary {
+        items.push(quote::quote! { summary = #summary });
+    }
+    if let Some(description) = &args.description {
+        items.push(quote::quote! { description = #description });
+    }
+    if !args.tags.is_empty() {
+        let tags = &args.tags;
+        items.push(quote::quote! { tags = [#(#tags),*] });
+    }
+
+    quote::quote! {
+        #[utoipa::path(#(#items),*)]
+    }
+}
+
+/// Generates the aide variant of the route, documented with its metadata
+#[cfg(feature = "aide")]
+fn api_handler(
+    krate: &syn::Path,
+    enabled_if: Option<&syn::Path>,
+    method: &Method,
+    handler_ref: &proc_macro2::TokenStream,
+    layers: &[proc_macro2::TokenStream],
+    paths: &[proc_macro2::TokenStream],
+    docs: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let method = match method {
+        Method::Get => quote::quote! { get_with },
+        Method::Post => quote::quote! { post_with },
+        Method::Put => quote::quote! { put_with },
+        Method::Patch => quote::quote! { patch_with },
+        Method::Delete => quote::quote! { delete_with },
+        Method::Head => quote::quote! { head_with },
+        Method::Options => quote::quote! { options_with },
+        Method::Trace => quote::quote! { trace_with },
+        // CONNECT cannot be documented, the route is merged undocumented
+        Method::Connect => return quote::quote! {},
+    };
+
+    let method_router = quote::quote! {
+        #krate::__private::aide::axum::routing::#method(#handler_ref, |op| #docs)
+        #(#layers)*
+    };
+    let route = with_enabled_if(
+        enabled_if,
+        quote::quote! { r #(.api_route(#paths, #method_router))* },
+    );
+
+    quote::quote! {
+        .with_api_handler(#krate::__private::ApiHandler::new(|r, _| #route))
+    }
+}
+
+#[cfg(not(feature = "aide"))]
+fn api_handler(
+    _krate: &syn::Path,
+    _enabled_if: Option<&syn::Path>,
+    _method: &Method,
+    _handler_ref: &proc_macro2::TokenStream,
+    _layers: &[proc_macro2::TokenStream],
+    _paths: &[proc_macro2::TokenStream],
+    _docs: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    quote::quote! {}
+}
+
+/// Generates the expression documenting the aide operation `op` with the
+/// route metadata, routes without tags being tagged with the tag of `group`,
+/// the group the route is registered in
+#[cfg(feature = "aide")]
+fn api_docs(args: &Args, group: &Type) -> proc_macro2::TokenStream {
+    let summary = args.summary.iter();
+    let description = args
+        .description
+        .as_ref()
+        .map(|d| d.value())
+        .or_else(|| doc_comment(&args.handler_attributes))
+        .into_iter();
+    let tags = &args.tags;
+    let docs = quote::quote! {
+        op #(.summary(#summary))* #(.description(#description))* #(.tag(#tags))*
+    };
+
+    if tags.is_empty() {
+        let krate = &args.krate;
+        quote::quote! { #krate::__private::tag_operation::<#group>(#docs) }
+    } else {
+        docs
+    }
+}
+
+#[cfg(not(feature = "aide"))]
+fn api_docs(_args: &Args, _group: &Type) -> proc_macro2::TokenStream {
+    quote::quote! {}
+}
+
+#[cfg(not(feature = "utoipa"))]
+fn openapi_attribute(_args: &Args) -> proc_macro2::TokenStream {
+    quote::quote! {}
+}
+
+/// Renders a type as a compact string (e.g. `Vec<String>`)
+fn type_name(ty: &Type) -> String {
+    quote::quote! { #ty }.to_string().replace(' ', "")
+}
+
+/// Generates the `RouteMetadata` fields describing the operation, used to
+/// build the OpenAPI document of the core crate
+fn operation_metadata(args: &Args) -> proc_macro2::TokenStream {
+    let method = args.method.variant(&args.krate);
+
+    let params = args.all_path_params().map(|PathParam { name, ty, .. }| {
+        let ty = type_name(ty);
+        quote::quote! { (#name, #ty) }
+    });
+
+    let query = option_tokens(
+        args.query_params
+            .as_ref()
+            .map(|ty| type_name(unwrap_type(ty, "Option").unwrap_or(ty))),
+    );
+
+    let body = option_tokens(args.body_params.as_ref().map(|body| {
+        let (content_type, ty) = match body {
+            Body::Type(ty) => {
+                let ty = unwrap_type(ty, "Option").unwrap_or(&**ty);
+                if let Some(ty) = unwrap_type(ty, "Json").or(unwrap_type(ty, "FormOrJson")) {
+                    ("application/json", type_name(ty))
+                } else if let Some(ty) = unwrap_type(ty, "Form") {
+                    ("application/x-www-form-urlencoded", type_name(ty))
+                } else {
+                    ("application/octet-stream", type_name(ty))
+                }
+            }
+            Body::Multipart => ("multipart/form-data", "Multipart".to_owned()),
+            Body::Stream | Body::Bytes => ("application/octet-stream", "Bytes".to_owned()),
+        };
+        quote::quote! { (#content_type, #ty) }
+    }));
+
+    let response = option_tokens(
+        args.return_type
+            .as_ref()
+            .filter(|_| matches!(args.handler_kind, HandlerKind::Async))
+            .and_then(|ty| match unwrap_type(ty, "Json") {
+                Some(ty) => Some(("application/json", type_name(ty))),
+                None => {
+                    let is_text = type_name(ty) == "String"
+                        || matches!(ty, Type::Reference(r) if type_name(&r.elem) == "str");
+                    is_text.then(|| ("text/plain", "String".to_owned()))
+                }
+            })
+            .map(|(content_type, ty)| quote::quote! { (#content_type, #ty) }),
+    );
+
+    let deprecation = option_tokens(args.deprecated.as_ref().map(|since| {
+        let krate = &args.krate;
+        let sunset = option_tokens(args.sunset.as_ref());
+        let successor = option_tokens(args.successor.as_ref());
+        quote::quote! {
+            #krate::Deprecation {
+                since: #since,
+                sunset: #sunset,
+                successor: #successor,
+            }
+        }
+    }));
+
+    let status = option_tokens(
+        args.status
+            .as_ref()
+            .map(|status| status.as_u16(&args.krate)),
+    );
+    let priority = args.priority.as_ref().map_or_else(
+        || quote::quote! { 0 },
+        |priority| quote::quote! { #priority },
+    );
+
+    let aliases = &args.aliases;
+    let optional_body = matches!(
+        &args.body_params,
+        Some(Body::Type(ty)) if unwrap_type(ty, "Option").is_some()
+    );
+
+    let guards = args
+        .guards
+        .iter()
+        .map(|guard| quote::quote! { #guard }.to_string().replace(' ', ""));
+    let name = args.name.to_string();
+
+    quote::quote! {
+        name: Some(#name),
+        group: None,
+        method: Some(#method),
+        params: &[#(#params),*],
+        query: #query,
+        body: #body,
+        optional_body: #optional_body,
+        response: #response,
+        status: #status,
+        priority: #priority,
+        deprecation: #deprecation,
+        aliases: &[#(#aliases),*],
+        guards: &[#(#guards),*],
+    }
+}
+
+/// Generates the function building the URL of a route from its path
+/// parameters and its query, e.g. `get_user_url(42, &Filter { .. })`, along
+/// with the struct of its path parameters that `uri!` fills in
+///
+/// The bounds are higher-ranked so that they are only checked where the
+/// function is called, routes whose parameters don't implement `Display` or
+/// whose query doesn't implement `Serialize` don't get a usable function but
+/// still compile.
+fn url_function(
+    args: &Args,
+    cfg: Option<&proc_macro2::TokenStream>,
+    name: &Ident,
+    route_name: &Ident,
+    group: &Type,
+) -> proc_macro2::TokenStream {
+    let krate = &args.krate;
+    let link = link_ident(route_name);
+    let bindings = args
+        .all_path_params()
+        .map(|param| &param.binding)
+        .collect::<Vec<_>>();
+    let types = args
+        .all_path_params()
+        .map(|param| &param.ty)
+        .collect::<Vec<_>>();
+
+    let path = route_path(args, group);
+
+    let link_value = quote::quote! { #link { #(#bindings),* } };
+    let (with_query, query_param, query_bound, url) = match &args.query_params {
+        Some(query) => {
+            let optional = unwrap_type(query, "Option");
+            let query = optional.unwrap_or(query);
+            let bound = quote::quote! { for<'a> #query: #krate::__private::serde::Serialize };
+            let with_query = quote::quote! {
+                pub(crate) fn with_query(self, query: &#query) -> String
+                where
+                    #(for<'a> #types: ::std::fmt::Display,)*
+                    #bound
+                {
+                    let mut url = self.path();
+                    let query = #krate::__private::serde_urlencoded::to_string(query)
+                        .expect("the query of a route must serialize to a flat list of parameters");
+                    if !query.is_empty() {
+                        url.push('?');
+                        url.push_str(&query);
+                    }
+                    url
+                }
+            };
+            match optional {
+                Some(_) => (
+                    with_query,
+                    quote::quote! { query: Option<&#query> },
+                    bound,
+                    quote::quote! {
+                        match query {
+                            Some(query) => #link_value.with_query(query),
+                            None => #link_value.path(),
+                        }
+                    },
+                ),
+                None => (
+                    with_query,
+                    quote::quote! { query: &#query },
+                    bound,
+                    quote::quote! { #link_value.with_query(query) },
+                ),
+            }
+        }
+        None => (
+            quote::quote! {},
+            quote::quote! {},
+            quote::quote! {},
+            quote::quote! { #link_value.path() },
+        ),
+    };
+
+    quote::quote! {
+        /// The path parameters of a route, see `uri!`
+        #cfg
+        #[doc(hidden)]
+        #[allow(dead_code, non_camel_case_types)]
+        pub(crate) struct #link {
+            #(pub(crate) #bindings: #types,)*
+        }
+
+        #cfg
+        #[allow(dead_code)]
+        impl #link {
+            pub(crate) fn path(self) -> String
+            where
+                #(for<'a> #types: ::std::fmt::Display,)*
+            {
+                let Self { #(#bindings),* } = self;
+                #path
+            }
+
+            #with_query
+        }
+
+        #cfg
+        #[allow(dead_code)]
+        pub(crate) fn #name(#(#bindings: #types,)* #query_param) -> String
+        where
+            #(for<'a> #types: ::std::fmt::Display,)*
+            #query_bound
+        {
+            #url
+        }
+    }
+}
+
+/// Generates the function calling a route over HTTP with the `client`
+/// feature, e.g. `call_get_user(&client, 42, &filter)`
+///
+/// WebSocket and multipart routes don't get a client function. Like the URL
+/// builders, the bounds are higher-ranked so that routes whose parameters
+/// can't be sent still compile.
+fn client_function(
+    args: &Args,
+    cfg: Option<&proc_macro2::TokenStream>,
+    name: &Ident,
+    group: &Type,
+) -> proc_macro2::TokenStream {
+    if matches!(args.handler_kind, HandlerKind::WebSocket(_))
+        || matches!(args.body_params, Some(Body::Multipart))
+    {
+        return quote::quote! {};
+    }
+
+    let krate = &args.krate;
+    let reqwest = quote::quote! { #krate::__private::reqwest };
+    let path = route_path(args, group);
+    let bindings = args
+        .all_path_params()
+        .map(|param| &param.binding)
+        .collect::<Vec<_>>();
+    let types = args
+        .all_path_params()
+        .map(|param| &param.ty)
+        .collect::<Vec<_>>();
+    let method = Ident::new(args.method.as_str(), name.span());
+
+    let mut bounds = Vec::new();
+    let (query_param, query) = match &args.query_params {
+        Some(query) => {
+            let optional = unwrap_type(query, "Option");
+            let query = optional.unwrap_or(query);
+            bounds.push(quote::quote! { for<'a> #query: #krate::__private::serde::Serialize });
+            match optional {
+                Some(_) => (
+                    quote::quote! { query: Option<&#query>, },
+                    quote::quote! {
+                        if let Some(query) = query {
+                            request = request.query(query);
+                        }
+                    },
+                ),
+                None => (
+                    quote::quote! { query: &#query, },
+                    quote::quote! { request = request.query(query); },
+                ),
+            }
+        }
+        None => (quote::quote! {}, quote::quote! {}),
+    };
+
+    let raw_body = || {
+        (
+            quote::quote! { body: impl Into<#reqwest::Body>, },
+            quote::quote! { request = request.body(body); },
+        )
+    };
+    let (body_param, body) = match &args.body_params {
+        Some(Body::Type(ty)) => {
+            let optional = unwrap_type(ty, "Option");
+            let ty = optional.unwrap_or(ty);
+            let encoded =
+                if let Some(ty) = unwrap_type(ty, "Json").or(unwrap_type(ty, "FormOrJson")) {
+                    Some((ty, quote::quote! { json }))
+                } else {
+                    unwrap_type(ty, "Form").map(|ty| (ty, quote::quote! { form }))
+                };
+            match (encoded, optional) {
+                (Some((ty, encode)), Some(_)) => {
+                    bounds.push(quote::quote! { for<'a> #ty: #krate::__private::serde::Serialize });
+                    (
+                        quote::quote! { body: Option<&#ty>, },
+                        quote::quote! {
+                            if let Some(body) = body {
+                                request = request.#encode(body);
+                            }
+                        },
+                    )
+                }
+                (Some((ty, encode)), None) => {
+                    bounds.push(quote::quote! { for<'a> #ty: #krate::__private::serde::Serialize });
+                    (
+                        quote::quote! { body: &#ty, },
+                        quote::quote! { request = request.#encode(body); },
+                    )
+                }
+                (None, _) => raw_body(),
+            }
+        }
+        Some(Body::Stream) | Some(Body::Bytes) => raw_body(),
+        Some(Body::Multipart) => unreachable!(),
+        None if args.request => raw_body(),
+        None => (quote::quote! {}, quote::quote! {}),
+    };
+
+    quote::quote! {
+        #cfg
+        #[allow(dead_code, private_interfaces, clippy::too_many_arguments)]
+        pub async fn #name(
+            client: &#krate::Client,
+            #(#bindings: #types,)*
+            #query_param
+            #body_param
+        ) -> #reqwest::Result<#reqwest::Response>
+        where
+            #(for<'a> #types: ::std::fmt::Display,)*
+            #(#bounds,)*
+        {
+            let path = #path;
+            #[allow(unused_mut)]
+            let mut request = client.request(#reqwest::Method::#method, &path);
+            #query
+            #body
+            request.send().await
+        }
+    }
+}
+
+/// Generates the block building the absolute path of a route from its path
+/// parameters, bound to variables named after them
+fn route_path(args: &Args, group: &Type) -> proc_macro2::TokenStream {
+    let krate = &args.krate;
+
+    // The captures of the group are filled with the inherited parameters
+    let inherited = args.inherited.iter().map(|param| &param.binding);
+    let prefix = if args.inherited.is_empty() {
+        quote::quote! { <#group as #krate::Route>::prefix() }
+    } else {
+        quote::quote! {
+            #krate::__private::fill_captures(
+                &<#group as #krate::Route>::prefix(),
+                &[#(&#inherited),*],
+            )
+        }
+    };
+
+    // The template alternates literal parts and captures, in path order
+    let mut segments = Vec::new();
+    let mut params = args.path_params.iter();
+    for (i, part) in args.template.split(['{', '}']).enumerate() {
+        if i % 2 == 0 {
+            if !part.is_empty() {
+                segments.push(quote::quote! { url.push_str(#part); });
+            }
+        } else if let Some(param) = params.next() {
+            let binding = &param.binding;
+            segments.push(quote::quote! { #krate::__private::push_segment(&mut url, &#binding); });
+        }
+    }
+
+    quote::quote! {{
+        let mut url = String::new();
+        #(#segments)*
+        #krate::join_paths(&#prefix, &url)
+    }}
+}
+
+/// The struct of the path parameters of a route, named after its static,
+/// e.g. `__URI_ROUTE_GET_USER`
+fn link_ident(route_name: &Ident) -> Ident {
+    Ident::new(&format!("__URI_{}", route_name), route_name.span())
+}
+
+/// Resolves the request parts types of the `parts` key that are usually not
+/// imported (`HeaderMap`, `Method`, `Uri` and `Version`) to those of `http`,
+/// other types are used as written
+fn part_type(krate: &syn::Path, ty: &Type) -> proc_macro2::TokenStream {
+    if let Type::Path(path) = ty
+        && path.qself.is_none()
+        && let Some(ident) = path.path.get_ident()
+        && ["HeaderMap", "Method", "Uri", "Version"].contains(&ident.to_string().as_str())
+    {
+        return quote::quote! { #krate::__private::axum::http::#ident };
+    }
+    quote::quote! { #ty }
+}
+
+/// Wraps an extractor pattern and type in `WithRejection` when the route
+/// declares a `rejection` type
+fn with_rejection(
+    krate: &syn::Path,
+    rejection: Option<&Type>,
+    pat: proc_macro2::TokenStream,
+    ty: proc_macro2::TokenStream,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    match rejection {
+        Some(rejection) => (
+            quote::quote! { #krate::WithRejection(#pat, _) },
+            quote::quote! { #krate::WithRejection<#ty, #rejection> },
+        ),
+        None => (pat, ty),
+    }
+}
+
+/// Wraps an extractor pattern and type in the extractor of the `validate` key
+fn with_validation(
+    args: &Args,
+    validation: Option<&Validation>,
+    pat: proc_macro2::TokenStream,
+    ty: proc_macro2::TokenStream,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    match validation {
+        Some(validation) => validation.wrap(args, pat, ty),
+        None => (pat, ty),
+    }
+}
+
+/// Wraps the expression attaching a route to `r` so that `r` is returned
+/// unchanged when the `enabled_if` function returns `false`
+#[cfg(feature = "aide")]
+fn with_enabled_if(
+    enabled_if: Option<&syn::Path>,
+    route: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    match enabled_if {
+        Some(enabled_if) => quote::quote! { if #enabled_if() { #route } else { r } },
+        None => route,
+    }
+}
+
+/// Parses a non-empty list of versions, e.g. `[1, 2]`
+fn parse_versions(input: syn::parse::ParseStream) -> syn::Result<Vec<LitInt>> {
+    let content;
+    let brackets = bracketed!(content in input);
+    let versions = Punctuated::<LitInt, Token![,]>::parse_terminated(&content)?
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    if versions.is_empty() {
+        return Err(syn::Error::new(
+            brackets.span.join(),
+            "Expected at least one version",
+        ));
+    }
+    let mut seen = Vec::new();
+    for version in &versions {
+        let value = version.base10_parse::<u32>()?;
+        if seen.contains(&value) {
+            return Err(syn::Error::new(version.span(), "Duplicate version"));
+        }
+        seen.push(value);
+    }
+
+    Ok(versions)
+}
+
+/// Appends a version to an identifier, e.g. `Api` and `2` give `ApiV2`
+fn versioned_ident(ident: &Ident, version: &LitInt) -> Ident {
+    Ident::new(
+        &format!("{}V{}", ident, version.base10_digits()),
+        ident.span(),
+    )
+}
+
+/// Quotes an optional value as `Some(value)` or `None`
+fn option_tokens<T: quote::ToTokens>(value: Option<T>) -> proc_macro2::TokenStream {
+    match value {
+        Some(value) => quote::quote! { Some(#value) },
+        None => quote::quote! { None },
+    }
+}
+
+/// Extracts the doc comment from a list of attributes
+fn doc_comment(attributes: &[Attribute]) -> Option<String> {
+    let lines = attributes
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                value:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(doc),
+                        ..
+                    }),
+                ..
+            }) => Some(doc.value()),
+            _ => None,
+        })
+        .map(|line| line.strip_prefix(' ').map(str::to_owned).unwrap_or(line))
+        .collect::<Vec<_>>();
+
+    let doc = lines.join("\n").trim().to_owned();
+    (!doc.is_empty()).then_some(doc)
+}
+
+/// Wraps a handler in a handler running the `require` guards of the route
+/// first, in declaration order
+fn guarded(
+    krate: &syn::Path,
+    group: &Type,
+    guards: &[syn::Path],
+    handler: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if guards.is_empty() {
+        return handler;
+    }
+
+    quote::quote! {
+        |#krate::__private::axum::extract::State(state): #krate::__private::axum::extract::State<<#group as #krate::Route>::State>,
+         request: #krate::__private::axum::extract::Request| async move {
+            #(
+                let request = match #krate::Guard::check(#guards, request, state.clone()).await {
+                    Ok(request) => request,
+                    Err(response) => return response,
+                };
+            )*
+            #krate::__private::axum::handler::Handler::call(#handler, request, state).await
+        }
+    }
+}
+
+/// Generates the span a route runs in, named after its handler
+#[cfg(feature = "tracing")]
+fn route_span(args: &Args) -> Option<proc_macro2::TokenStream> {
+    let krate = &args.krate;
+    let name = args.name.to_string();
+    let method = args.method.as_str();
+    // Recorded by the name of the capture, which isn't always an identifier
+    let params = args.all_path_params().map(|param| &param.name);
+
+    Some(quote::quote! {
+        #krate::__private::tracing::info_span!(
+            #name,
+            http.method = #method,
+            http.route = #krate::__private::tracing::field::Empty,
+            http.status_code = #krate::__private::tracing::field::Empty,
+            #(#params = #krate::__private::tracing::field::Empty,)*
+        )
+    })
+}
+
+#[cfg(not(feature = "tracing"))]
+fn route_span(_args: &Args) -> Option<proc_macro2::TokenStream> {
+    None
+}
+
+/// Wraps a handler in a handler running it in the span of the route, see
+/// `__private::instrumented`
+fn instrumented(
+    krate: &syn::Path,
+    group: &Type,
+    span: Option<&proc_macro2::TokenStream>,
+    handler: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let Some(span) = span else {
+        return handler;
+    };
+
+    quote::quote! {
+        |#krate::__private::axum::extract::State(state): #krate::__private::axum::extract::State<<#group as #krate::Route>::State>,
+         request: #krate::__private::axum::extract::Request| async move {
+            #krate::__private::instrumented(#span, #handler, request, state).await
+        }
+    }
+}
+
+/// Creates a route and add it to the group
+///
+/// The handler is generated as a `pub(crate) async fn` named after the route,
+/// so it can be called directly (e.g. from unit tests) with constructed
+/// extractor values. The route registration references this function.
+///
+/// # Example
+/// ```
+/// # use axum_distributed_routing::{route, route_group};
+/// # route_group!(Routes, ());
+/// route!(
+///     group = Routes,
+///     path = "/echo/{str:String}",
+///     method = GET,
+///     async test_fn -> String { str }
+/// );
+/// # fn main() {}
+/// ```
+///
+/// Extra extractors are declared as parameters of the handler, with any
+/// irrefutable pattern, e.g.
+/// `async stats(State(AppState { db, .. }): State<AppState>, mut jar: CookieJar)`.
+///
+/// Since the handler is a regular function, the return type can be any type
+/// implementing `IntoResponse`, including `impl IntoResponse`. The return
+/// type can also be omitted (`async ping { "pong" }`), in which case it
+/// defaults to `impl IntoResponse`.
+///
+/// Fallible handlers can declare their error type with `error = ApiError`:
+/// the handler then returns `Result<ReturnType, ApiError>` and can use `?`.
+/// A bare `error` uses the default error type declared on the group (see
+/// `route_group!`).
+///
+/// With the `anyhow` feature, `error = anyhow` returns `AnyhowError`, into
+/// which `?` converts any `anyhow::Error`. Errors are answered with a
+/// `500 Internal Server Error` and logged with `tracing::error!`:
+///
+/// ```
+/// # use anyhow::Context;
+/// # use axum::Json;
+/// # use axum_distributed_routing::{route, route_group};
+/// # #[derive(serde::Serialize)]
+/// # struct Report;
+/// # async fn build_report() -> std::io::Result<Report> {
+/// #     Ok(Report)
+/// # }
+/// # route_group!(Api, ());
+/// route!(
+///     group = Api,
+///     path = "/report",
+///     method = GET,
+///     error = anyhow,
+///     async report -> Json<Report> {
+///         let report = build_report().await.context("building the report")?;
+///         Ok(Json(report))
+///     }
+/// );
+/// # fn main() {}
+/// ```
+///
+/// With the `blocking` flag, the block of the handler runs on the blocking
+/// pool of tokio, e.g. for CPU-heavy work, the extractors being moved into it.
+/// The block can't `.await`, and its output must be `Send + 'static`. Requests
+/// whose block panics are answered with a `500 Internal Server Error`:
+///
+/// ```
+/// # use axum::body::Bytes;
+/// # use axum_distributed_routing::{route, route_group};
+/// # fn resize(image: &[u8], size: usize) -> Vec<u8> {
+/// #     image.iter().copied().take(size).collect()
+/// # }
+/// # route_group!(Api, ());
+/// route!(
+///     group = Api,
+///     path = "/thumbnails",
+///     method = POST,
+///     body = Bytes,
+///     blocking,
+///     async thumbnail -> Vec<u8> { resize(&body, 128) }
+/// );
+/// # fn main() {}
+/// ```
+///
+/// The success status can be changed with `status = 201` or
+/// `status = CREATED`. The precedence is decided from the return type: a
+/// return type that is `StatusCode` or a tuple starting with it, e.g.
+/// `(StatusCode, Json<User>)`, sets the status itself and is left untouched,
+/// even when it returns `StatusCode::OK`, the key then only documenting the
+/// route. For a `Result`, only the success type is looked at. Otherwise the
+/// `200 OK` responses of the route are given the status, while errors keep
+/// theirs.
+///
+/// Static response headers can be added with
+/// `response_headers = { "Cache-Control": "no-store", "Vary": append "Accept" }`.
+/// Headers replace the ones set by the handler, unless prefixed by `append`.
+///
+/// The successful responses of a route can be given a `Cache-Control` header
+/// with e.g. `cache_control = "public, max-age=300"`, unless the handler sets
+/// one. It replaces the `cache_control` of the group, see `route_group!`.
+///
+/// With the `compression` feature, the responses of the route are compressed
+/// with gzip or brotli, when the client accepts it, if its group declares
+/// `compression = true` (see `route_group!`). The route can opt in or out
+/// with `compression = true` or `compression = false`, e.g. for files which
+/// are already compressed. `sse` and `ws` routes, whose responses are
+/// streamed, are never compressed and refuse `compression = true`.
+///
+/// Routes can be documented with `summary = "..."`, `description = "..."`
+/// and `tags = ["users", "admin"]`, which are exposed through the `Route`
+/// trait. Doc comments on the handler are used as the description when the
+/// key is omitted. Routes without tags are tagged with the tag of their group
+/// (see `route_group!`), except in the `#[utoipa::path]` attribute, which is
+/// generated before the tag of the group is known.
+///
+/// With the `utoipa` feature, inline handlers are also annotated with
+/// `#[utoipa::path]`, derived from the method, path, parameters, body, status,
+/// return type and metadata of the route, so they can be listed in the
+/// `paths(...)` of an `OpenApi` derive. Query types must implement
+/// `utoipa::IntoParams`, with `#[into_params(parameter_in = Query)]` since
+/// utoipa documents their fields as path parameters otherwise, and `Json`
+/// bodies and responses `utoipa::ToSchema`, `Pagination` being documented as
+/// its `page` and `per_page` parameters. Routes whose types don't implement
+/// them opt out with `utoipa = false`. utoipa's `axum_extras` feature isn't
+/// supported, the generated handlers destructure their extractors.
+///
+/// With the `aide` feature, groups can be turned into an `ApiRouter` with
+/// `create_api_router!`. Routes are registered as aide operations documented
+/// with their metadata, their extractors and return types must then implement
+/// aide's `OperationInput` and `OperationOutput`. Routes whose types don't
+/// implement them opt out with `aide = false` and are attached undocumented,
+/// like inline handlers without a return type or returning `impl Trait`,
+/// whose output can't be documented.
+///
+/// The group's state can be extracted with the `state` key, either as
+/// `state = name` or as a bare `state` to bind it to `state`. Groups whose
+/// state is `()` reject this key. Groups nested with a part of their parent's
+/// state extract that part (see `GroupState`).
+///
+/// Request extensions (e.g. inserted by a middleware) are extracted with
+/// `extensions = [user: CurrentUser, request_id: RequestId]`. A missing
+/// extension is rejected by axum with a 500.
+///
+/// Headers are extracted with `headers = { auth: TypedHeader<Authorization<Bearer>> }`
+/// (typed headers require the `axum-extra` feature). Wrap a header in `Option`
+/// to accept requests without it. A single type such as `headers = HeaderMap`
+/// is bound to `headers`.
+///
+/// Other request parts are extracted with
+/// `parts = [headers: HeaderMap, uri: Uri, method: Method]`, in order and
+/// before the body. `HeaderMap`, `Method`, `Uri` and `Version` refer to the
+/// types of `http` without being imported, any other `FromRequestParts` type
+/// is used as written.
+///
+/// The address of the client is extracted with `connect_info = addr`, bound
+/// as a `std::net::SocketAddr`, or with e.g. `connect_info = info: MyConnectInfo`
+/// for a custom `Connected` type. axum only provides it to routers served with
+/// `into_make_service_with_connect_info`, e.g. through
+/// `serve_with_connect_info`, other requests are rejected with a 500 about
+/// the missing `ConnectInfo` extension.
+///
+/// The template of the route is extracted with `matched_path = mp`, bound as
+/// an `axum::extract::MatchedPath`. Routes are registered under their group
+/// with `Router::nest`, so `mp.as_str()` is the full template, e.g.
+/// `/api/users/{id}`, including the prefixes of the nested groups.
+///
+/// The prefix the group of the route is mounted at is extracted with
+/// `nested_path = prefix`, bound as an `axum::extract::NestedPath`, e.g.
+/// `/api/inner` for a route of a group nested in `Api`. Routes of the root
+/// group aren't nested, so axum rejects their requests with a 500.
+///
+/// Cookies are extracted with `cookies = jar` (`cookies` feature),
+/// `cookies = signed jar` (`cookies-signed` feature) or `cookies = private jar`
+/// (`cookies-private` feature). Signed and private jars pull their
+/// [`Key`](https://docs.rs/cookie/latest/cookie/struct.Key.html) from the
+/// group's state, which must implement `FromRef<State> for Key`. Return the jar
+/// from the handler to send the modified cookies back.
+///
+/// ```
+/// # use axum_distributed_routing::cookie::{Cookie, CookieJar};
+/// # use axum_distributed_routing::{route, route_group};
+/// # route_group!(Routes, ());
+/// route!(
+///     group = Routes,
+///     path = "/visits",
+///     method = GET,
+///     cookies = jar,
+///     async visits -> (CookieJar, String) {
+///         let visits = jar
+///             .get("visits")
+///             .and_then(|c| c.value().parse::<u32>().ok())
+///             .unwrap_or(0) + 1;
+///         (jar.add(Cookie::new("visits", visits.to_string())), visits.to_string())
+///     }
+/// );
+/// # fn main() {}
+/// ```
+///
+/// The extractors are generated in this order: path parameters, `state`,
+/// `extensions`, `headers`, `parts`, `connect_info`, `matched_path`,
+/// `nested_path`, `cookies`, `query`, the extra parameters of the handler, and
+/// the body (`body` or `request`). As only the last extractor can consume the
+/// body, an extra parameter consuming it (e.g. `Json` or `Request`) is an
+/// error when the route declares a body, or when it isn't the last one.
+///
+/// The body is extracted with `body = Type` and bound to `body`; it is always
+/// the last extractor. `body = multipart` binds an `axum::extract::Multipart`
+/// (requires the `multipart` feature). axum applies a default body limit of
+/// 2MB through `DefaultBodyLimit`, which also bounds multipart uploads; it
+/// can be changed per route with `body_limit = 16 * 1024 * 1024`.
+///
+/// `body = bytes` binds the fully buffered body as `axum::body::Bytes`, subject
+/// to the body limit. `body = stream` binds the unbuffered body as a stream of
+/// `Result<Bytes, axum::Error>` chunks, which is not subject to the body limit.
+///
+/// ```
+/// # use axum_distributed_routing::{route, route_group};
+/// # use futures::StreamExt;
+/// # route_group!(Routes, ());
+/// route!(
+///     group = Routes,
+///     path = "/upload",
+///     method = POST,
+///     body = stream,
+///     async upload -> String {
+///         let mut size = 0;
+///         while let Some(Ok(chunk)) = body.next().await {
+///             size += chunk.len();
+///         }
+///         size.to_string()
+///     }
+/// );
+/// # fn main() {}
+/// ```
+///
+/// The `request` key binds the whole `axum::extract::Request` as the last
+/// parameter, e.g. to forward it or to check a signature over the raw body.
+/// It cannot be combined with `body`, since the body can only be extracted
+/// once.
+///
+/// ```
+/// # use axum::http::StatusCode;
+/// # use axum_distributed_routing::{route, route_group};
+/// # route_group!(Routes, ());
+/// route!(
+///     group = Routes,
+///     path = "/webhook",
+///     method = POST,
+///     request,
+///     async webhook -> StatusCode {
+///         let Ok(body) = axum::body::to_bytes(request.into_body(), usize::MAX).await else {
+///             return StatusCode::BAD_REQUEST;
+///         };
+///         // ...
+/// #       let _ = body;
+///         StatusCode::NO_CONTENT
+///     }
+/// );
+/// # fn main() {}
+/// ```
+///
+/// WebSocket endpoints use the `ws` form (requires the `ws` feature). The
+/// upgraded `axum::extract::ws::WebSocket` is bound to the
+/// pattern in parentheses, while path, query and other parameters are still
+/// available in the block.
+///
+/// ```
+/// # use axum_distributed_routing::{route, route_group};
+/// # route_group!(Routes, ());
+/// route!(
+///     group = Routes,
+///     path = "/echo",
+///     method = GET,
+///     ws echo(socket) {
+///         while let Some(Ok(msg)) = socket.recv().await {
+///             if socket.send(msg).await.is_err() {
+///                 break;
+///             }
+///         }
+///     }
+/// );
+/// # fn main() {}
+/// ```
+///
+/// Server-Sent Events endpoints use the `sse` form. The block evaluates to a
+/// stream of `Result<Event, E>`, which is wrapped in an
+/// `axum::response::sse::Sse` response with a default keep-alive. The
+/// keep-alive interval can be changed with `keep_alive = Duration::from_secs(5)`.
+///
+/// ```
+/// # use std::convert::Infallible;
+/// # use axum::response::sse::Event;
+/// # use axum_distributed_routing::{route, route_group};
+/// # use futures::StreamExt;
+/// # route_group!(Routes, ());
+/// route!(
+///     group = Routes,
+///     path = "/ticks/{count:usize}",
+///     method = GET,
+///     sse ticks {
+///         futures::stream::iter(0..count)
+///             .map(|i| Ok::<_, Infallible>(Event::default().data(i.to_string())))
+///     }
+/// );
+/// # fn main() {}
+/// ```
+///
+/// An existing async function can be registered instead of an inline handler
+/// with the `handler` key. No extractors are generated in that case, so it
+/// cannot be combined with `query`, `body`, `request`, `state`, `extensions`,
+/// `headers`, `parts`, `connect_info`, `matched_path`, `nested_path` or
+/// `cookies`.
+///
+/// ```
+/// # use axum_distributed_routing::{route, route_group};
+/// # route_group!(Routes, ());
+/// # mod handlers {
+/// #     pub async fn get_user(axum::extract::Path(id): axum::extract::Path<u64>) -> String {
+/// #         id.to_string()
+/// #     }
+/// # }
+/// route!(
+///     group = Routes,
+///     path = "/users/{id:u64}",
+///     method = GET,
+///     handler = crate::handlers::get_user
+/// );
+/// # fn main() {}
+/// ```
+///
+/// The same route can be declared on the function itself with the attribute
+/// form, `#[attr::route(group = Routes, path = "/users/{id:u64}", method = GET)]`.
+///
+/// The query can be made optional with `query = Option<Pagination>`, binding
+/// `query: Option<Pagination>` through `OptionalQuery`. A request without a
+/// query string gets `None`, while a malformed one is still rejected with a
+/// `400 Bad Request`. This differs from `#[serde(default)]` fields, which
+/// default the missing parameters one by one, so that a request without a
+/// query can't be told apart from one spelling out the defaults.
+///
+/// ```
+/// # use axum_distributed_routing::{route, route_group};
+/// # #[derive(serde::Deserialize)]
+/// # struct Pagination {
+/// #     page: u32,
+/// #     per_page: u32,
+/// # }
+/// # impl Default for Pagination {
+/// #     fn default() -> Self {
+/// #         Self { page: 1, per_page: 20 }
+/// #     }
+/// # }
+/// # route_group!(Routes, ());
+/// route!(
+///     group = Routes,
+///     path = "/users",
+///     method = GET,
+///     query = Option<Pagination>,
+///     async list_users -> String {
+///         let Pagination { page, per_page } = query.unwrap_or_default();
+///         format!("page {page} of {per_page}")
+///     }
+/// );
+/// # fn main() {}
+/// ```
+///
+/// The body can be made optional with e.g. `body = Option<Json<Payload>>`,
+/// binding `body: Option<Json<Payload>>`. axum decides from the
+/// `Content-Type` header: a request without one gets `None`, whatever its
+/// body, while a request declaring `application/json` is rejected when its
+/// body is empty or isn't valid JSON, and a request declaring another
+/// content type is rejected with `415 Unsupported Media Type`. Optional bodies
+/// can't be combined with `validate`.
+///
+/// `body = FormOrJson<CreateUser>` accepts the same type as JSON or as a form,
+/// depending on the `Content-Type` of the request, and binds
+/// `body: CreateUser`. Other content types are rejected with
+/// `415 Unsupported Media Type`.
+///
+/// With the `validator` feature, the `validate` key validates the query and
+/// the body with `validator::Validate` before the handler runs. The body must
+/// dereference to the validated type (e.g. `Json<T>` or `Form<T>`). Invalid
+/// inputs are rejected by the `ValidationErrorResponse` implementation of the
+/// group's state, which defaults to a 422 with the errors as JSON.
+///
+/// With the `garde` feature, `validate = garde` validates them with
+/// `garde::Validate` instead, and invalid inputs are rejected by the
+/// `GardeErrorResponse` implementation of the state. Validators requiring a
+/// context use `validate = garde(context)`, the context is then taken from
+/// the state with `FromRef`. It can also be built by a function taking the
+/// state, with e.g. `validate = garde(context = password_rules)`, or by any
+/// other expression, with e.g.
+/// `validate = garde(context = PasswordRules { min_length: 12 })`.
+///
+/// ```
+/// # use axum::Json;
+/// # use axum::http::StatusCode;
+/// # use axum_distributed_routing::{route, route_group};
+/// # use validator::Validate;
+/// # route_group!(Routes, ());
+/// #[derive(serde::Deserialize, Validate)]
+/// struct CreateUser {
+///     #[validate(email)]
+///     email: String,
+/// }
+///
+/// route!(
+///     group = Routes,
+///     path = "/users",
+///     method = POST,
+///     body = Json<CreateUser>,
+///     validate,
+///     async create_user -> StatusCode { StatusCode::CREATED }
+/// );
+/// # fn main() {}
+/// ```
+///
+/// Extraction failures of the path parameters, query and body are reported
+/// with the rejection types of axum by default. They can be replaced with
+/// `rejection = ApiError`, where `ApiError` implements `IntoResponse` and
+/// `From` each rejection (e.g. `PathRejection` and `JsonRejection`). With the
+/// `rejection-format` feature, the routes without a `rejection` type report
+/// them in the format of their group, see `route_group!`. The handler
+/// function still takes the extractors of axum, which are wrapped when the
+/// route is registered.
+///
+/// The route is registered through a static named after the handler (e.g.
+/// `ROUTE_GET_USER`), so registering `handler = users::health` and
+/// `handler = orders::health` in one module conflicts. The static can be
+/// renamed with `static_name = USERS_HEALTH_ROUTE`.
+///
+/// Every route gets a `pub(crate) fn <name>_url` building the URL of the route from its path parameters, percent-encoded,
+/// and its query, serialized with `serde_urlencoded` (the query type must
+/// implement `Serialize`). The path starts with the prefixes of the groups
+/// the route's group is nested in with `route_group!`, see `Route::prefix`.
+/// Routes of a versioned group get one function per version, e.g.
+/// `get_user_v1_url`, and routes renamed with `static_name` one named after
+/// the static, e.g. `users_health_route_url`. `uri!` builds the same URL from
+/// the static of the route, with named parameters.
+///
+/// ```
+/// # use axum_distributed_routing::{route, route_group};
+/// # use serde::{Deserialize, Serialize};
+/// # route_group!(Routes, ());
+/// # route_group!(Api, (), Routes, "/api");
+/// # #[derive(Deserialize, Serialize)]
+/// # struct Filter {
+/// #     sort: String,
+/// # }
+/// route!(
+///     group = Api,
+///     path = "/users/{id:u64}/posts",
+///     method = GET,
+///     query = Filter,
+///     async user_posts -> String { format!("posts of {id} by {}", query.sort) }
+/// );
+///
+/// # fn main() {
+/// let url = user_posts_url(42, &Filter { sort: "new".into() });
+/// assert_eq!(url, "/api/users/42/posts?sort=new");
+/// # }
+/// ```
+///
+/// With the `client` feature, a `pub async fn call_<name>` sends the request
+/// of the route with `reqwest` through a `Client`, e.g. from another crate of
+/// the workspace. It takes the path parameters, then a reference to the query
+/// and to the body if any, and returns the response, whose body is left to
+/// the caller. `Json`, `FormOrJson` and `Form` bodies are serialized, other
+/// bodies are sent as is. Routes of a versioned group get one function per
+/// version, e.g. `call_get_user_v1`, and routes renamed with `static_name`
+/// one named after the static, e.g. `call_users_health_route`. WebSocket and
+/// multipart routes don't get a function.
+///
+/// ```no_run
+/// # use axum_distributed_routing::{Client, route, route_group};
+/// # use serde::{Deserialize, Serialize};
+/// # route_group!(Routes, ());
+/// # route_group!(Api, (), Routes, "/api");
+/// # #[derive(Deserialize, Serialize)]
+/// # struct Filter {
+/// #     sort: String,
+/// # }
+/// # route!(
+/// #     group = Api,
+/// #     path = "/users/{id:u64}/posts",
+/// #     method = GET,
+/// #     query = Filter,
+/// #     async user_posts -> String { format!("posts of {id} by {}", query.sort) }
+/// # );
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("http://localhost:3000");
+/// let response = call_user_posts(&client, 42, &Filter { sort: "new".into() }).await?;
+/// # Ok(())
+/// # }
+/// # fn main() {}
+/// ```
+///
+/// Routes of a group are attached by descending `priority` (`0` by default),
+/// then by path, so the order doesn't depend on link order. A route can be
+/// attached before its siblings with e.g. `priority = 10`, or after them with
+/// `priority = -10`.
+///
+/// Path parameters are declared with their type, e.g. `/users/{id:u64}`. The
+/// route is registered with axum's capture syntax, `/users/{id}`, which is the
+/// path seen by `MatchedPath` and middlewares. Paths must start with `/`, and
+/// can't contain empty segments (`/a//b`), whitespace, `?` or `#`.
+///
+/// The value of a path parameter is bound to a variable of the same name,
+/// which can be renamed with `as`, e.g. `/users/{id:u64 as user_id}`. This is
+/// required when the name isn't a valid identifier, e.g. `{type:String as kind}`
+/// or `{account-id:u64 as account}`. The capture keeps its name in the
+/// registered path and in the OpenAPI document.
+///
+/// The path parameters captured by the paths the group is nested at (see
+/// `route_group!`) are declared with `inherit`, in order, and bound like those
+/// of the route. They are extracted along them, so a route of such a group
+/// extracting path parameters must inherit every capture of the group,
+/// which is checked when the router is created. The URL builders of the route
+/// take them first, while those of the routes that don't inherit them keep
+/// the captures as is.
+///
+/// ```
+/// # use axum_distributed_routing::{route, route_group};
+/// # #[derive(Clone)]
+/// # pub struct AppState;
+/// # route_group!(pub Routes, AppState);
+/// route_group!(pub Org, AppState, Routes, "/orgs/{org_id:u64}");
+///
+/// route!(
+///     group = Org,
+///     path = "/projects/{project_id:u64}",
+///     method = GET,
+///     inherit = [org_id: u64],
+///     async get_project -> String { format!("{org_id}/{project_id}") }
+/// );
+/// # fn main() {}
+/// ```
+///
+/// The route can be compiled conditionally with e.g. `cfg = feature = "admin"`
+/// or `cfg = all(debug_assertions, feature = "admin")`, which is applied to
+/// every generated item.
+///
+/// Deprecated routes are declared with `deprecated = "2025-06-01"`, and
+/// optionally `sunset = "2025-12-31"` and `successor = "/v2/users"`. Their
+/// responses carry the `Deprecation`, `Sunset` and `Link` headers, and the
+/// deprecation is recorded in the route metadata.
+///
+/// A route of a group created with `versioned_group!` targets its versions
+/// with e.g. `group = Api, versions = [1, 2]`, and is registered into both
+/// `ApiV1` and `ApiV2` through the `ROUTE_<NAME>_V1` and `ROUTE_<NAME>_V2`
+/// statics. A route of a single version can use `versions = [2]`, or
+/// `group = ApiV2` directly.
+///
+/// The route can be registered at other paths of the group with e.g.
+/// `aliases = ["/v1/users/{id:u64}"]`, whose path parameters must be the same
+/// as `path`, in the same order. Aliases are listed next to the route by
+/// `print_routes`.
+///
+/// `GET` routes also answer `HEAD` requests, running the handler and dropping
+/// the body. A cheaper handler can be given with e.g.
+/// `head = { StatusCode::OK }`, which takes the same parameters as the handler
+/// but the body. With `auto_head = false`, `HEAD` requests are answered with
+/// `405 Method Not Allowed` instead, and an `Allow: GET` header.
+///
+/// Authorization guards run before the handler with e.g.
+/// `require = crate::auth::require_admin`, or `require = [authenticated,
+/// require_admin]` for several guards, which run in that order. A guard is an
+/// async function taking the request, optionally preceded by the state, and
+/// returning `Result<Request, E>` (see `Guard`). Requests it rejects never
+/// reach the handler. Guards are listed next to the route by `print_routes`.
+///
+/// With the `tracing` feature, the handler runs in an `INFO` span named after
+/// it, which records the method, the matched path, the path parameters and
+/// the response status. Routes opt out with `instrument = false`.
+///
+/// With the `metrics` feature, the requests of the route are counted in
+/// `http_requests_total`, labelled with the `route` name, `method` and
+/// `status`, and timed in `http_request_duration_seconds`, labelled with the
+/// `route` name and `method`. Routes opt out with `metrics = false`, and whole
+/// groups with `route_group!`'s `metrics` argument.
+///
+/// Routes can be toggled at run time with `enabled_if = path::to::function`,
+/// a `fn() -> bool` called each time the group is attached to a router. The
+/// route is skipped when it returns `false`. `cached_router!` only attaches
+/// the group once, so the function isn't called again by its next calls.
+///
+/// The generated code refers to this crate as `axum_distributed_routing`.
+/// Crates re-exporting the macros from a facade can point it at the
+/// re-export with e.g. `crate = ::platform_http::routing`. `route_group!`
+/// and the other declarative macros resolve the crate on their own.
+/// axum is referred to through the re-export of this crate, so the caller
+/// doesn't need an `axum` dependency under that name.
+#[proc_macro]
+pub fn route(attr: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let args = parse_macro_input!(attr as Args);
+
+    let krate = &args.krate;
+    // Copied onto every generated item
+    let cfg = args.cfg.as_ref().map(|cfg| quote::quote! { #[cfg(#cfg)] });
+    let span = if args.instrument {
+        route_span(&args)
+    } else {
+        None
+    };
+
+    let groups = route_groups(&args);
+    // The state, default error and rejection format are the same for every
+    // version
+    let group = &groups[0].0;
+
+    let params = HandlerParams::new(&args, group);
+    let handler_def = handler_def(&args, group, cfg.as_ref(), &params);
+    let handler_ref = match &args.handler_path {
+        Some(handler_path) => quote::quote! { #handler_path },
+        None => registered_handler(
+            &args,
+            group,
+            &args.name,
+            params.parts.iter().chain(&params.body),
+        ),
+    };
+    // The span is entered before the guards run, so rejections are recorded
+    let handler_ref = instrumented(
+        krate,
+        group,
+        span.as_ref(),
+        guarded(krate, group, &args.guards, handler_ref),
+    );
+    let (head_def, head) = head_handler(&args, group, cfg.as_ref(), span.as_ref(), &params);
+
+    let method = match args.method {
+        Method::Get => quote::quote! { get },
+        Method::Post => quote::quote! { post },
+        Method::Put => quote::quote! { put },
+        Method::Patch => quote::quote! { patch },
+        Method::Delete => quote::quote! { delete },
+        Method::Head => quote::quote! { head },
+        Method::Options => quote::quote! { options },
+        Method::Trace => quote::quote! { trace },
+        Method::Connect => quote::quote! { connect },
+    };
+    let layers = route_layers(&args, group);
+    let method_router = quote::quote! {
+        #krate::__private::axum::routing::#method(#handler_ref) #head #(#layers)*
+    };
+
+    // The functions of a versioned route are named after the version, e.g.
+    // `get_user_v1_url`. Routes renamed with `static_name` get their functions
+    // named after the static, as their handlers may share a name within the
+    // module
+    let versioned_names = args
+        .versions
+        .iter()
+        .map(|version| format!("{}_v{}", args.name, version.base10_digits()))
+        .chain(std::iter::repeat(args.name.to_string()))
+        .zip(&groups)
+        .map(|(name, (group, route_name))| match &args.static_name {
+            Some(_) => (route_name.to_string().to_lowercase(), (group, route_name)),
+            None => (name, (group, route_name)),
+        })
+        .collect::<Vec<_>>();
+
+    let url_functions = versioned_names
+        .iter()
+        .map(|(name, (group, route_name))| {
+            url_function(
+                &args,
+                cfg.as_ref(),
+                &Ident::new(&format!("{name}_url"), args.name.span()),
+                route_name,
+                group,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let client_functions = if cfg!(feature = "client") {
+        versioned_names
+            .iter()
+            .map(|(name, (group, _))| {
+                client_function(
+                    &args,
+                    cfg.as_ref(),
+                    &Ident::new(&format!("call_{name}"), args.name.span()),
+                    group,
+                )
+            })
+            .collect::<Vec<_>>()
+    } else {
+        Vec::new()
+    };
+
+    let state_check = state_check(&args, group, cfg.as_ref());
+    let garde_context_def = garde_context_def(
+        &args,
+        group,
+        cfg.as_ref(),
+        params.parts.iter().chain(&params.body),
+    );
+
+    let registrations = groups.iter().map(|(group, route_name)| {
+        registration(
+            &args,
+            cfg.as_ref(),
+            group,
+            route_name,
+            &handler_ref,
+            &method_router,
+            &layers,
+        )
+    });
+
+    let result = quote::quote! {
+        #handler_def
+
+        #head_def
+
+        #(#url_functions)*
+
+        #(#client_functions)*
+
+        #state_check
+
+        #garde_context_def
+
+        #(#registrations)*
+    };
+
+    result.into()
+}
+
+/// The groups a route is registered in, with the name of its static in each
+///
+/// A versioned route is registered into the group of each version, e.g.
+/// `ApiV1` and `ApiV2` for `group = Api, versions = [1, 2]`.
+fn route_groups(args: &Args) -> Vec<(Type, Ident)> {
+    let route_name = args.static_name.clone().unwrap_or_else(|| {
+        Ident::new(
+            &format!(
+                "ROUTE_{}",
+                stringcase::macro_case(args.name.to_string().as_str())
+            ),
+            proc_macro2::Span::call_site(),
+        )
+    });
+
+    if args.versions.is_empty() {
+        return vec![(args.group.clone(), route_name)];
+    }
+
+    args.versions
+        .iter()
+        .map(|version| {
+            let mut group = args.group.clone();
+            if let Type::Path(path) = &mut group
+                && let Some(segment) = path.path.segments.last_mut()
+            {
+                segment.ident = versioned_ident(&segment.ident, version);
+            }
+            let route_name = Ident::new(
+                &format!("{}_V{}", route_name, version.base10_digits()),
+                route_name.span(),
+            );
+            (group, route_name)
+        })
+        .collect()
+}
+
+/// A parameter of the generated handler, e.g. `Query(query): Query<Q>`
+struct Extractor {
+    /// The parameter, its attributes included
+    param: proc_macro2::TokenStream,
+    ty: proc_macro2::TokenStream,
+    /// With the `rejection-format` feature, the extractor whose rejection is
+    /// reported in the format of the group
+    formatted: Option<proc_macro2::TokenStream>,
+    /// The extractor wrapped in the extractor of the `validate` key, if any
+    validated: Option<proc_macro2::TokenStream>,
+}
+
+impl Extractor {
+    fn new(pat: proc_macro2::TokenStream, ty: proc_macro2::TokenStream) -> Self {
+        Self {
+            param: quote::quote! { #pat: #ty, },
+            ty,
+            formatted: None,
+            validated: None,
+        }
+    }
+
+    /// An extractor of the path parameters, query or body, whose rejection is
+    /// replaced with the `rejection` type of the route, and which is wrapped
+    /// in the extractor of the `validate` key when `validated`
+    fn rejecting(
+        args: &Args,
+        pat: proc_macro2::TokenStream,
+        ty: proc_macro2::TokenStream,
+        validated: bool,
+    ) -> Self {
+        let krate = &args.krate;
+        let validation = args.validate.as_ref().filter(|_| validated);
+        let formatted =
+            (cfg!(feature = "rejection-format") && args.rejection.is_none()).then(|| ty.clone());
+        let validated = validation.map(|_| ty.clone());
+        let (pat, ty) = with_rejection(krate, args.rejection.as_ref(), pat, ty);
+        let (pat, ty) = with_validation(args, validation, pat, ty);
+        Self {
+            formatted,
+            validated,
+            ..Self::new(pat, ty)
+        }
+    }
+}
+
+impl quote::ToTokens for Extractor {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.param.to_tokens(tokens);
+    }
+}
+
+/// The parameters of the generated handler, in the order they are extracted
+struct HandlerParams {
+    /// Every extractor but the one consuming the request, shared with the
+    /// HEAD handler
+    parts: Vec<Extractor>,
+    /// The extractor consuming the request, e.g. the body, which must be last
+    body: Option<Extractor>,
+    /// Statements run before the block of the handler
+    body_prelude: proc_macro2::TokenStream,
+}
+
+impl HandlerParams {
+    fn new(args: &Args, group: &Type) -> Self {
+        let krate = &args.krate;
+        let mut parts = Vec::new();
+
+        if args.all_path_params().next().is_some() {
+            let idents = args.all_path_params().map(|param| &param.binding);
+            let types = args.all_path_params().map(|param| &param.ty);
+            parts.push(Extractor::rejecting(
+                args,
+                quote::quote! { #krate::__private::axum::extract::Path((#(#idents),*)) },
+                quote::quote! { #krate::__private::axum::extract::Path<(#(#types),*)> },
+                false,
+            ));
+        }
+
+        if let Some(s) = &args.state {
+            parts.push(Extractor::new(
+                quote::quote! { #krate::__private::axum::extract::State(#s) },
+                quote::quote! { #krate::__private::axum::extract::State<<#group as #krate::GroupState>::State> },
+            ));
+        }
+
+        parts.extend(args.extensions.iter().map(|Binding { name, ty }| {
+            Extractor::new(
+                quote::quote! { #krate::__private::axum::Extension(#name) },
+                quote::quote! { #krate::__private::axum::Extension<#ty> },
+            )
+        }));
+
+        parts.extend(
+            args.headers
+                .iter()
+                .map(|Binding { name, ty }| Extractor::new(quote::quote! { #name }, quote::quote! { #ty })),
+        );
+
+        parts.extend(
+            args.parts
+                .iter()
+                .map(|Binding { name, ty }| Extractor::new(quote::quote! { #name }, part_type(krate, ty))),
+        );
+
+        if let Some((name, ty)) = &args.connect_info {
+            let ty = ty.as_ref().map_or_else(
+                || quote::quote! { ::std::net::SocketAddr },
+                |ty| quote::quote! { #ty },
+            );
+            parts.push(Extractor::new(
+                quote::quote! { #krate::__private::axum::extract::ConnectInfo(#name) },
+                quote::quote! { #krate::__private::axum::extract::ConnectInfo<#ty> },
+            ));
+        }
+
+        if let Some(name) = &args.matched_path {
+            parts.push(Extractor::new(
+                quote::quote! { #name },
+                quote::quote! { #krate::__private::axum::extract::MatchedPath },
+            ));
+        }
+
+        if let Some(name) = &args.nested_path {
+            parts.push(Extractor::new(
+                quote::quote! { #name },
+                quote::quote! { #krate::__private::axum::extract::NestedPath },
+            ));
+        }
+
+        let cookie_jar = match &args.cookies {
+            Some((CookieJar::Plain, name)) => Some((name, quote::quote! { CookieJar })),
+            Some((CookieJar::Signed, name)) => Some((name, quote::quote! { SignedCookieJar })),
+            Some((CookieJar::Private, name)) => Some((name, quote::quote! { PrivateCookieJar })),
+            None => None,
+        };
+        if let Some((name, jar)) = cookie_jar {
+            parts.push(Extractor::new(
+                quote::quote! { #name },
+                quote::quote! { #krate::cookie::#jar },
             ));
         }
 
-        Ok((path, path_params))
+        if let Some(q) = &args.query_params {
+            let (pat, ty) = match unwrap_type(q, "Option") {
+                Some(q) => (
+                    quote::quote! { #krate::OptionalQuery(query) },
+                    quote::quote! { #krate::OptionalQuery<#q> },
+                ),
+                None => (
+                    quote::quote! { #krate::__private::axum::extract::Query(query) },
+                    quote::quote! { #krate::__private::axum::extract::Query<#q> },
+                ),
+            };
+            parts.push(Extractor::rejecting(args, pat, ty, true));
+        }
+
+        parts.extend(args.parameters.iter().map(|PatType { attrs, pat, ty, .. }| Extractor {
+            param: quote::quote! { #(#attrs)* #pat: #ty, },
+            ..Extractor::new(quote::quote! { #pat }, quote::quote! { #ty })
+        }));
+
+        let (body, body_prelude) = match &args.body_params {
+            Some(Body::Type(b)) => {
+                let (pat, ty) = match unwrap_type(b, "FormOrJson") {
+                    Some(b) => (
+                        quote::quote! { #krate::FormOrJson(body) },
+                        quote::quote! { #krate::FormOrJson<#b> },
+                    ),
+                    None => (quote::quote! { body }, quote::quote! { #b }),
+                };
+                (
+                    Some(Extractor::rejecting(args, pat, ty, true)),
+                    quote::quote! {},
+                )
+            }
+            Some(Body::Multipart) => {
+                let body = Extractor::rejecting(
+                    args,
+                    quote::quote! { mut body },
+                    quote::quote! { #krate::__private::axum::extract::Multipart },
+                    false,
+                );
+                let param = &body.param;
+                (
+                    Some(Extractor {
+                        param: quote::quote! { #[allow(unused_mut)] #param },
+                        ..body
+                    }),
+                    quote::quote! {},
+                )
+            }
+            Some(Body::Stream) => (
+                Some(Extractor::new(
+                    quote::quote! { body },
+                    quote::quote! { #krate::__private::axum::body::Body },
+                )),
+                quote::quote! {
+                    #[allow(unused_mut)]
+                    let mut body = body.into_data_stream();
+                },
+            ),
+            Some(Body::Bytes) => (
+                Some(Extractor::rejecting(
+                    args,
+                    quote::quote! { body },
+                    quote::quote! { #krate::__private::axum::body::Bytes },
+                    false,
+                )),
+                quote::quote! {},
+            ),
+            // The request must be the last extractor, like the body it holds
+            None if args.request => (
+                Some(Extractor::new(
+                    quote::quote! { request },
+                    quote::quote! { #krate::__private::axum::extract::Request },
+                )),
+                quote::quote! {},
+            ),
+            None if matches!(args.handler_kind, HandlerKind::WebSocket(_)) => (
+                Some(Extractor::new(
+                    quote::quote! { __ws },
+                    quote::quote! { #krate::__private::axum::extract::WebSocketUpgrade },
+                )),
+                quote::quote! {},
+            ),
+            None => (None, quote::quote! {}),
+        };
+
+        Self {
+            parts,
+            body,
+            body_prelude,
+        }
     }
 }
 
-/// Creates a route and add it to the group
+/// The handler registered for the generated handler `name` taking
+/// `extractors`
 ///
-/// # Example
-/// ```
-/// route!(
-///     group = Routes,
-///     path = "/echo/{str:String}",
-///     method = GET,
-///     async test_fn -> String { str }
-/// );
-/// ```
-#[proc_macro]
-pub fn route(attr: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    // TODO: cleanup
-    let args = parse_macro_input!(attr as Args);
+/// With the `rejection-format` feature, the extractors whose rejection is
+/// reported in the format of the group are extracted by a closure wrapping
+/// them in `GroupRejection`, which then calls `name`, so that the signature of
+/// `name` doesn't depend on the feature.
+fn registered_handler<'a>(
+    args: &Args,
+    group: &Type,
+    name: &Ident,
+    extractors: impl Iterator<Item = &'a Extractor> + Clone,
+) -> proc_macro2::TokenStream {
+    let krate = &args.krate;
+    if extractors.clone().all(|extractor| extractor.formatted.is_none()) {
+        return quote::quote! { #name };
+    }
 
-    let path_params = args.path_params;
-    let path_idents = path_params.keys().collect::<Vec<_>>();
-    let path_types = path_params.values().collect::<Vec<_>>();
+    let (params, arguments): (Vec<_>, Vec<_>) = extractors
+        .enumerate()
+        .map(|(i, extractor)| {
+            let arg = Ident::new(&format!("__arg{i}"), proc_macro2::Span::call_site());
+            match &extractor.formatted {
+                Some(ty) => {
+                    let pat = quote::quote! { #krate::GroupRejection(#arg, _) };
+                    let ty = quote::quote! { #krate::GroupRejection<#ty, #group> };
+                    match args
+                        .validate
+                        .as_ref()
+                        .filter(|_| extractor.validated.is_some())
+                    {
+                        Some(validation) => {
+                            let (pat, ty) = validation.wrap(args, pat, ty);
+                            let (arg, _) =
+                                validation.wrap(args, quote::quote! { #arg }, ty.clone());
+                            (quote::quote! { #pat: #ty }, arg)
+                        }
+                        None => (quote::quote! { #pat: #ty }, quote::quote! { #arg }),
+                    }
+                }
+                None => {
+                    let ty = &extractor.ty;
+                    (quote::quote! { #arg: #ty }, quote::quote! { #arg })
+                }
+            }
+        })
+        .unzip();
 
-    let path_params = if path_params.is_empty() {
-        quote::quote! {}
-    } else {
+    quote::quote! {
+        |#(#params),*| async move { #name(#(#arguments),*).await }
+    }
+}
+
+/// Generates the provider of the context of `validate = garde(context = ...)`,
+/// which implements `GardeContext` for each type validated by the route
+fn garde_context_def<'a>(
+    args: &Args,
+    group: &Type,
+    cfg: Option<&proc_macro2::TokenStream>,
+    extractors: impl Iterator<Item = &'a Extractor>,
+) -> proc_macro2::TokenStream {
+    let Some(Validation::Garde(Some(context))) = &args.validate else {
+        return quote::quote! {};
+    };
+    let Some(provider) = args
+        .validate
+        .as_ref()
+        .and_then(|v| v.context_provider(args))
+    else {
+        return quote::quote! {};
+    };
+
+    let krate = &args.krate;
+    let context = match context {
+        GardeContext::Function(function) => quote::quote! { #function(state) },
+        GardeContext::Expr(expr) => quote::quote! { #expr },
+        GardeContext::State => unreachable!(),
+    };
+    let impls = extractors.filter_map(|extractor| extractor.validated.as_ref()).map(|ty| {
         quote::quote! {
-            axum::extract::Path((#(#path_idents),*)): axum::extract::Path<(#(#path_types),*)>,
+            #cfg
+            impl #krate::GardeContext<
+                <#group as #krate::GroupState>::State,
+                <#ty as ::std::ops::Deref>::Target,
+            > for #provider {
+                #[allow(unused_variables)]
+                fn context(
+                    state: &<#group as #krate::GroupState>::State,
+                ) -> <<#ty as ::std::ops::Deref>::Target as #krate::__private::garde::Validate>::Context {
+                    #context
+                }
+            }
         }
+    });
+
+    quote::quote! {
+        #cfg
+        #[doc(hidden)]
+        #[allow(non_camel_case_types)]
+        pub(crate) struct #provider;
+
+        #(#impls)*
+    }
+}
+
+/// Checks at compile time that a route with a `state` key is in a group with
+/// a state
+fn state_check(
+    args: &Args,
+    group: &Type,
+    cfg: Option<&proc_macro2::TokenStream>,
+) -> proc_macro2::TokenStream {
+    let Some(s) = &args.state else {
+        return quote::quote! {};
     };
 
-    let query_params = if let Some(q) = args.query_params {
-        quote::quote! { axum::extract::Query(query): axum::extract::Query<#q>, }
-    } else {
-        quote::quote! {}
+    let krate = &args.krate;
+    quote::quote_spanned! {s.span()=>
+        #cfg
+        const _: () = {
+            use #krate::__private::UnitStateFallback;
+            assert!(
+                !#krate::__private::UnitStateCheck::<<#group as #krate::GroupState>::State>::IS_UNIT,
+                "the `state` key requires a group with a state, but this group's state is `()`",
+            );
+        };
+    }
+}
+
+/// Generates the handler function of a route declared with a block, nothing
+/// for a `handler` path
+fn handler_def(
+    args: &Args,
+    group: &Type,
+    cfg: Option<&proc_macro2::TokenStream>,
+    params: &HandlerParams,
+) -> proc_macro2::TokenStream {
+    let Some(block) = &args.handler else {
+        return quote::quote! {};
+    };
+
+    let krate = &args.krate;
+    let name = &args.name;
+    let stmts = &block.stmts;
+    let openapi = openapi_attribute(args);
+    let handler_attributes = &args.handler_attributes;
+    let handler_attributes = quote::quote! { #cfg #openapi #(#handler_attributes)* };
+    let HandlerParams {
+        parts,
+        body,
+        body_prelude,
+    } = params;
+
+    match &args.handler_kind {
+        HandlerKind::Async => {
+            // Errors about the output of a blocking block point at the return
+            // type
+            let output_span = args
+                .return_type
+                .as_ref()
+                .map_or_else(|| name.span(), syn::spanned::Spanned::span);
+            // Without an explicit return type, the tail expression decides
+            let return_type = args.return_type.as_ref().map_or_else(
+                || {
+                    if args.blocking {
+                        quote::quote! { _ }
+                    } else {
+                        quote::quote! { impl #krate::__private::axum::response::IntoResponse }
+                    }
+                },
+                |ty| quote::quote! { #ty },
+            );
+            let return_type = match &args.error {
+                Some(ErrorType::Type(error)) => quote::quote! { Result<#return_type, #error> },
+                Some(ErrorType::Anyhow) => quote::quote! {
+                    Result<#return_type, #krate::AnyhowError>
+                },
+                Some(ErrorType::GroupDefault) => quote::quote! {
+                    Result<#return_type, <#group as #krate::DefaultError>::Error>
+                },
+                None => return_type,
+            };
+
+            if args.blocking {
+                // Spanned on its own, the path of the crate included, so that
+                // the bounds of the output are reported at the return type
+                let krate_path = quote::quote! { #krate }.into_iter().map(|mut token| {
+                    token.set_span(output_span);
+                    token
+                });
+                let run_blocking = quote::quote_spanned! {output_span=>
+                    #(#krate_path)*::__private::run_blocking(__block).await
+                };
+                quote::quote! {
+                    #handler_attributes
+                    pub(crate) async fn #name(#(#parts)* #body) -> #krate::__private::axum::response::Response {
+                        #body_prelude
+                        // The extractors are moved into the block
+                        let __block = move || -> #return_type { #(#stmts)* };
+                        #run_blocking
+                    }
+                }
+            } else {
+                quote::quote! {
+                    #handler_attributes
+                    pub(crate) async fn #name(#(#parts)* #body) -> #return_type {
+                        #body_prelude
+                        #(#stmts)*
+                    }
+                }
+            }
+        }
+        HandlerKind::WebSocket(socket) => {
+            // A bare identifier is made mutable, as receiving and sending both
+            // need a mutable socket
+            let socket = match socket {
+                Pat::Ident(pat) if pat.mutability.is_none() && pat.by_ref.is_none() => {
+                    let ident = &pat.ident;
+                    quote::quote! { #[allow(unused_mut)] mut #ident }
+                }
+                socket => quote::quote! { #socket },
+            };
+
+            quote::quote! {
+                #handler_attributes
+                pub(crate) async fn #name(#(#parts)* #body) -> #krate::__private::axum::response::Response {
+                    __ws.on_upgrade(move |#socket: #krate::__private::axum::extract::ws::WebSocket| async move #block)
+                }
+            }
+        }
+        HandlerKind::Sse => {
+            let keep_alive = if let Some(interval) = &args.keep_alive {
+                quote::quote! { #krate::__private::axum::response::sse::KeepAlive::default().interval(#interval) }
+            } else {
+                quote::quote! { #krate::__private::axum::response::sse::KeepAlive::default() }
+            };
+
+            quote::quote! {
+                #handler_attributes
+                pub(crate) async fn #name(#(#parts)* #body) -> #krate::__private::axum::response::Response {
+                    #body_prelude
+                    #krate::__private::axum::response::IntoResponse::into_response(
+                        #krate::__private::axum::response::sse::Sse::new(#block).keep_alive(#keep_alive)
+                    )
+                }
+            }
+        }
+    }
+}
+
+/// Generates the HEAD handler of a route and the call adding it to the
+/// method router
+///
+/// The HEAD handler takes the same parameters as the GET one but the body.
+fn head_handler(
+    args: &Args,
+    group: &Type,
+    cfg: Option<&proc_macro2::TokenStream>,
+    span: Option<&proc_macro2::TokenStream>,
+    params: &HandlerParams,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let krate = &args.krate;
+
+    match &args.head {
+        Some(block) => {
+            let head_name = Ident::new(&format!("{}_head", args.name), args.name.span());
+            let stmts = &block.stmts;
+            let parts = &params.parts;
+            let head = instrumented(
+                krate,
+                group,
+                span,
+                guarded(
+                    krate,
+                    group,
+                    &args.guards,
+                    registered_handler(args, group, &head_name, parts.iter()),
+                ),
+            );
+            (
+                quote::quote! {
+                    #cfg
+                    #[allow(unused_variables)]
+                    pub(crate) async fn #head_name(#(#parts)*) -> impl #krate::__private::axum::response::IntoResponse {
+                        #(#stmts)*
+                    }
+                },
+                quote::quote! { .head(#head) },
+            )
+        }
+        None if !args.auto_head => (
+            quote::quote! {},
+            quote::quote! { .head(#krate::__private::head_not_allowed) },
+        ),
+        None => (quote::quote! {}, quote::quote! {}),
+    }
+}
+
+/// Generates the layers applied to the method router of a route, innermost
+/// first
+fn route_layers(args: &Args, group: &Type) -> Vec<proc_macro2::TokenStream> {
+    let krate = &args.krate;
+    let mut layers = Vec::new();
+
+    // A return type like `(StatusCode, T)` sets the status of the response
+    // itself, which the `status` key then leaves untouched
+    let sets_status = args.return_type.as_ref().is_some_and(sets_status_code);
+    if let Some(status) = args.status.as_ref().filter(|_| !sets_status) {
+        let status = status.as_u16(krate);
+        layers.push(quote::quote! {
+            .route_layer(#krate::__private::axum::middleware::map_response(
+                #krate::__private::default_status::<#status>,
+            ))
+        });
+    }
+
+    if !args.response_headers.is_empty() {
+        let set_headers = args.response_headers.iter().map(
+            |ResponseHeader {
+                 name,
+                 value,
+                 append,
+             }| {
+                quote::quote! {
+                    #krate::__private::set_response_header(&mut response, #name, #value, #append);
+                }
+            },
+        );
+
+        layers.push(quote::quote! {
+            .route_layer(#krate::__private::axum::middleware::map_response(
+                |mut response: #krate::__private::axum::response::Response| async move {
+                    #(#set_headers)*
+                    response
+                },
+            ))
+        });
+    }
+
+    // Outside the response headers, which take precedence, and inside the
+    // layer of the group's `cache_control`
+    if let Some(value) = &args.cache_control {
+        layers.push(quote::quote! {
+            .route_layer(#krate::__private::axum::middleware::map_response(
+                |mut response: #krate::__private::axum::response::Response| async move {
+                    #krate::__private::set_cache_control(&mut response, #value);
+                    response
+                },
+            ))
+        });
+    }
+
+    if let Some(limit) = &args.body_limit {
+        layers.push(quote::quote! { .route_layer(#krate::__private::axum::extract::DefaultBodyLimit::max(#limit)) });
+    }
+
+    // `sse` and `ws` routes are only compressed when they ask for it, which
+    // is refused
+    let compression = match &args.compression {
+        Some(compression) => Some(quote::quote! { #compression }),
+        None if matches!(args.handler_kind, HandlerKind::Async) => {
+            Some(quote::quote! { <#group as #krate::Route>::compression() })
+        }
+        None => None,
     };
+    // Outside the layers setting headers, so the response is complete when
+    // compressed
+    if let Some(enabled) = compression.filter(|_| cfg!(feature = "compression")) {
+        layers.push(quote::quote! {
+            .route_layer(#krate::__private::compression_layer(#enabled))
+        });
+    }
 
-    let body_params = if let Some(b) = args.body_params {
-        quote::quote! { body: #b, }
+    // Outermost, so the recorded status is the one sent
+    if cfg!(feature = "metrics") && args.metrics {
+        let name = args.name.to_string();
+        let method = args.method.variant(krate);
+        layers.push(quote::quote! {
+            .route_layer(#krate::__private::axum::middleware::from_fn(
+                |request: #krate::__private::axum::extract::Request,
+                 next: #krate::__private::axum::middleware::Next| {
+                    #krate::__private::record_metrics::<#group>(#name, #method, request, next)
+                },
+            ))
+        });
+    }
+
+    layers
+}
+
+/// Generates the static registering a route into `group`, and the description
+/// of the route
+fn registration(
+    args: &Args,
+    cfg: Option<&proc_macro2::TokenStream>,
+    group: &Type,
+    route_name: &Ident,
+    handler_ref: &proc_macro2::TokenStream,
+    method_router: &proc_macro2::TokenStream,
+    layers: &[proc_macro2::TokenStream],
+) -> proc_macro2::TokenStream {
+    let krate = &args.krate;
+    let path = &args.path;
+    let name = args.name.to_string();
+    let group_name = type_name(group);
+    let method = args.method.variant(krate);
+
+    // axum captures are written without their type, e.g. `/users/{id}`
+    let template = &args.template;
+    let alias_templates = &args.alias_templates;
+    // Aliases are documented as operations of their own
+    let paths = std::iter::once(quote::quote! { #template })
+        .chain(alias_templates.iter().map(|alias| quote::quote! { #alias }))
+        .collect::<Vec<_>>();
+    // The `impl IntoResponse` output of inline handlers without a return type
+    // or returning `impl Trait` can't be documented
+    let undocumented_output = args.handler.is_some()
+        && matches!(args.handler_kind, HandlerKind::Async)
+        && !args.blocking
+        && args
+            .return_type
+            .as_ref()
+            .is_none_or(|ty| matches!(ty, Type::ImplTrait(_)));
+    let api_handler = if args.aide && !undocumented_output {
+        api_handler(
+            krate,
+            args.enabled_if.as_ref(),
+            &args.method,
+            handler_ref,
+            layers,
+            &paths,
+            api_docs(args, group),
+        )
     } else {
         quote::quote! {}
     };
 
-    let route_name = Ident::new(
-        &format!(
-            "ROUTE_{}",
-            stringcase::macro_case(args.name.to_string().as_str())
-        ),
-        proc_macro2::Span::call_site(),
-    );
-    let name = args.name;
-    let path = args.path;
-    let parameters = if !args.parameters.trailing_punct() && !args.parameters.is_empty() {
-        let parameters = args.parameters;
-        quote::quote! { #parameters, }
+    let route = if alias_templates.is_empty() {
+        quote::quote! { #krate::__private::collect_leaf::<#group>(routes, #template, #method_router); }
     } else {
-        let parameters = args.parameters;
-        quote::quote! { #parameters }
+        // Aliases share the method router of the primary path
+        quote::quote! {
+            let method_router = #method_router;
+            #krate::__private::collect_leaf::<#group>(routes, #template, method_router.clone());
+            #(#krate::__private::collect_leaf::<#group>(routes, #alias_templates, method_router.clone());)*
+        }
     };
-    let return_type = args.return_type;
-    let block = args.handler;
-    let group = args.group;
-    let handler_attributes = args.handler_attributes;
-
-    let handler_def = quote::quote! {
-        #(#handler_attributes)*
-        async fn #name(#path_params #query_params #parameters #body_params) -> #return_type #block
+    // The path parameters are extracted as a tuple, which must also hold the
+    // captures of the group
+    let route = if !args.inherited.is_empty() || !args.path_params.is_empty() {
+        let inherited_params = args.inherited.iter().map(|PathParam { name, ty, .. }| {
+            let ty = type_name(ty);
+            quote::quote! { (#name, #ty) }
+        });
+        quote::quote! {
+            #krate::__private::check_inherited::<#group>(#name, &[#(#inherited_params),*]);
+            #route
+        }
+    } else {
+        route
     };
+    let route = match &args.enabled_if {
+        Some(enabled_if) => quote::quote! { if #enabled_if() { #route } },
+        None => route,
+    };
+
+    // Doc comments on the handler are used as the description by default
+    let summary = option_tokens(args.summary.as_ref().map(|s| s.value()));
+    let description = option_tokens(
+        args.description
+            .as_ref()
+            .map(|d| d.value())
+            .or_else(|| doc_comment(&args.handler_attributes)),
+    );
+    let tags = &args.tags;
+    let operation = operation_metadata(args);
+
+    quote::quote! {
+        #cfg
+        #krate::__registry! {
+            submit #group: #group,
+            pub static #route_name = #group::new(#path, |r, _| r)
+                .with_method_routes(|routes| { #route })
+                .with_metadata(#krate::RouteMetadata {
+                    summary: #summary,
+                    description: #description,
+                    tags: &[#(#tags),*],
+                    #operation
+                })
+                #api_handler
+        }
+
+        #cfg
+        #krate::__registry! {
+            submit #krate::__private::ROUTE_INFOS: #krate::RouteInfo,
+            #krate::RouteInfo {
+                group: #group_name,
+                path: #path,
+                method: Some(#method),
+                name: #name,
+                kind: #krate::RouteKind::Leaf,
+            }
+        }
+    }
+}
 
-    let handler = match args.method {
-        Method::Get => quote::quote! { axum::routing::get(#name) },
-        Method::Post => quote::quote! { axum::routing::post(#name) },
-        Method::Put => quote::quote! { axum::routing::put(#name) },
-        Method::Patch => quote::quote! { axum::routing::patch(#name) },
-        Method::Delete => quote::quote! { axum::routing::delete(#name) },
-        Method::Head => quote::quote! { axum::routing::head(#name) },
-        Method::Options => quote::quote! { axum::routing::options(#name) },
-        Method::Trace => quote::quote! { axum::routing::trace(#name) },
-        Method::Connect => quote::quote! { axum::routing::connect(#name) },
+/// The attribute form of `route!`, registering the function it is applied to
+/// as the handler of a route
+///
+/// ```
+/// # use axum::Json;
+/// # use axum::extract::{Path, State};
+/// # use axum_distributed_routing::route_group;
+/// use axum_distributed_routing::attr;
+/// # #[derive(Clone)]
+/// # struct AppState;
+/// # impl AppState {
+/// #     fn user(&self, id: u64) -> User {
+/// #         User { id }
+/// #     }
+/// # }
+/// # #[derive(serde::Serialize)]
+/// # struct User {
+/// #     id: u64,
+/// # }
+/// # route_group!(Api, AppState);
+///
+/// #[attr::route(group = Api, path = "/users/{id:u64}", method = GET)]
+/// async fn get_user(Path(id): Path<u64>, State(state): State<AppState>) -> Json<User> {
+///     Json(state.user(id))
+/// }
+/// # fn main() {}
+/// ```
+///
+/// This is equivalent to declaring the function and calling
+/// `route!(group = Api, path = "/users/{id:u64}", method = GET, handler = get_user)`:
+/// the function declares its own extractors, so the keys generating
+/// extractors (`query`, `body`, `state`, ...) are not available. The number of
+/// path parameters of the route is checked against the tuple of the `Path`
+/// extractor of the function, if any.
+///
+/// It is exported as `attr::route`, as `route` is the function-like macro.
+#[proc_macro_attribute]
+pub fn route_attribute(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let attr = proc_macro2::TokenStream::from(attr);
+    let function = parse_macro_input!(item as syn::ItemFn);
+    match route_attribute_impl(attr, &function) {
+        Ok(registration) => quote::quote! {
+            #function
+
+            #registration
+        },
+        Err(err) => {
+            let err = err.to_compile_error();
+            quote::quote! {
+                #function
+
+                #err
+            }
+        }
+    }
+    .into()
+}
+
+fn route_attribute_impl(
+    attr: proc_macro2::TokenStream,
+    function: &syn::ItemFn,
+) -> syn::Result<proc_macro2::TokenStream> {
+    if function.sig.asyncness.is_none() {
+        return Err(syn::Error::new_spanned(
+            function.sig.fn_token,
+            "`route` expects an `async fn`",
+        ));
+    }
+
+    let ident = &function.sig.ident;
+    let separator = match attr.clone().into_iter().last() {
+        None => quote::quote! {},
+        Some(proc_macro2::TokenTree::Punct(punct)) if punct.as_char() == ',' => quote::quote! {},
+        Some(_) => quote::quote! { , },
     };
+    let tokens = quote::quote! { #attr #separator handler = #ident };
+    let args = syn::parse2::<Args>(tokens.clone()).map_err(|err| {
+        // The `handler` key is implied by the attribute
+        match err.to_string().strip_prefix("`handler` cannot be combined") {
+            Some(rest) => syn::Error::new(
+                err.span(),
+                format!("The attribute form of `route` cannot be combined{rest}"),
+            ),
+            None => err,
+        }
+    })?;
 
-    let result = quote::quote! {
-        #handler_def
+    // A `Path<(A, B)>` extractor must have as many elements as the route has
+    // path parameters, other `Path` extractors (e.g. of a struct) can't be
+    // checked
+    for input in &function.sig.inputs {
+        let syn::FnArg::Typed(input) = input else {
+            continue;
+        };
+        let Some(extracted) = unwrap_type(&input.ty, "Path") else {
+            continue;
+        };
+        let count = args.all_path_params().count();
+        if count == 0 {
+            return Err(syn::Error::new_spanned(
+                &input.ty,
+                "The route has no path parameters, but the function extracts a `Path`",
+            ));
+        }
+        if let Type::Tuple(tuple) = extracted
+            && tuple.elems.len() != count
+        {
+            return Err(syn::Error::new_spanned(
+                &input.ty,
+                format!(
+                    "The route has {} path parameters, but the function extracts {}",
+                    count,
+                    tuple.elems.len()
+                ),
+            ));
+        }
+    }
+
+    Ok(route(tokens.into()).into())
+}
+
+struct VersionedGroup {
+    vis: syn::Visibility,
+    name: Ident,
+    state: Type,
+    parent: Ident,
+    versions: Vec<LitInt>,
+    krate: syn::Path,
+}
+
+impl Parse for VersionedGroup {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let vis = input.parse()?;
+        let name = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let state = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let parent = input.parse()?;
+        input.parse::<Token![,]>()?;
+
+        let key: Ident = input.parse()?;
+        if key != "versions" {
+            return Err(syn::Error::new(key.span(), "Expected `versions`"));
+        }
+        input.parse::<Token![=]>()?;
+        let versions = parse_versions(input)?;
+
+        let mut krate = None;
+        if input.peek(Token![,]) && input.peek2(Token![crate]) {
+            input.parse::<Token![,]>()?;
+            input.parse::<Token![crate]>()?;
+            input.parse::<Token![=]>()?;
+            krate = Some(input.call(syn::Path::parse_mod_style)?);
+        }
+        input.parse::<Option<Token![,]>>()?;
+
+        Ok(VersionedGroup {
+            vis,
+            name,
+            state,
+            parent,
+            versions,
+            krate: krate.unwrap_or_else(|| syn::parse_quote!(axum_distributed_routing)),
+        })
+    }
+}
+
+/// Creates a route group per API version, nested in `parent` at `/v1`,
+/// `/v2`, etc.
+///
+/// ```
+/// # use axum_distributed_routing::{route_group, versioned_group};
+/// # #[derive(Clone)]
+/// # pub struct AppState;
+/// # route_group!(pub Routes, AppState);
+/// versioned_group!(pub Api, AppState, Routes, versions = [1, 2]);
+/// # fn main() {}
+/// ```
+///
+/// This is equivalent to:
+///
+/// ```
+/// # use axum_distributed_routing::route_group;
+/// # #[derive(Clone)]
+/// # pub struct AppState;
+/// # route_group!(pub Routes, AppState);
+/// route_group!(pub ApiV1, AppState, Routes, "/v1");
+/// route_group!(pub ApiV2, AppState, Routes, "/v2");
+/// # fn main() {}
+/// ```
+///
+/// Routes shared by several versions are declared once with e.g.
+/// `route!(group = Api, versions = [1, 2], ...)`, see `route!`.
+///
+/// Like `route!`, the generated code refers to this crate as
+/// `axum_distributed_routing`, which can be changed with a trailing
+/// `crate = ::platform_http::routing`.
+#[proc_macro]
+pub fn versioned_group(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let VersionedGroup {
+        vis,
+        name,
+        state,
+        parent,
+        versions,
+        krate,
+    } = parse_macro_input!(input as VersionedGroup);
+
+    let groups = versions.iter().map(|version| {
+        let name = versioned_ident(&name, version);
+        let path = LitStr::new(&format!("/v{}", version.base10_digits()), version.span());
+        quote::quote! {
+            #krate::route_group!(#vis #name, #state, #parent, #path);
+        }
+    });
+
+    quote::quote! { #(#groups)* }.into()
+}
+
+struct CrudRoutes {
+    group: Type,
+    path: LitStr,
+    id: Option<Type>,
+    /// The handlers of the endpoints, as `(key, handler)`
+    handlers: Vec<(Ident, syn::Path)>,
+    krate: Option<syn::Path>,
+}
+
+impl Parse for CrudRoutes {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut group = None;
+        let mut path = None;
+        let mut id = None;
+        let mut handlers = Vec::<(Ident, syn::Path)>::new();
+        let mut krate = None;
+
+        while !input.is_empty() {
+            if input.parse::<Option<Token![crate]>>()?.is_some() {
+                input.parse::<Token![=]>()?;
+                krate = Some(input.call(syn::Path::parse_mod_style)?);
+            } else {
+                let key: Ident = input.parse()?;
+                input.parse::<Token![=]>()?;
+                let duplicate = match key.to_string().as_str() {
+                    "group" => group.replace(input.parse()?).is_some(),
+                    "path" => path.replace(input.parse()?).is_some(),
+                    "id" => id.replace(input.parse()?).is_some(),
+                    "list" | "get" | "create" | "update" | "delete" => {
+                        let duplicate = handlers.iter().any(|(other, _)| *other == key);
+                        handlers.push((key.clone(), input.parse()?));
+                        duplicate
+                    }
+                    _ => {
+                        return Err(syn::Error::new(
+                            key.span(),
+                            format!(
+                                "Unknown key `{key}`, expected `group`, `path`, `id`, `list`, `get`, `create`, `update`, `delete` or `crate`"
+                            ),
+                        ));
+                    }
+                };
+                if duplicate {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!("`{key}` is declared twice"),
+                    ));
+                }
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        let missing = |key| syn::Error::new(input.span(), format!("Expected `{key}`"));
+        let group = group.ok_or_else(|| missing("group"))?;
+        let path = path.ok_or_else(|| missing("path"))?;
+        if handlers.is_empty() {
+            return Err(syn::Error::new(
+                input.span(),
+                "Expected at least one of `list`, `get`, `create`, `update` or `delete`",
+            ));
+        }
+        if id.is_none()
+            && let Some((key, _)) = handlers
+                .iter()
+                .find(|(key, _)| key != "list" && key != "create")
+        {
+            return Err(syn::Error::new(
+                key.span(),
+                format!("`{key}` targets `{{id}}`, whose type must be declared with `id`"),
+            ));
+        }
+
+        Ok(CrudRoutes {
+            group,
+            path,
+            id,
+            handlers,
+            krate,
+        })
+    }
+}
+
+/// Registers the standard REST endpoints of a resource, each handled by an
+/// existing async function
+///
+/// ```
+/// # use axum::Json;
+/// # use axum::extract::Path;
+/// # use axum_distributed_routing::{route_group, crud_routes};
+/// # #[derive(serde::Deserialize, serde::Serialize)]
+/// # struct Widget {
+/// #     name: String,
+/// # }
+/// # route_group!(Api, ());
+/// # async fn list_widgets() -> Json<Vec<Widget>> { Json(Vec::new()) }
+/// # async fn get_widget(Path(_id): Path<u64>) -> Json<Widget> { unimplemented!() }
+/// # async fn create_widget(Json(widget): Json<Widget>) -> Json<Widget> { Json(widget) }
+/// # async fn update_widget(Path(_id): Path<u64>, Json(widget): Json<Widget>) -> Json<Widget> { Json(widget) }
+/// # async fn delete_widget(Path(_id): Path<u64>) {}
+/// crud_routes!(
+///     group = Api,
+///     path = "/widgets",
+///     id = u64,
+///     list = list_widgets,
+///     get = get_widget,
+///     create = create_widget,
+///     update = update_widget,
+///     delete = delete_widget,
+/// );
+/// # fn main() {}
+/// ```
+///
+/// This is equivalent to:
+///
+/// ```
+/// # use axum::Json;
+/// # use axum::extract::Path;
+/// # use axum_distributed_routing::{route_group, route};
+/// # #[derive(serde::Deserialize, serde::Serialize)]
+/// # struct Widget {
+/// #     name: String,
+/// # }
+/// # route_group!(Api, ());
+/// # async fn list_widgets() -> Json<Vec<Widget>> { Json(Vec::new()) }
+/// # async fn get_widget(Path(_id): Path<u64>) -> Json<Widget> { unimplemented!() }
+/// # async fn create_widget(Json(widget): Json<Widget>) -> Json<Widget> { Json(widget) }
+/// # async fn update_widget(Path(_id): Path<u64>, Json(widget): Json<Widget>) -> Json<Widget> { Json(widget) }
+/// # async fn delete_widget(Path(_id): Path<u64>) {}
+/// route!(group = Api, path = "/widgets", method = GET, handler = list_widgets);
+/// route!(group = Api, path = "/widgets/{id:u64}", method = GET, handler = get_widget);
+/// route!(group = Api, path = "/widgets", method = POST, handler = create_widget);
+/// route!(group = Api, path = "/widgets/{id:u64}", method = PUT, handler = update_widget);
+/// route!(group = Api, path = "/widgets/{id:u64}", method = DELETE, handler = delete_widget);
+/// # fn main() {}
+/// ```
+///
+/// The endpoints whose key is omitted are not registered, and `id` is only
+/// required by `get`, `update` and `delete`. The functions extract their
+/// inputs themselves, like with `route!`'s `handler` key, e.g. the id with
+/// `Path<u64>`.
+///
+/// Like `route!`, the generated code refers to this crate as
+/// `axum_distributed_routing`, which can be changed with
+/// `crate = ::platform_http::routing`.
+#[proc_macro]
+pub fn crud_routes(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let CrudRoutes {
+        group,
+        path,
+        id,
+        handlers,
+        krate,
+    } = parse_macro_input!(input as CrudRoutes);
+
+    let collection = path.value();
+    let item = id.map(|id| {
+        LitStr::new(
+            &format!(
+                "{}/{{id:{}}}",
+                collection.trim_end_matches('/'),
+                type_name(&id)
+            ),
+            path.span(),
+        )
+    });
+    let krate_key = krate
+        .as_ref()
+        .map(|krate| quote::quote! { crate = #krate, });
+
+    let routes = handlers.iter().map(|(key, handler)| {
+        let (path, method) = match key.to_string().as_str() {
+            "list" => (&path, quote::quote! { GET }),
+            "create" => (&path, quote::quote! { POST }),
+            "get" => (item.as_ref().unwrap_or(&path), quote::quote! { GET }),
+            "update" => (item.as_ref().unwrap_or(&path), quote::quote! { PUT }),
+            _ => (item.as_ref().unwrap_or(&path), quote::quote! { DELETE }),
+        };
+        proc_macro2::TokenStream::from(route(
+            quote::quote! {
+                #krate_key group = #group, path = #path, method = #method, handler = #handler
+            }
+            .into(),
+        ))
+    });
+
+    quote::quote! { #(#routes)* }.into()
+}
+
+struct Uri {
+    route: syn::Path,
+    params: Vec<(Ident, syn::Expr)>,
+    query: Option<syn::Expr>,
+}
+
+impl Parse for Uri {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let route = input.parse()?;
+        let mut params = Vec::new();
+        while input.parse::<Option<Token![,]>>()?.is_some() {
+            if input.is_empty() || input.peek(Token![;]) {
+                break;
+            }
+            let name = input.parse()?;
+            input.parse::<Token![=]>()?;
+            params.push((name, input.parse()?));
+        }
 
-        pub static #route_name: #group = #group::new(#path, |r, _| r.route(#path, #handler));
+        // The query follows the path parameters, e.g. `id = 42; &filter`
+        let query = if input.parse::<Option<Token![;]>>()?.is_some() {
+            Some(input.parse()?)
+        } else {
+            None
+        };
 
-        axum_distributed_routing::inventory::submit! {
-            #route_name
+        Ok(Uri {
+            route,
+            params,
+            query,
+        })
+    }
+}
+
+/// Builds the URL of a route from the static it is registered through,
+/// checking its path parameters at compile time
+///
+/// ```
+/// # use axum_distributed_routing::{route, route_group, uri};
+/// # use serde::{Deserialize, Serialize};
+/// # route_group!(Routes, ());
+/// # route_group!(Api, (), Routes, "/api");
+/// # #[derive(Deserialize, Serialize)]
+/// # struct Filter {
+/// #     sort: String,
+/// # }
+/// mod users {
+///     # use axum_distributed_routing::route;
+///     # use super::{Api, Filter};
+///     route!(
+///         group = Api,
+///         path = "/users/{id:u64}/posts",
+///         method = GET,
+///         query = Filter,
+///         async user_posts -> String { format!("posts of {id} by {}", query.sort) }
+///     );
+/// }
+///
+/// route!(group = Api, path = "/users/{id:u64}", method = GET, async get_user -> String { id.to_string() });
+///
+/// # fn main() {
+/// assert_eq!(uri!(ROUTE_GET_USER, id = 42), "/api/users/42");
+/// assert_eq!(
+///     uri!(users::ROUTE_USER_POSTS, id = 42; &Filter { sort: "new".into() }),
+///     "/api/users/42/posts?sort=new",
+/// );
+/// # }
+/// ```
+///
+/// Every path parameter of the route must be given, by the name it is bound
+/// to in the handler, with a value of its type. Missing, unknown and
+/// mistyped parameters are compile errors. The query is optional, and is
+/// serialized like by the `<name>_url` function of the route (see `route!`).
+#[proc_macro]
+pub fn uri(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let Uri {
+        mut route,
+        params,
+        query,
+    } = parse_macro_input!(input as Uri);
+
+    // The struct of the path parameters sits next to the static
+    if let Some(segment) = route.segments.last_mut() {
+        segment.ident = link_ident(&segment.ident);
+    }
+
+    let names = params.iter().map(|(name, _)| name);
+    let values = params.iter().map(|(_, value)| value);
+    let url = match query {
+        Some(query) => {
+            quote::quote_spanned! {syn::spanned::Spanned::span(&query)=> .with_query(#query) }
         }
+        None => quote::quote! { .path() },
     };
 
-    result.into()
+    quote::quote! { #route { #(#names: #values),* } #url }.into()
 }
@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use aide::axum::ApiRouter;
+use aide::openapi::{Info, OpenApi};
+use axum::routing::get;
+use axum::{Extension, Json};
+use axum_distributed_routing::create_api_router;
+use axum_distributed_routing::route;
+use axum_distributed_routing::route_group;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+route_group!(Routes, ());
+route_group!(Api, (), Routes, "/api", tag = "Users");
+
+#[derive(Deserialize, Serialize, JsonSchema)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+struct User {
+    name: String,
+}
+
+// Documented with its response body and summary
+route!(
+    group = Api,
+    path = "/users/{id:u32}",
+    method = GET,
+    summary = "Fetches a user",
+    async get_user -> Json<User> {
+        Json(User { name: format!("user {id}") })
+    }
+);
+
+// Documented with its request and response bodies
+route!(
+    group = Api,
+    path = "/users",
+    method = POST,
+    body = Json<User>,
+    summary = "Creates a user",
+    async create_user -> Json<User> { body }
+);
+
+// Served but left out of the document
+route!(
+    group = Routes,
+    path = "/health",
+    method = GET,
+    aide = false,
+    async health -> &'static str { "ok" }
+);
+
+async fn serve_docs(Extension(api): Extension<Arc<OpenApi>>) -> Json<OpenApi> {
+    Json((*api).clone())
+}
+
+#[tokio::main]
+async fn main() {
+    let mut api = OpenApi {
+        info: Info {
+            title: "Users".to_owned(),
+            ..Info::default()
+        },
+        ..OpenApi::default()
+    };
+
+    // The document is generated while the routes are attached, then served
+    // at `/docs`
+    let router = ApiRouter::new()
+        .merge(create_api_router!(Routes))
+        .route("/docs", get(serve_docs))
+        .finish_api(&mut api)
+        .layer(Extension(Arc::new(api)));
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:3000")
+        .await
+        .unwrap();
+
+    axum::serve(listener, router).await.unwrap();
+}
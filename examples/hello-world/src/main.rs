@@ -1,15 +1,32 @@
+use axum::extract::Path;
 use axum::http::StatusCode;
+use axum::response::IntoResponse;
 use axum::Json;
+use axum_distributed_routing::attr;
 use axum_distributed_routing::create_router;
+use axum_distributed_routing::full_paths;
+use axum_distributed_routing::Method;
+use axum_distributed_routing::print_routes;
+use axum_distributed_routing::raw_routes;
 use axum_distributed_routing::route;
 use axum_distributed_routing::route_group;
+use axum_distributed_routing::routes;
+use axum_distributed_routing::routes_manifest;
+use axum_distributed_routing::Route;
 use serde::Deserialize;
 
 // Create the root route group
 route_group!(Routes, ());
 
-// You can nest groups
-route_group!(pub Api, (), Routes, "/api");
+// You can nest groups, and tag their routes in the generated documentation
+route_group!(
+    pub Api,
+    (),
+    Routes,
+    "/api",
+    tag = "Public API",
+    description = "Endpoints available to third parties"
+);
 
 #[derive(Deserialize)]
 pub struct ExprQuery {
@@ -52,9 +69,45 @@ route!(
     group = Api,
     path = "/health",
     method = GET,
-    async api_health -> (StatusCode, &'static str) { (axum::http::StatusCode::OK, "ok") }
+
+    // Metadata is exposed through the `Route` trait
+    summary = "Health check",
+    tags = ["monitoring"],
+
+    // Handlers are regular functions, so they can return `impl Trait`
+    async api_health -> impl IntoResponse { (StatusCode::OK, "ok") }
+);
+
+// You can also register an existing function as the handler
+async fn version() -> &'static str {
+    env!("CARGO_PKG_VERSION")
+}
+
+route!(
+    group = Api,
+    path = "/version",
+    method = GET,
+    handler = version
 );
 
+// Groups can capture path parameters, which their routes inherit
+route_group!(pub Org, (), Api, "/orgs/{org_id:u64}");
+
+route!(
+    group = Org,
+    path = "/projects/{project_id:u64}",
+    method = GET,
+    inherit = [org_id: u64],
+    async get_project -> String { format!("project {project_id} of org {org_id}") }
+);
+
+// ...or declare the route as an attribute of its handler, which declares its
+// own extractors
+#[attr::route(group = Api, path = "/echo/{word:String}", method = GET)]
+async fn echo(Path(word): Path<String>) -> String {
+    word
+}
+
 #[tokio::main]
 async fn main() {
     // Create the router by calling `create_router!` with the root group
@@ -67,5 +120,181 @@ async fn main() {
     // You can access a route individually.
     println!("{:?}", ROUTE_API_HEALTH);
 
+    // ...or iterate over the routes of a group.
+    for route in routes!(Routes) {
+        println!(
+            "{:<7} {:<10} {:<10} {:?}",
+            route.method.map_or("", Method::as_str),
+            route.path,
+            route.name,
+            route.kind
+        );
+    }
+
+    // The routes themselves are available through `raw_routes!`
+    for route in raw_routes!(Api) {
+        println!(
+            "{:<7} {:<10} {:<15} {:?}",
+            route.method().map(|m| m.to_string()).unwrap_or_default(),
+            route.path(),
+            route.summary().unwrap_or_default(),
+            route.tags()
+        );
+    }
+
+    // Nested routes can be listed with their absolute path
+    for (path, method, name) in full_paths::<Routes>() {
+        println!(
+            "{:<7} {:<15} {}",
+            method.map(|m| m.to_string()).unwrap_or_default(),
+            path,
+            name
+        );
+    }
+
+    // ...or as a tree
+    print_routes::<Routes>();
+
+    // ...or as a JSON manifest
+    let manifest = routes_manifest!(Routes);
+    println!("{}", serde_json::to_string_pretty(&manifest).unwrap());
+
     axum::serve(listener, router).await.unwrap();
 }
+
+#[cfg(test)]
+mod tests {
+    use axum_distributed_routing::{
+        GroupSummary, RouteKind, children_of, group_summary, openapi_spec, route_count,
+    };
+
+    use super::*;
+
+    #[test]
+    fn exposes_the_method_of_routes() {
+        let health = raw_routes!(Api)
+            .find(|route| route.path() == "/health")
+            .unwrap();
+        assert_eq!(health.method(), Some(axum::http::Method::GET));
+
+        // Nested groups aren't a single endpoint
+        let api = raw_routes!(Routes)
+            .find(|route| route.path() == "/api")
+            .unwrap();
+        assert_eq!(api.method(), None);
+    }
+
+    #[test]
+    fn registers_every_route_globally() {
+        let mut routes = axum_distributed_routing::all_routes()
+            .map(|route| (route.group, route.path, route.name))
+            .collect::<Vec<_>>();
+        routes.sort();
+        assert_eq!(
+            routes,
+            [
+                ("Api", "/echo/{word:String}", "echo"),
+                ("Api", "/health", "api_health"),
+                ("Api", "/orgs/{org_id:u64}", "Org"),
+                ("Api", "/version", "version"),
+                ("Org", "/projects/{project_id:u64}", "get_project"),
+                ("Routes", "/api", "Api"),
+                ("Routes", "/expr/{val:i32}", "test_fn"),
+            ]
+        );
+    }
+
+    #[test]
+    fn describes_the_routes_of_a_group() {
+        let mut routes = routes!(Routes)
+            .map(|route| (route.method, route.path, route.name, route.kind))
+            .collect::<Vec<_>>();
+        routes.sort_by_key(|(_, path, _, _)| *path);
+        assert_eq!(
+            routes,
+            [
+                (None, "/api", "Api", RouteKind::Group),
+                (
+                    Some(Method::Get),
+                    "/expr/{val:i32}",
+                    "test_fn",
+                    RouteKind::Leaf
+                ),
+            ]
+        );
+        assert!(routes!(Api).all(|route| route.group == "Api"));
+        assert_eq!(raw_routes!(Api).count(), routes!(Api).count());
+    }
+
+    #[test]
+    fn counts_the_routes_of_nested_groups() {
+        assert_eq!(route_count::<Routes>(), 5);
+        assert_eq!(route_count::<Api>(), 4);
+        assert_eq!(
+            group_summary::<Routes>(),
+            GroupSummary {
+                leaf_count: 5,
+                nested_group_count: 2,
+                paths: vec![
+                    "/api/echo/{word:String}".to_owned(),
+                    "/api/health".to_owned(),
+                    "/api/orgs/{org_id:u64}/projects/{project_id:u64}".to_owned(),
+                    "/api/version".to_owned(),
+                    "/expr/{val:i32}".to_owned(),
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn lists_the_groups_nested_in_a_group() {
+        let children = children_of::<Routes>();
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].name, "Api");
+        assert_eq!(children[0].path, "/api");
+        assert_eq!(children[0].prefix, "/api");
+
+        let children = children_of::<Api>();
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].name, "Org");
+        assert_eq!(children[0].path, "/orgs/{org_id:u64}");
+        assert_eq!(children[0].prefix, "/api/orgs/{org_id:u64}");
+    }
+
+    #[test]
+    fn renders_the_route_tree() {
+        let tree = axum_distributed_routing::route_tree_string::<Routes>();
+        assert_eq!(
+            tree,
+            "\
+Routes
+    /api (tag: Public API)
+        GET /echo/{word:String}
+        GET /health
+        /api/orgs/{org_id:u64}
+            GET /projects/{project_id:u64}
+        GET /version
+    GET /expr/{val:i32}
+"
+        );
+    }
+
+    #[test]
+    fn generates_the_route_manifest() {
+        let manifest = serde_json::to_string_pretty(&routes_manifest!(Routes)).unwrap();
+        assert_eq!(manifest, include_str!("../manifest.json").trim_end());
+    }
+
+    #[test]
+    fn generates_the_openapi_document() {
+        let spec = openapi_spec!(Routes, "Hello world", "1.0.0");
+        let expected: serde_json::Value =
+            serde_json::from_str(include_str!("../openapi.json")).unwrap();
+        assert_eq!(
+            spec,
+            expected,
+            "{}",
+            serde_json::to_string_pretty(&spec).unwrap()
+        );
+    }
+}
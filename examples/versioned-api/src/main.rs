@@ -0,0 +1,41 @@
+use axum_distributed_routing::create_router;
+use axum_distributed_routing::print_routes;
+use axum_distributed_routing::route;
+use axum_distributed_routing::route_group;
+use axum_distributed_routing::versioned_group;
+
+route_group!(Routes, ());
+
+// Creates `ApiV1` and `ApiV2`, nested at `/v1` and `/v2`
+versioned_group!(pub Api, (), Routes, versions = [1, 2]);
+
+// Served at both `/v1/users/{id}` and `/v2/users/{id}`
+route!(
+    group = Api,
+    versions = [1, 2],
+    path = "/users/{id:u32}",
+    method = GET,
+    async get_user -> String { format!("user {}", id) }
+);
+
+// Only served at `/v2/users/{id}/avatar`
+route!(
+    group = Api,
+    versions = [2],
+    path = "/users/{id:u32}/avatar",
+    method = GET,
+    async get_avatar -> String { format!("avatar of user {}", id) }
+);
+
+#[tokio::main]
+async fn main() {
+    let router = create_router!(Routes);
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:3000")
+        .await
+        .unwrap();
+
+    print_routes::<Routes>();
+
+    axum::serve(listener, router).await.unwrap();
+}
@@ -0,0 +1,28 @@
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+
+/// The error of the handlers declared with `route!`'s `error = anyhow`,
+/// wrapping an `anyhow::Error`
+///
+/// Errors are answered with a `500 Internal Server Error`, and their chain is
+/// logged with `tracing::error!`. The chain is only sent in the body of the
+/// response in debug builds, so that it doesn't leak in release builds.
+#[derive(Debug)]
+pub struct AnyhowError(pub anyhow::Error);
+
+impl<E: Into<anyhow::Error>> From<E> for AnyhowError {
+    fn from(error: E) -> Self {
+        Self(error.into())
+    }
+}
+
+impl IntoResponse for AnyhowError {
+    fn into_response(self) -> Response {
+        tracing::error!("handler failed: {:#}", self.0);
+        if cfg!(debug_assertions) {
+            (StatusCode::INTERNAL_SERVER_ERROR, format!("{:#}", self.0)).into_response()
+        } else {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
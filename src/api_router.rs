@@ -0,0 +1,33 @@
+use aide::axum::ApiRouter;
+
+use crate::Route;
+
+/// A route that can be attached to an aide `ApiRouter`
+///
+/// This is implemented by all groups defined with `route_group!` when the
+/// `aide` feature is enabled. Routes defined with `route!` are documented
+/// with their summary, description and tags, unless they opt out with
+/// `aide = false`. Other routes are attached without documentation.
+pub trait ApiRoute: Route {
+    fn attach_api(&self, router: ApiRouter<Self::State>, level: usize) -> ApiRouter<Self::State>;
+}
+
+/// Creates an aide `ApiRouter` from the provided group
+///
+/// This is the aide counterpart of `create_router!`, the OpenAPI document can
+/// then be generated with `ApiRouter::finish_api`.
+#[macro_export]
+macro_rules! create_api_router {
+    ($type:ty) => {
+        $crate::create_api_router::<$type>(0)
+    };
+}
+
+#[doc(hidden)]
+pub fn create_api_router<T: ApiRoute + 'static>(level: usize) -> ApiRouter<T::State> {
+    let mut router = ApiRouter::new();
+    for route in crate::__private::sorted_routes::<T>() {
+        router = route.attach_api(router, level);
+    }
+    router
+}
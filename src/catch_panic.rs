@@ -0,0 +1,23 @@
+use std::any::Any;
+
+use axum::http::StatusCode;
+use axum::http::header::CONTENT_TYPE;
+use axum::response::{IntoResponse, Response};
+
+/// Builds the response to a request whose handler panicked, from the payload
+/// of the panic, declared with `route_group!`'s `panic_response` argument
+pub type PanicHandler = fn(Box<dyn Any + Send + 'static>) -> Response;
+
+/// The response of the groups catching panics without a `panic_response` of
+/// their own: `500 Internal Server Error` with a JSON body
+///
+/// The payload of the panic isn't sent to the client, the panic hook having
+/// already printed it.
+pub fn default_panic_response(_: Box<dyn Any + Send + 'static>) -> Response {
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        [(CONTENT_TYPE, "application/json")],
+        r#"{"error":"internal server error"}"#,
+    )
+        .into_response()
+}
@@ -0,0 +1,52 @@
+use reqwest::{Method, RequestBuilder};
+
+/// An HTTP client calling the routes of a server, used by the client
+/// functions `route!` generates with the `client` feature
+///
+/// Every request targets the base URL of the server, e.g.
+/// `http://localhost:3000`, joined with the absolute path of the route.
+/// Default headers, timeouts, etc. are configured on the `reqwest::Client`.
+///
+/// ```no_run
+/// # use axum_distributed_routing::{Client, route, route_group};
+/// # route_group!(Api, ());
+/// # route!(group = Api, path = "/users/{id:u64}", method = GET, async get_user -> String { id.to_string() });
+/// # async fn run() -> reqwest::Result<()> {
+/// let client = Client::new("http://localhost:3000");
+/// let response = call_get_user(&client, 42).await?;
+/// # Ok(())
+/// # }
+/// # fn main() {}
+/// ```
+#[derive(Clone, Debug)]
+pub struct Client {
+    base_url: String,
+    http: reqwest::Client,
+}
+
+impl Client {
+    /// Creates a client with a default `reqwest::Client`
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self::with_client(base_url, reqwest::Client::new())
+    }
+
+    /// Creates a client sending its requests through the provided
+    /// `reqwest::Client`
+    pub fn with_client(base_url: impl Into<String>, http: reqwest::Client) -> Self {
+        Self {
+            base_url: base_url.into(),
+            http,
+        }
+    }
+
+    /// The base URL of the server
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Starts a request to the absolute path of a route
+    pub fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        let url = format!("{}{}", self.base_url.trim_end_matches('/'), path);
+        self.http.request(method, url)
+    }
+}
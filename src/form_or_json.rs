@@ -0,0 +1,126 @@
+use std::fmt;
+use std::ops::Deref;
+
+use axum::Json;
+use axum::extract::rejection::{FormRejection, JsonRejection};
+use axum::extract::{Form, FromRequest, Request};
+use axum::http::StatusCode;
+use axum::http::header::CONTENT_TYPE;
+use axum::response::{IntoResponse, Response};
+
+/// An extractor deserializing the body with `Json` or `Form` depending on its
+/// `Content-Type`, used by `route!`'s `body = FormOrJson<T>`
+///
+/// `application/json` (and `application/*+json`) bodies are extracted with
+/// `Json`, `application/x-www-form-urlencoded` bodies with `Form`. Requests
+/// declaring another content type, or none, are rejected with a
+/// `415 Unsupported Media Type`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FormOrJson<T>(pub T);
+
+impl<T> Deref for FormOrJson<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// The rejection of `FormOrJson`
+#[derive(Debug)]
+pub enum FormOrJsonRejection {
+    Json(JsonRejection),
+    Form(FormRejection),
+    /// The request declares neither a JSON nor a form content type
+    UnsupportedMediaType,
+}
+
+impl fmt::Display for FormOrJsonRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json(rejection) => rejection.fmt(f),
+            Self::Form(rejection) => rejection.fmt(f),
+            Self::UnsupportedMediaType => f.write_str(
+                "Expected request with `Content-Type: application/json` or `Content-Type: application/x-www-form-urlencoded`",
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FormOrJsonRejection {}
+
+impl IntoResponse for FormOrJsonRejection {
+    fn into_response(self) -> Response {
+        match self {
+            Self::Json(rejection) => rejection.into_response(),
+            Self::Form(rejection) => rejection.into_response(),
+            Self::UnsupportedMediaType => {
+                (StatusCode::UNSUPPORTED_MEDIA_TYPE, self.to_string()).into_response()
+            }
+        }
+    }
+}
+
+impl From<JsonRejection> for FormOrJsonRejection {
+    fn from(rejection: JsonRejection) -> Self {
+        Self::Json(rejection)
+    }
+}
+
+impl From<FormRejection> for FormOrJsonRejection {
+    fn from(rejection: FormRejection) -> Self {
+        Self::Form(rejection)
+    }
+}
+
+impl<T, S> FromRequest<S> for FormOrJson<T>
+where
+    Json<T>: FromRequest<S, Rejection = JsonRejection>,
+    Form<T>: FromRequest<S, Rejection = FormRejection>,
+    S: Send + Sync,
+{
+    type Rejection = FormOrJsonRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let content_type = req
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| {
+                let essence = value.split(';').next().unwrap_or_default();
+                essence.trim().to_ascii_lowercase()
+            });
+        match content_type.as_deref() {
+            Some(ty) if ty == "application/json" || is_json_suffix(ty) => {
+                let Json(value) = Json::<T>::from_request(req, state).await?;
+                Ok(FormOrJson(value))
+            }
+            Some("application/x-www-form-urlencoded") => {
+                let Form(value) = Form::<T>::from_request(req, state).await?;
+                Ok(FormOrJson(value))
+            }
+            _ => Err(FormOrJsonRejection::UnsupportedMediaType),
+        }
+    }
+}
+
+/// Whether a content type is a JSON-based `application` type, e.g.
+/// `application/merge-patch+json`
+fn is_json_suffix(ty: &str) -> bool {
+    ty.strip_prefix("application/")
+        .is_some_and(|subtype| subtype.ends_with("+json"))
+}
+
+#[cfg(feature = "aide")]
+impl<T> aide::OperationInput for FormOrJson<T>
+where
+    Json<T>: aide::OperationInput,
+{
+    fn operation_input(
+        ctx: &mut aide::generate::GenContext,
+        operation: &mut aide::openapi::Operation,
+    ) {
+        // Documented as its JSON variant, the form sharing its schema
+        Json::<T>::operation_input(ctx, operation);
+    }
+}
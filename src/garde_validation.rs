@@ -0,0 +1,178 @@
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use axum::extract::{FromRef, FromRequest, FromRequestParts, Request};
+use axum::http::StatusCode;
+use axum::http::request::Parts;
+use axum::response::{IntoResponse, Response};
+use garde::{Report, Validate};
+
+/// An extractor validating the value extracted by `T` with `garde::Validate`,
+/// used by `route!`'s `validate = garde` key
+///
+/// The validation context is created with `Default`, see `GardeWith` for
+/// validators requiring a context.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Garde<T>(pub T);
+
+/// An extractor validating the value extracted by `T` with `garde::Validate`,
+/// used by `route!`'s `validate = garde(context)` key
+///
+/// The validation context is provided by `C`, which takes it from the state
+/// with `FromRef` by default. `route!` generates the provider of
+/// `validate = garde(context = ...)`.
+pub struct GardeWith<T, C = GardeStateContext>(pub T, pub PhantomData<fn() -> C>);
+
+impl<T, C> std::fmt::Debug for GardeWith<T, C>
+where
+    T: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("GardeWith").field(&self.0).finish()
+    }
+}
+
+/// Provides the context validating `V` in the routes of a router with the
+/// state `S`, see `GardeWith`
+pub trait GardeContext<S, V: Validate + ?Sized> {
+    fn context(state: &S) -> V::Context;
+}
+
+/// The default provider of `GardeWith`, taking the context from the state with
+/// `FromRef`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GardeStateContext;
+
+impl<S, V> GardeContext<S, V> for GardeStateContext
+where
+    V: Validate + ?Sized,
+    V::Context: FromRef<S>,
+{
+    fn context(state: &S) -> V::Context {
+        FromRef::from_ref(state)
+    }
+}
+
+/// Builds the response of requests rejected by `Garde` and `GardeWith`
+///
+/// This is implemented on the state of the router, the default rejects the
+/// request with a `422 Unprocessable Entity` and the errors as a JSON object
+/// mapping each invalid field to its message:
+///
+/// ```
+/// # use axum_distributed_routing::GardeErrorResponse;
+/// # struct AppState;
+/// impl GardeErrorResponse for AppState {}
+/// ```
+pub trait GardeErrorResponse {
+    fn garde_error_response(report: Report) -> Response {
+        let errors = report
+            .iter()
+            .map(|(path, error)| (path.to_string(), error.to_string()))
+            .collect::<BTreeMap<_, _>>();
+        (StatusCode::UNPROCESSABLE_ENTITY, axum::Json(errors)).into_response()
+    }
+}
+
+impl GardeErrorResponse for () {}
+
+impl<T, S> FromRequestParts<S> for Garde<T>
+where
+    T: FromRequestParts<S> + Deref,
+    T::Target: Validate,
+    <T::Target as Validate>::Context: Default,
+    S: GardeErrorResponse + Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let value = T::from_request_parts(parts, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+        Validate::validate(&*value).map_err(S::garde_error_response)?;
+        Ok(Garde(value))
+    }
+}
+
+impl<T, S> FromRequest<S> for Garde<T>
+where
+    T: FromRequest<S> + Deref,
+    T::Target: Validate,
+    <T::Target as Validate>::Context: Default,
+    S: GardeErrorResponse + Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let value = T::from_request(req, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+        Validate::validate(&*value).map_err(S::garde_error_response)?;
+        Ok(Garde(value))
+    }
+}
+
+impl<T, C, S> FromRequestParts<S> for GardeWith<T, C>
+where
+    T: FromRequestParts<S> + Deref,
+    T::Target: Validate,
+    C: GardeContext<S, T::Target>,
+    S: GardeErrorResponse + Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let value = T::from_request_parts(parts, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+        let context = C::context(state);
+        Validate::validate_with(&*value, &context).map_err(S::garde_error_response)?;
+        Ok(GardeWith(value, PhantomData))
+    }
+}
+
+impl<T, C, S> FromRequest<S> for GardeWith<T, C>
+where
+    T: FromRequest<S> + Deref,
+    T::Target: Validate,
+    C: GardeContext<S, T::Target>,
+    S: GardeErrorResponse + Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let value = T::from_request(req, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+        let context = C::context(state);
+        Validate::validate_with(&*value, &context).map_err(S::garde_error_response)?;
+        Ok(GardeWith(value, PhantomData))
+    }
+}
+
+#[cfg(feature = "aide")]
+impl<T> aide::OperationInput for Garde<T>
+where
+    T: aide::OperationInput,
+{
+    fn operation_input(
+        ctx: &mut aide::generate::GenContext,
+        operation: &mut aide::openapi::Operation,
+    ) {
+        T::operation_input(ctx, operation);
+    }
+}
+
+#[cfg(feature = "aide")]
+impl<T, C> aide::OperationInput for GardeWith<T, C>
+where
+    T: aide::OperationInput,
+{
+    fn operation_input(
+        ctx: &mut aide::generate::GenContext,
+        operation: &mut aide::openapi::Operation,
+    ) {
+        T::operation_input(ctx, operation);
+    }
+}
@@ -0,0 +1,209 @@
+use std::sync::Arc;
+
+use axum::extract::{Request, State};
+use axum::routing::{MethodRouter, Router};
+use tower::ServiceExt;
+
+use crate::Route;
+
+/// Builds a router from routes only known at runtime, alongside the groups
+/// registered with `route!`
+///
+/// Every route ends up in a single `Router`, so conflicting routes are
+/// rejected by axum whether they were registered statically or at runtime.
+/// Groups declared with a `host` are kept in a router of their own, see
+/// `create_router!`.
+///
+/// ```
+/// # use axum::routing::get;
+/// # use axum_distributed_routing::{GroupBuilder, route_group};
+/// # #[derive(Clone)]
+/// # struct AppState;
+/// # route_group!(Routes, AppState);
+/// # async fn webhook() {}
+/// # fn main() {
+/// # let v2_router = axum::Router::new();
+/// let router: axum::Router = GroupBuilder::<AppState>::new()
+///     .group::<Routes>()
+///     .route("/hooks/{id}", get(webhook))
+///     .nest("/v2", v2_router)
+///     .with_state(AppState);
+/// # }
+/// ```
+pub struct GroupBuilder<S = ()> {
+    router: Router<S>,
+    hosts: Vec<(&'static str, Router<S>)>,
+}
+
+impl<S> GroupBuilder<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    pub fn new() -> Self {
+        Self {
+            router: Router::new(),
+            hosts: Vec::new(),
+        }
+    }
+
+    /// Adds a route, see `Router::route`
+    ///
+    /// # Panics
+    /// Panics if the route conflicts with a route already added.
+    pub fn route(self, path: &str, method_router: MethodRouter<S>) -> Self {
+        Self {
+            router: self.router.route(path, method_router),
+            ..self
+        }
+    }
+
+    /// Nests a router at `path`, see `Router::nest`
+    ///
+    /// # Panics
+    /// Panics if one of its routes conflicts with a route already added.
+    pub fn nest(self, path: &str, router: Router<S>) -> Self {
+        Self {
+            router: self.router.nest(path, router),
+            ..self
+        }
+    }
+
+    /// Adds the routes of a group defined with `route_group!`, to the router
+    /// of its host if it has one
+    ///
+    /// # Panics
+    /// Panics if one of its routes conflicts with a route already added.
+    pub fn group<T: Route<State = S> + 'static>(mut self) -> Self {
+        let router = crate::create_router::<T>(0);
+        match T::host() {
+            Some(host) => match self.hosts.iter().position(|(h, _)| *h == host) {
+                Some(i) => {
+                    let (host, existing) = self.hosts.swap_remove(i);
+                    self.hosts.push((host, existing.merge(router)));
+                }
+                None => self.hosts.push((host, router)),
+            },
+            None => self.router = self.router.merge(router),
+        }
+        self
+    }
+
+    /// Nests the routes of a group defined with `route_group!` at `path`
+    ///
+    /// # Panics
+    /// Panics if one of its routes conflicts with a route already added.
+    pub fn nest_group<T: Route<State = S> + 'static>(self, path: &str) -> Self {
+        self.nest(path, crate::create_router::<T>(0))
+    }
+
+    /// Builds the router, still missing its state
+    ///
+    /// When groups declared with a `host` were added, the router of the host
+    /// of each request is given the state on that request, prefer `with_state`
+    /// to provide it once.
+    pub fn build(mut self) -> Router<S> {
+        if self.hosts.is_empty() {
+            return self.router;
+        }
+
+        // Exact hosts are matched before wildcards
+        self.hosts.sort_by_key(|(host, _)| host.starts_with("*."));
+        let routers = Arc::new((self.router, self.hosts));
+        Router::new().fallback(move |State(state): State<S>, request: Request| {
+            let routers = routers.clone();
+            async move {
+                let (default, hosts) = &*routers;
+                let router = host_router(default, hosts, &request);
+                router.clone().with_state(state).oneshot(request).await
+            }
+        })
+    }
+
+    /// Builds the router and provides its state, see `Router::with_state`
+    ///
+    /// The routers of the groups declared with a `host` are given the state
+    /// here once, rather than on each request as with `build`.
+    pub fn with_state(mut self, state: S) -> Router {
+        if self.hosts.is_empty() {
+            return self.router.with_state(state);
+        }
+
+        // Exact hosts are matched before wildcards
+        self.hosts.sort_by_key(|(host, _)| host.starts_with("*."));
+        let hosts: Vec<(&'static str, Router)> = self
+            .hosts
+            .into_iter()
+            .map(|(host, router)| (host, router.with_state(state.clone())))
+            .collect();
+        let routers = Arc::new((self.router.with_state(state), hosts));
+        Router::new().fallback_service(tower::service_fn(move |request: Request| {
+            let routers = routers.clone();
+            async move {
+                let (default, hosts) = &*routers;
+                host_router(default, hosts, &request)
+                    .clone()
+                    .oneshot(request)
+                    .await
+            }
+        }))
+    }
+}
+
+/// The router of the first host matching the request, or the default one
+fn host_router<'a, R>(default: &'a R, hosts: &'a [(&'static str, R)], request: &Request) -> &'a R {
+    let host = request_host(request);
+    hosts
+        .iter()
+        .find(|(pattern, _)| host.is_some_and(|host| host_matches(pattern, host)))
+        .map_or(default, |(_, router)| router)
+}
+
+/// The host of a request without its port, from the `Host` header or the URI
+fn request_host(request: &Request) -> Option<&str> {
+    let host = request
+        .headers()
+        .get(axum::http::header::HOST)
+        .and_then(|host| host.to_str().ok())
+        .or_else(|| request.uri().host())?;
+
+    // IPv6 addresses are bracketed
+    let host = match host.find(']') {
+        Some(end) => &host[..=end],
+        None => host.split(':').next().unwrap_or(host),
+    };
+    Some(host.trim_end_matches('.'))
+}
+
+/// Whether a host matches a pattern of `route_group!`, e.g. `api.example.com`
+/// or `*.example.com`
+fn host_matches(pattern: &str, host: &str) -> bool {
+    match pattern.strip_prefix("*.") {
+        Some(domain) => host
+            .len()
+            .checked_sub(domain.len())
+            .filter(|&start| start > 1 && host.as_bytes()[start - 1] == b'.')
+            .is_some_and(|start| host.as_bytes()[start..].eq_ignore_ascii_case(domain.as_bytes())),
+        None => host.eq_ignore_ascii_case(pattern),
+    }
+}
+
+impl<S> Default for GroupBuilder<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> std::fmt::Debug for GroupBuilder<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GroupBuilder")
+            .field("router", &self.router)
+            .field(
+                "hosts",
+                &self.hosts.iter().map(|(host, _)| host).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
@@ -0,0 +1,69 @@
+use axum::extract::{Request, State};
+use axum::response::{IntoResponse, Response};
+
+/// An authorization guard of `route!`'s `require` key
+///
+/// Guards are async functions taking the request, optionally preceded by the
+/// state of the group, and returning `Result<Request, E>` like
+/// `axum::middleware::map_request` expects. The request is passed on to the
+/// handler on `Ok`, and `E` is responded on `Err`.
+///
+/// ```
+/// # use axum::extract::{Request, State};
+/// # use axum::http::{HeaderMap, StatusCode};
+/// # use axum_distributed_routing::{route, route_group};
+/// # #[derive(Clone)]
+/// # struct AppState;
+/// # route_group!(Admin, AppState);
+/// # impl AppState {
+/// #     fn is_admin(&self, headers: &HeaderMap) -> bool {
+/// #         headers.contains_key("x-admin")
+/// #     }
+/// # }
+/// async fn require_admin(
+///     State(state): State<AppState>,
+///     request: Request,
+/// ) -> Result<Request, StatusCode> {
+///     if state.is_admin(request.headers()) {
+///         Ok(request)
+///     } else {
+///         Err(StatusCode::FORBIDDEN)
+///     }
+/// }
+///
+/// route!(group = Admin, path = "/stats", method = GET, require = require_admin, async stats {});
+/// # fn main() {}
+/// ```
+pub trait Guard<T, S>: Send + Sized {
+    fn check(
+        self,
+        request: Request,
+        state: S,
+    ) -> impl Future<Output = Result<Request, Response>> + Send;
+}
+
+impl<F, Fut, E, S> Guard<(Request,), S> for F
+where
+    F: FnOnce(Request) -> Fut + Send,
+    Fut: Future<Output = Result<Request, E>> + Send,
+    E: IntoResponse,
+    S: Send,
+{
+    async fn check(self, request: Request, _state: S) -> Result<Request, Response> {
+        self(request).await.map_err(IntoResponse::into_response)
+    }
+}
+
+impl<F, Fut, E, S> Guard<(State<S>, Request), S> for F
+where
+    F: FnOnce(State<S>, Request) -> Fut + Send,
+    Fut: Future<Output = Result<Request, E>> + Send,
+    E: IntoResponse,
+    S: Send,
+{
+    async fn check(self, request: Request, state: S) -> Result<Request, Response> {
+        self(State(state), request)
+            .await
+            .map_err(IntoResponse::into_response)
+    }
+}
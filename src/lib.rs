@@ -1,7 +1,91 @@
 pub use axum_distributed_routing_macros::*;
 pub use inventory;
 
+#[cfg(feature = "axum-extra")]
+pub use axum_extra::{TypedHeader, headers};
+
+#[cfg(feature = "cookies")]
+pub use axum_extra::extract::cookie;
+
+#[cfg(feature = "cors")]
+pub use tower_http::cors;
+
+/// The attribute form of `route!`, which can't share its name at the root of
+/// the crate
+pub mod attr {
+    pub use axum_distributed_routing_macros::route_attribute as route;
+}
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+use axum::extract::connect_info::Connected;
 use axum::routing::Router;
+use axum::serve::{IncomingStream, Listener};
+
+#[cfg(feature = "anyhow")]
+mod anyhow_error;
+#[cfg(feature = "aide")]
+mod api_router;
+#[cfg(feature = "catch-panic")]
+mod catch_panic;
+#[cfg(feature = "client")]
+mod client;
+mod form_or_json;
+#[cfg(feature = "garde")]
+mod garde_validation;
+mod group_builder;
+mod guard;
+mod manifest;
+mod method;
+mod method_routes;
+#[cfg(feature = "openapi")]
+mod openapi;
+mod optional_query;
+mod pagination;
+#[cfg(feature = "rejection-format")]
+mod rejection;
+#[cfg(feature = "swagger-ui")]
+mod swagger_ui;
+#[cfg(feature = "testing")]
+mod testing;
+#[cfg(feature = "trace")]
+mod trace;
+#[cfg(feature = "validator")]
+mod validation;
+mod with_rejection;
+
+#[cfg(feature = "anyhow")]
+pub use anyhow_error::AnyhowError;
+#[cfg(feature = "aide")]
+pub use api_router::{ApiRoute, create_api_router};
+#[cfg(feature = "catch-panic")]
+pub use catch_panic::{PanicHandler, default_panic_response};
+#[cfg(feature = "client")]
+pub use client::Client;
+pub use form_or_json::{FormOrJson, FormOrJsonRejection};
+#[cfg(feature = "garde")]
+pub use garde_validation::{Garde, GardeContext, GardeErrorResponse, GardeStateContext, GardeWith};
+pub use group_builder::GroupBuilder;
+pub use guard::Guard;
+pub use manifest::{ManifestRoute, RouteManifest, routes_manifest};
+pub use method::{Method, ParseMethodError};
+pub use method_routes::MethodRoutes;
+#[cfg(feature = "openapi")]
+pub use openapi::openapi_spec;
+pub use optional_query::OptionalQuery;
+pub use pagination::{Pagination, PaginationHeaders};
+#[cfg(feature = "rejection-format")]
+pub use rejection::{GroupRejection, Rejection, RejectionFormat, RejectionKind};
+#[cfg(feature = "testing")]
+pub use testing::{TestClient, TestRequest, TestResponse};
+#[cfg(feature = "trace")]
+pub use trace::{MakeRouteSpan, RecordFailure, RecordResponse, RouteTraceLayer, trace_layer};
+#[cfg(feature = "validator")]
+pub use validation::{Valid, ValidationErrorResponse};
+pub use with_rejection::WithRejection;
 
 /// A trait for defining a route. All routes must implement this trait
 ///
@@ -17,7 +101,474 @@ pub trait Route {
 
     fn attach(&self, router: Router<Self::State>, level: usize) -> Router<Self::State>;
 
+    /// Adds the method routers of this route to `routes` instead of attaching
+    /// it, returning whether it did
+    ///
+    /// `create_router!` inserts the collected method routers together, each
+    /// path once.
+    /// Routes that aren't method routers (e.g. nested groups) return `false`
+    /// and are attached.
+    fn collect_routes(&self, _routes: &mut MethodRoutes<Self::State>) -> bool {
+        false
+    }
+
     fn path(&self) -> &'static str;
+
+    /// The name of the route, used for logging and lookups
+    ///
+    /// Defaults to the path of the route.
+    fn name(&self) -> &'static str {
+        self.path()
+    }
+
+    /// The HTTP method of the route, `None` for routes that aren't a single
+    /// endpoint (e.g. nested groups)
+    fn method(&self) -> Option<axum::http::Method> {
+        None
+    }
+
+    /// A short summary of the route
+    fn summary(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// A longer description of the route
+    fn description(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// The tags of the route, used to categorize it in documentation
+    fn tags(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// The priority of the route, routes of a group are attached by descending
+    /// priority then by path
+    fn priority(&self) -> i32 {
+        0
+    }
+
+    /// The host the routes of this group are served on, declared with
+    /// `route_group!`'s `host` argument, see `create_router!`
+    fn host() -> Option<&'static str>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// How the leaf routes of this group treat a trailing slash, declared
+    /// with `route_group!`'s `trailing_slash` argument
+    fn trailing_slash() -> TrailingSlash
+    where
+        Self: Sized,
+    {
+        TrailingSlash::Strict
+    }
+
+    /// Whether `OPTIONS` requests to the paths of this group are answered
+    /// automatically, declared with `route_group!`'s `auto_options` argument
+    fn auto_options() -> bool
+    where
+        Self: Sized,
+    {
+        false
+    }
+
+    /// The CORS policy applied to the routes of this group, declared with
+    /// `route_group!`'s `cors` argument
+    ///
+    /// Nested groups without a policy of their own use the policy of their
+    /// parent.
+    #[cfg(feature = "cors")]
+    fn cors() -> Option<cors::CorsLayer>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// The response to the requests of this group whose handler panics,
+    /// declared with `route_group!`'s `catch_panics` and `panic_response`
+    /// arguments, `None` if panics aren't caught
+    ///
+    /// Nested groups without a switch of their own follow their parent.
+    #[cfg(feature = "catch-panic")]
+    fn catch_panics() -> Option<PanicHandler>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// The format of the rejections of the extractors of the routes of this
+    /// group, declared with `route_group!`'s `rejection_format` argument,
+    /// `None` to keep the rejections of the extractors
+    ///
+    /// Nested groups without a format of their own use the format of their
+    /// parent.
+    #[cfg(feature = "rejection-format")]
+    fn rejection_format() -> Option<RejectionFormat>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// The `Cache-Control` header of the successful responses of the routes
+    /// of this group, declared with `route_group!`'s `cache_control` argument
+    ///
+    /// The header is only set on the responses without one, e.g. set by the
+    /// handler or by `route!`'s `cache_control` key. Nested groups without a
+    /// header of their own use the header of their parent.
+    fn cache_control() -> Option<&'static str>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// Whether the responses of the routes of this group are compressed with
+    /// the `compression` feature, declared with `route_group!`'s
+    /// `compression` argument
+    ///
+    /// Routes declaring their own `compression` key, and `sse` and `ws`
+    /// routes, ignore it. Nested groups without a switch of their own follow
+    /// their parent.
+    #[cfg(feature = "compression")]
+    fn compression() -> bool
+    where
+        Self: Sized,
+    {
+        false
+    }
+
+    /// Whether the routes of this group record metrics with the `metrics`
+    /// feature, declared with `route_group!`'s `metrics` argument
+    ///
+    /// Nested groups without a switch of their own follow their parent.
+    fn metrics() -> bool
+    where
+        Self: Sized,
+    {
+        true
+    }
+
+    /// The tag of this group, declared with `route_group!`'s `tag` argument
+    ///
+    /// Leaf routes without tags of their own are tagged with it. Nested
+    /// groups without a tag of their own use the tag of their parent.
+    fn group_tag() -> Option<&'static str>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// The description of the tag of this group, declared with
+    /// `route_group!`'s `description` argument
+    fn group_description() -> Option<&'static str>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// The absolute path this group is mounted at, e.g. `/api/v1`, following
+    /// the nesting of `route_group!`
+    ///
+    /// Root groups are mounted at the root, so their prefix is empty.
+    fn prefix() -> String
+    where
+        Self: Sized,
+    {
+        String::new()
+    }
+
+    /// Describes the route, see `routes!`
+    fn info(&self) -> RouteInfo {
+        let group = std::any::type_name::<Self>();
+        RouteInfo {
+            group: group.rsplit("::").next().unwrap_or(group),
+            path: self.path(),
+            method: None,
+            name: self.name(),
+            kind: RouteKind::Leaf,
+        }
+    }
+
+    /// Calls `f` with the absolute path and the metadata of every leaf route
+    /// under this one, `prefix` being the path this route is mounted at.
+    ///
+    /// Nested groups are walked recursively, other routes are leaves.
+    fn walk(&self, prefix: &str, f: &mut dyn FnMut(&str, &RouteMetadata)) {
+        let group = std::any::type_name::<Self>();
+        let metadata = RouteMetadata {
+            summary: self.summary(),
+            description: self.description(),
+            tags: self.tags(),
+            name: Some(self.name()),
+            group: group.rsplit("::").next(),
+            ..RouteMetadata::EMPTY
+        };
+        f(&join_paths(prefix, self.path()), &metadata)
+    }
+
+    /// Calls `f` with the tag of every group nested under this route, see
+    /// `group_tags`
+    fn walk_tags(&self, _f: &mut dyn FnMut(GroupTag)) {}
+
+    /// Calls `f` with the absolute path of every group nested under this
+    /// route, `prefix` being the path this route is mounted at, see
+    /// `group_summary`
+    fn walk_groups(&self, _prefix: &str, _f: &mut dyn FnMut(&str)) {}
+
+    /// Returns the routes registered in this group, by `route!` or nested
+    /// `route_group!`
+    ///
+    /// Defaults to no routes, for types implementing `Route` by hand.
+    fn registered() -> impl Iterator<Item = &'static Self>
+    where
+        Self: Sized + 'static,
+    {
+        std::iter::empty()
+    }
+
+    /// Writes the line of this route in the tree of `route_tree_string`,
+    /// indented by `level` spaces, followed by its nested routes if any
+    fn write_tree(&self, out: &mut String, _prefix: &str, level: usize) {
+        __private::write_leaf(
+            out,
+            level,
+            self.method(),
+            self.path(),
+            &RouteMetadata::EMPTY,
+        );
+    }
+}
+
+/// Removes the parameter types from a path (`/{id:u64}` becomes `/{id}`)
+pub(crate) fn path_template(path: &str) -> String {
+    let mut template = String::with_capacity(path.len());
+    let (mut in_param, mut in_type) = (false, false);
+    for c in path.chars() {
+        match c {
+            '{' => in_param = true,
+            '}' => (in_param, in_type) = (false, false),
+            ':' if in_param => in_type = true,
+            _ => {}
+        }
+        if !in_type {
+            template.push(c);
+        }
+    }
+    template
+}
+
+/// Joins a prefix and the path of a route nested under it, the way axum's
+/// `nest` does
+pub fn join_paths(prefix: &str, path: &str) -> String {
+    match (prefix.trim_end_matches('/'), path) {
+        ("", path) => path.to_owned(),
+        (prefix, "" | "/") => prefix.to_owned(),
+        (prefix, path) => format!("{prefix}{path}"),
+    }
+}
+
+/// The tag of a group, declared with `route_group!`'s `tag` and
+/// `description` arguments
+#[derive(Copy, Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct GroupTag {
+    pub name: &'static str,
+    pub description: Option<&'static str>,
+}
+
+/// Lists the tags of the provided group and of the groups nested under it,
+/// sorted by name
+///
+/// A tag declared by several groups is listed once, with the first
+/// description found.
+pub fn group_tags<T: Route + 'static>() -> Vec<GroupTag> {
+    let mut tags = Vec::<GroupTag>::new();
+    __private::walk_tags::<T>(
+        &mut |tag| match tags.iter_mut().find(|other| other.name == tag.name) {
+            Some(other) => other.description = other.description.or(tag.description),
+            None => tags.push(tag),
+        },
+    );
+    tags.sort_by_key(|tag| tag.name);
+    tags
+}
+
+/// Metadata attached to a route by the `route!` macro
+///
+/// Group types store it alongside the path and expose it through the `Route`
+/// accessors. Types are recorded as written in the route definition.
+#[derive(Copy, Clone, Debug)]
+pub struct RouteMetadata {
+    pub summary: Option<&'static str>,
+    pub description: Option<&'static str>,
+    pub tags: &'static [&'static str],
+    /// The name of the handler, or of the group for nested groups
+    pub name: Option<&'static str>,
+    /// The name of the group the route is registered in, filled in by
+    /// `Route::walk`
+    pub group: Option<&'static str>,
+    /// The HTTP method
+    pub method: Option<Method>,
+    /// The path parameters, as `(name, type)`
+    pub params: &'static [(&'static str, &'static str)],
+    /// The query type
+    pub query: Option<&'static str>,
+    /// The body, as `(content type, type)`
+    pub body: Option<(&'static str, &'static str)>,
+    /// Whether the body can be omitted, see `route!`'s `body = Option<T>`
+    pub optional_body: bool,
+    /// The success response, as `(content type, type)`, when it is known
+    pub response: Option<(&'static str, &'static str)>,
+    /// The success status, when it isn't `200`
+    pub status: Option<u16>,
+    /// The attach order of the route in its group, see `Route::priority`
+    pub priority: i32,
+    /// The deprecation of the route, if it is deprecated
+    pub deprecation: Option<Deprecation>,
+    /// The other paths the route is registered at, see `route!`'s `aliases`
+    /// key
+    pub aliases: &'static [&'static str],
+    /// The guards of the route in the order they run, see `route!`'s
+    /// `require` key
+    pub guards: &'static [&'static str],
+}
+
+impl RouteMetadata {
+    /// Metadata without any information, used by routes that don't declare any
+    pub const EMPTY: Self = Self {
+        summary: None,
+        description: None,
+        tags: &[],
+        name: None,
+        group: None,
+        method: None,
+        params: &[],
+        query: None,
+        body: None,
+        optional_body: false,
+        response: None,
+        status: None,
+        priority: 0,
+        deprecation: None,
+        aliases: &[],
+        guards: &[],
+    };
+}
+
+/// How the leaf routes of a group treat the path differing from theirs by a
+/// trailing slash, e.g. `/users/` for `/users`
+///
+/// The root path `/` and paths with a wildcard (e.g. `/{*rest}`) are left as
+/// is.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum TrailingSlash {
+    /// Only the path of the route is served
+    #[default]
+    Strict,
+    /// Both paths are served by the route
+    Merge,
+    /// The other path is redirected to the path of the route with a
+    /// `308 Permanent Redirect`, keeping the query
+    Redirect,
+}
+
+/// The deprecation of a route, declared with `route!`'s `deprecated` key
+///
+/// Dates are written as `YYYY-MM-DD`.
+#[derive(Copy, Clone, Debug)]
+pub struct Deprecation {
+    /// The date the route was deprecated
+    pub since: &'static str,
+    /// The date the route will stop responding
+    pub sunset: Option<&'static str>,
+    /// The path of the route replacing it
+    pub successor: Option<&'static str>,
+}
+
+impl Default for RouteMetadata {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+/// A type-erased registration of a route
+///
+/// `route!` and the nested form of `route_group!` submit one alongside the
+/// typed registration, so routes can be listed without knowing every group
+/// type (see `all_routes`).
+#[derive(Copy, Clone, Debug)]
+pub struct RouteInfo {
+    /// The name of the group the route is registered in
+    pub group: &'static str,
+    /// The path of the route, relative to its group
+    pub path: &'static str,
+    /// The HTTP method, `None` for nested groups
+    pub method: Option<Method>,
+    /// The name of the handler, or of the group for nested groups
+    pub name: &'static str,
+    pub kind: RouteKind,
+}
+
+/// A group nested in another one by the nested form of `route_group!` or by
+/// `mount_group!`, see `children_of`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChildGroupInfo {
+    /// The name of the nested group
+    pub name: &'static str,
+    /// The path the group is nested at, relative to its parent
+    pub path: &'static str,
+    /// The absolute path the group is mounted at
+    pub prefix: String,
+}
+
+/// Whether a route is an endpoint or a nested group
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RouteKind {
+    Leaf,
+    Group,
+}
+
+#[cfg(not(feature = "backend-linkme"))]
+inventory::collect!(RouteInfo);
+
+/// Returns every route registered by `route!` and nested `route_group!`, in
+/// any group and any crate
+#[cfg(not(feature = "backend-linkme"))]
+pub fn all_routes() -> impl Iterator<Item = &'static RouteInfo> {
+    inventory::iter::<RouteInfo>.into_iter()
+}
+
+/// Returns every route registered by `route!` and nested `route_group!`, in
+/// any group and any crate
+#[cfg(feature = "backend-linkme")]
+pub fn all_routes() -> impl Iterator<Item = &'static RouteInfo> {
+    __private::ROUTE_INFOS.iter()
+}
+
+/// Declares the default error type of a group
+///
+/// Routes with a bare `error` key return `Result<T, Self::Error>`. This is
+/// implemented by the `route_group!` macro when an `error = Type` argument is
+/// given.
+pub trait DefaultError {
+    type Error: axum::response::IntoResponse;
+}
+
+/// Declares the state extracted by the routes of a group with `route!`'s
+/// `state` key
+///
+/// This is the state of the group's router, except for groups nested with a
+/// part of their parent's state (see `route_group!`), which extract it with
+/// `FromRef`.
+pub trait GroupState {
+    type State;
 }
 
 /// Define a route group
@@ -27,29 +578,532 @@ pub trait Route {
 /// `Route` trait.
 ///
 /// You can also use this macro to define a nested route group, simply add the
-/// parent group and the subpath as the third and fourth arguments
+/// parent group and the subpath as the third and fourth arguments. The subpath
+/// can't be `/`, as axum doesn't support nesting at the root.
+///
+/// A default error type for the routes of the group can be declared with a
+/// trailing `error = Type` argument (see `DefaultError`).
+///
+/// A nested group is attached among the routes of its parent according to its
+/// priority (see `Route::priority`), which can be set with a trailing
+/// `priority = 10` argument.
+///
+/// A root group can be served on a single host with e.g.
+/// `route_group!(Admin, AppState, host = "admin.example.com")`, or on every
+/// subdomain with `host = "*.example.com"`, see `create_router!`.
+///
+/// Requests to a path differing from the path of a route by a trailing slash
+/// can be served with `trailing_slash = merge`, or redirected to the path of
+/// the route with `trailing_slash = redirect` (see `TrailingSlash`). The
+/// argument only applies to the routes declared with `route!` in the group,
+/// nested groups having their own, and is ignored by `create_api_router`.
+///
+/// With `auto_options = true`, `OPTIONS` requests to the paths of the routes
+/// of the group, their aliases and, with `trailing_slash = merge`, the paths
+/// differing from them by a trailing slash are answered with `204 No Content`
+/// and an `Allow` header listing their methods, unless a route of the path
+/// handles `OPTIONS`.
+///
+/// With the `cors` feature, a `CorsLayer` can be applied to the routes of the
+/// group with e.g. `cors = CorsLayer::permissive()`, the expression being
+/// evaluated each time the group is attached. Nested groups use the policy of
+/// their parent unless they declare their own:
+///
+/// ```
+/// # #[cfg(feature = "cors")]
+/// # mod example {
+/// # use axum_distributed_routing::{cors::CorsLayer, route_group};
+/// # #[derive(Clone)]
+/// # struct AppState;
+/// # route_group!(Routes, AppState);
+/// # fn spa_cors() -> CorsLayer {
+/// #     CorsLayer::new()
+/// # }
+/// route_group!(Api, AppState, Routes, "/api", cors = spa_cors());
+/// route_group!(Public, AppState, Routes, "/public", cors = CorsLayer::permissive());
+/// # }
+/// # fn main() {}
+/// ```
+///
+/// With the `catch-panic` feature, `catch_panics = true` answers the requests
+/// whose handler panics with `500 Internal Server Error` instead of closing
+/// the connection (see `default_panic_response`). The response can be built
+/// by a function receiving the payload of the panic, e.g. to follow the error
+/// format of the application. Nested groups follow their parent unless they
+/// declare their own switch:
+///
+/// ```
+/// # #[cfg(feature = "catch-panic")]
+/// # mod example {
+/// # use std::any::Any;
+/// # use axum::http::StatusCode;
+/// # use axum::response::{IntoResponse, Json, Response};
+/// # use axum_distributed_routing::route_group;
+/// # #[derive(Clone)]
+/// # struct AppState;
+/// # route_group!(Routes, AppState);
+/// # #[derive(serde::Serialize)]
+/// # struct ErrorBody;
+/// # impl ErrorBody {
+/// #     fn internal() -> Self {
+/// #         ErrorBody
+/// #     }
+/// # }
+/// fn panic_response(_: Box<dyn Any + Send + 'static>) -> Response {
+///     (StatusCode::INTERNAL_SERVER_ERROR, Json(ErrorBody::internal())).into_response()
+/// }
+///
+/// route_group!(Api, AppState, Routes, "/api", catch_panics = true, panic_response = panic_response);
+/// route_group!(Debug, AppState, Api, "/debug", catch_panics = false);
+/// # }
+/// # fn main() {}
+/// ```
+///
+/// With the `rejection-format` feature, the failures of the extractors of the
+/// routes of the group (path parameters, query and body, see `route!`) can be
+/// reported in a same format. `rejection_format = json` answers with the
+/// status of the rejection and a `{ "error": { "kind", "message", "field" } }`
+/// body, see `Rejection`. A function building the response from the
+/// `Rejection` can be given instead. Nested groups use the format of their
+/// parent unless they declare their own, and routes declaring a `rejection`
+/// type keep it:
+///
+/// ```
+/// # #[cfg(feature = "rejection-format")]
+/// # mod example {
+/// # use axum::response::{IntoResponse, Response};
+/// # use axum_distributed_routing::{Rejection, route_group};
+/// # #[derive(Clone)]
+/// # struct AppState;
+/// # route_group!(Routes, AppState);
+/// fn legacy_rejection(rejection: Rejection) -> Response {
+///     (rejection.status, rejection.message).into_response()
+/// }
+///
+/// route_group!(Api, AppState, Routes, "/api", rejection_format = json);
+/// route_group!(Legacy, AppState, Api, "/legacy", rejection_format = legacy_rejection);
+/// # }
+/// # fn main() {}
+/// ```
+///
+/// The successful responses of the routes of the group can be given a
+/// `Cache-Control` header with e.g. `cache_control = "no-store"`, unless the
+/// handler or the `cache_control` key of the route (see `route!`) sets one.
+/// Nested groups use the header of their parent unless they declare their
+/// own:
+///
+/// ```
+/// # use axum_distributed_routing::route_group;
+/// # #[derive(Clone)]
+/// # struct AppState;
+/// # route_group!(Routes, AppState);
+/// route_group!(Api, AppState, Routes, "/api", cache_control = "no-store");
+/// route_group!(Assets, AppState, Api, "/assets", cache_control = "public, max-age=86400");
+/// # fn main() {}
+/// ```
+///
+/// With the `compression` feature, `compression = true` compresses the
+/// responses of the routes of the group with gzip or brotli, when the client
+/// accepts it. Routes can opt in or out with their own `compression` key (see
+/// `route!`), and `sse` and `ws` routes are never compressed by their group.
+/// Nested groups follow their parent unless they declare their own switch:
+///
+/// ```
+/// # #[cfg(feature = "compression")]
+/// # mod example {
+/// # use axum_distributed_routing::route_group;
+/// # #[derive(Clone)]
+/// # struct AppState;
+/// # route_group!(Routes, AppState);
+/// route_group!(Api, AppState, Routes, "/api", compression = true);
+/// route_group!(Downloads, AppState, Api, "/downloads", compression = false);
+/// # }
+/// # fn main() {}
+/// ```
+///
+/// With the `metrics` feature, the routes of the group record their requests
+/// (see `route!`) unless the group declares `metrics = false`. Nested groups
+/// follow their parent unless they declare their own switch.
+///
+/// The routes of a group can be tagged with e.g. `tag = "Public API"`,
+/// described with `description = "Endpoints available to third parties"`.
+/// Leaf routes without tags of their own are tagged with it in the documents
+/// of `openapi_spec!` and aide, and in `routes_manifest!`. Nested groups use
+/// the tag of their parent unless they declare their own:
+///
+/// ```
+/// # use axum_distributed_routing::route_group;
+/// # #[derive(Clone)]
+/// # struct AppState;
+/// # route_group!(Routes, AppState);
+/// route_group!(Api, AppState, Routes, "/api", tag = "Public API", description = "Endpoints available to third parties");
+/// # fn main() {}
+/// ```
+///
+/// A nested group can use a part of its parent's state, by following the
+/// parent with its state. Its routes are attached to the router of the parent
+/// and extract their state with `FromRef`:
+///
+/// ```
+/// # use axum::extract::FromRef;
+/// # use axum_distributed_routing::route_group;
+/// # #[derive(Clone)]
+/// # struct DbPool;
+/// #[derive(Clone)]
+/// struct AppState {
+///     db: DbPool,
+/// }
+///
+/// impl FromRef<AppState> for DbPool {
+///     fn from_ref(state: &AppState) -> Self {
+///         state.db.clone()
+///     }
+/// }
+///
+/// # route_group!(Routes, AppState);
+/// route_group!(Users, DbPool, Routes: AppState, "/users");
+/// # fn main() {}
+/// ```
+///
+/// The path of a nested group can capture path parameters, declared with
+/// their type like in `route!`, e.g. `"/orgs/{org_id:u64}"`. The routes of the
+/// group extracting path parameters receive them with `route!`'s `inherit`
+/// key.
+///
+/// A group can be generic over its state, e.g. for a library providing routes
+/// to any application whose state implements one of its traits. As generic
+/// routes can't be registered, the handlers of the group are listed in its
+/// declaration, with their method and their path written like in axum:
+///
+/// ```
+/// # use axum::extract::State;
+/// # use axum_distributed_routing::route_group;
+/// pub trait HasAuthConfig {
+///     fn realm(&self) -> &str;
+/// }
+///
+/// route_group!(pub Auth<S: HasAuthConfig + Clone + Send + Sync + 'static>, S, routes = [
+///     GET "/realm" => realm::<S>,
+///     POST "/logout" => logout,
+/// ]);
+///
+/// async fn realm<S: HasAuthConfig>(State(state): State<S>) -> String {
+///     state.realm().to_owned()
+/// }
+///
+/// async fn logout() {}
+/// # fn main() {}
+/// ```
+///
+/// Applications then mount it for their state with `instantiate_group!`. The
+/// other arguments of `route_group!` aren't available to generic groups.
+///
+/// Routes are registered with `inventory` by default. With the
+/// `backend-linkme` feature, they are registered in a `linkme` distributed
+/// slice named after the group instead, which doesn't rely on code running
+/// before `main`. The names of the groups must then be unique in the binary.
+///
+/// On `wasm32-unknown-unknown`, `inventory` collects the routes in the
+/// constructors of the module, which the linker gathers in a synthesized
+/// `__wasm_call_ctors` function (this requires `inventory` 0.3.25). The linker
+/// only calls it on its own when no exported function calls it. Modules called
+/// into several times per instance should export it and have the embedder call
+/// it right after instantiation, otherwise the routers created before it runs
+/// are empty:
+///
+/// ```
+/// #[cfg(target_family = "wasm")]
+/// unsafe extern "C" {
+///     fn __wasm_call_ctors();
+/// }
+///
+/// fn main() {
+///     #[cfg(target_family = "wasm")]
+///     unsafe {
+///         __wasm_call_ctors();
+///     }
+/// }
+/// ```
 #[macro_export]
 macro_rules! route_group {
-    ($vis:vis $name:ident, $type:ty, $parent:ident, $path:literal) => {
-        $crate::route_group!($vis $name, $type);
-        $crate::inventory::submit!($parent::new($path, |router, level| {
-            router.nest($path, $crate::create_router::<$name>(level + 4))
-        }));
+    // The bounds of the state parameter of generic groups are split off by
+    // `@generic`, one token at a time
+    ($vis:vis $name:ident < $param:ident : $($rest:tt)*) => {
+        $crate::route_group!(@generic [$vis $name $param] [] $($rest)*);
+    };
+    (@generic [$vis:vis $name:ident $param:ident] [$($bounds:tt)*] > , $state:ty $(, routes = [$($routes:tt)*])? $(,)?) => {
+        $crate::route_group!(@generic_group $vis $name, $param, [$($bounds)*], $state, [$($($routes)*)?]);
+    };
+    (@generic [$vis:vis $name:ident $param:ident] [$($bounds:tt)*] >> , $state:ty $(, routes = [$($routes:tt)*])? $(,)?) => {
+        $crate::route_group!(@generic_group $vis $name, $param, [$($bounds)* >], $state, [$($($routes)*)?]);
+    };
+    (@generic [$($head:tt)*] [$($bounds:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::route_group!(@generic [$($head)*] [$($bounds)* $next] $($rest)*);
+    };
+    (@generic_group $vis:vis $name:ident, $param:ident, [$($bounds:tt)*], $state:ty, [$($method:ident $path:literal => $handler:ident $(::<$($generic:ty),+>)?),* $(,)?]) => {
+        $vis struct $name<$param> {
+            path: &'static str,
+            method: $crate::Method,
+            name: &'static str,
+            method_router: fn() -> $crate::__private::axum::routing::MethodRouter<$state>,
+        }
+
+        impl<$param: $($bounds)*> $crate::Route for $name<$param> {
+            type State = $state;
+
+            fn attach(
+                &self,
+                router: $crate::__private::axum::routing::Router<$state>,
+                _level: usize,
+            ) -> $crate::__private::axum::routing::Router<$state> {
+                let mut routes = $crate::MethodRoutes::new();
+                $crate::Route::collect_routes(self, &mut routes);
+                routes.attach(router)
+            }
+
+            fn collect_routes(&self, routes: &mut $crate::MethodRoutes<$state>) -> bool {
+                $crate::__private::collect_leaf::<Self>(routes, self.path, (self.method_router)());
+                true
+            }
+
+            fn path(&self) -> &'static str {
+                self.path
+            }
+
+            fn name(&self) -> &'static str {
+                self.name
+            }
+
+            fn method(&self) -> Option<$crate::__private::axum::http::Method> {
+                Some(self.method.into())
+            }
+
+            fn info(&self) -> $crate::RouteInfo {
+                $crate::RouteInfo {
+                    group: stringify!($name),
+                    path: self.path,
+                    method: Some(self.method),
+                    name: self.name,
+                    kind: $crate::RouteKind::Leaf,
+                }
+            }
+
+            fn walk(&self, prefix: &str, f: &mut dyn FnMut(&str, &$crate::RouteMetadata)) {
+                f(
+                    &$crate::join_paths(prefix, self.path),
+                    &$crate::RouteMetadata {
+                        name: Some(self.name),
+                        group: Some(stringify!($name)),
+                        method: Some(self.method),
+                        ..$crate::RouteMetadata::EMPTY
+                    },
+                )
+            }
+
+            fn registered() -> impl Iterator<Item = &'static Self> {
+                $crate::__private::generic_routes::<Self>(|| {
+                    vec![$(Self {
+                        path: $path,
+                        method: $crate::route_group!(@method $method),
+                        name: stringify!($handler),
+                        method_router: || {
+                            $crate::__private::axum::routing::on(
+                                $crate::route_group!(@method $method).into(),
+                                $handler $(::<$($generic),+>)?,
+                            )
+                        },
+                    }),*]
+                })
+                .iter()
+            }
+        }
+
+        $crate::__impl_api_route!(@generic $name, $param, [$($bounds)*], $state);
+    };
+    (@method GET) => { $crate::Method::Get };
+    (@method POST) => { $crate::Method::Post };
+    (@method PUT) => { $crate::Method::Put };
+    (@method PATCH) => { $crate::Method::Patch };
+    (@method DELETE) => { $crate::Method::Delete };
+    (@method HEAD) => { $crate::Method::Head };
+    (@method OPTIONS) => { $crate::Method::Options };
+    (@method TRACE) => { $crate::Method::Trace };
+    (@method CONNECT) => { $crate::Method::Connect };
+    ($vis:vis $name:ident, $type:ty, $parent:ident, $path:literal $(, trailing_slash = $trailing_slash:ident)? $(, auto_options = $auto_options:literal)? $(, cors = $cors:expr)? $(, catch_panics = $catch_panics:literal)? $(, panic_response = $panic_response:expr)? $(, rejection_format = $rejection_format:expr)? $(, cache_control = $cache_control:literal)? $(, compression = $compression:literal)? $(, metrics = $metrics:literal)? $(, tag = $tag:literal)? $(, description = $group_description:literal)? $(, error = $error:ty)? $(, priority = $priority:expr)?) => {
+        $crate::route_group!(@group $vis $name, $type, $type, parent = $parent, path = $path $(, trailing_slash = $trailing_slash)? $(, auto_options = $auto_options)? $(, cors = $cors)? $(, catch_panics = $catch_panics)? $(, panic_response = $panic_response)? $(, rejection_format = $rejection_format)? $(, cache_control = $cache_control)? $(, compression = $compression)? $(, metrics = $metrics)? $(, tag = $tag)? $(, description = $group_description)?);
+        $(
+            impl $crate::DefaultError for $name {
+                type Error = $error;
+            }
+        )?
+        $crate::route_group!(@nest $name, $parent, $path $(, priority = $priority)?);
+    };
+    ($vis:vis $name:ident, $type:ty, $parent:ident: $parent_type:ty, $path:literal $(, trailing_slash = $trailing_slash:ident)? $(, auto_options = $auto_options:literal)? $(, cors = $cors:expr)? $(, catch_panics = $catch_panics:literal)? $(, panic_response = $panic_response:expr)? $(, rejection_format = $rejection_format:expr)? $(, cache_control = $cache_control:literal)? $(, compression = $compression:literal)? $(, metrics = $metrics:literal)? $(, tag = $tag:literal)? $(, description = $group_description:literal)? $(, error = $error:ty)? $(, priority = $priority:expr)?) => {
+        $crate::route_group!(@group $vis $name, $parent_type, $type, parent = $parent, path = $path $(, trailing_slash = $trailing_slash)? $(, auto_options = $auto_options)? $(, cors = $cors)? $(, catch_panics = $catch_panics)? $(, panic_response = $panic_response)? $(, rejection_format = $rejection_format)? $(, cache_control = $cache_control)? $(, compression = $compression)? $(, metrics = $metrics)? $(, tag = $tag)? $(, description = $group_description)?);
+        $(
+            impl $crate::DefaultError for $name {
+                type Error = $error;
+            }
+        )?
+        $crate::route_group!(@nest $name, $parent, $path $(, priority = $priority)?);
+    };
+    (@nest $name:ty, $parent:ident, $path:literal $(, priority = $priority:expr)? $(, state = $state:expr)?) => {
+        const _: () = {
+            assert!(
+                matches!($path.as_bytes(), [b'/', ..]),
+                "the path of a nested group must start with `/`",
+            );
+            assert!(
+                !matches!($path.as_bytes(), [b'/']),
+                "a group cannot be nested at `/`, as axum doesn't support nesting at the root: declare its routes in the parent group instead",
+            );
+            assert!(
+                !$crate::__private::has_empty_segment($path),
+                "the path of a nested group must not contain an empty segment",
+            );
+        };
+        $crate::__registry!(submit $parent: $parent, $crate::__with_api_nest!(
+            $parent::new($path, |router, level| {
+                router.nest(
+                    &$crate::__private::path_template($path),
+                    $crate::create_router::<$name>(level + 4) $(.with_state($state))?,
+                )
+            })
+            .with_metadata($crate::RouteMetadata {
+                name: Some(stringify!($name)),
+                priority: 0 $(+ $priority)?,
+                ..$crate::RouteMetadata::EMPTY
+            })
+            .with_nested($crate::__private::Nested::of::<$name>()),
+            $name,
+            $path
+            $(, state = $state)?
+        ));
+        $crate::__registry!(submit $crate::__private::ROUTE_INFOS: $crate::RouteInfo, $crate::RouteInfo {
+            group: stringify!($parent),
+            path: $path,
+            method: None,
+            name: stringify!($name),
+            kind: $crate::RouteKind::Group,
+        });
+    };
+    ($vis:vis $name:ident, $type:ty $(, host = $host:literal)? $(, trailing_slash = $trailing_slash:ident)? $(, auto_options = $auto_options:literal)? $(, cors = $cors:expr)? $(, catch_panics = $catch_panics:literal)? $(, panic_response = $panic_response:expr)? $(, rejection_format = $rejection_format:expr)? $(, cache_control = $cache_control:literal)? $(, compression = $compression:literal)? $(, metrics = $metrics:literal)? $(, tag = $tag:literal)? $(, description = $group_description:literal)? $(, error = $error:ty)?) => {
+        $crate::route_group!(@group $vis $name, $type, $type $(, host = $host)? $(, trailing_slash = $trailing_slash)? $(, auto_options = $auto_options)? $(, cors = $cors)? $(, catch_panics = $catch_panics)? $(, panic_response = $panic_response)? $(, rejection_format = $rejection_format)? $(, cache_control = $cache_control)? $(, compression = $compression)? $(, metrics = $metrics)? $(, tag = $tag)? $(, description = $group_description)?);
+        $(
+            impl $crate::DefaultError for $name {
+                type Error = $error;
+            }
+        )?
+    };
+    (@trailing_slash) => {
+        $crate::TrailingSlash::Strict
     };
-    ($vis:vis $name:ident, $type:ty) => {
+    (@trailing_slash strict) => {
+        $crate::TrailingSlash::Strict
+    };
+    (@trailing_slash merge) => {
+        $crate::TrailingSlash::Merge
+    };
+    (@trailing_slash redirect) => {
+        $crate::TrailingSlash::Redirect
+    };
+    (@cache_control) => {
+        None
+    };
+    (@cache_control parent $parent:ident) => {
+        <$parent as $crate::Route>::cache_control()
+    };
+    (@cache_control $(parent $parent:ident)? value $cache_control:literal) => {{
+        // Invalid values fail to compile
+        const _: $crate::__private::axum::http::HeaderValue =
+            $crate::__private::axum::http::HeaderValue::from_static($cache_control);
+        Some($cache_control)
+    }};
+    (@metrics) => {
+        true
+    };
+    (@metrics parent $parent:ident) => {
+        <$parent as $crate::Route>::metrics()
+    };
+    (@metrics $(parent $parent:ident)? value $metrics:literal) => {
+        $metrics
+    };
+    (@tags) => {
+        &[]
+    };
+    (@tags parent $parent:ident) => {
+        $parent::__TAGS
+    };
+    (@tags $(parent $parent:ident)? value $tag:literal) => {
+        &[$tag]
+    };
+    (@prefix) => {
+        String::new()
+    };
+    (@prefix parent $parent:ident path $path:literal) => {
+        $crate::join_paths(&<$parent as $crate::Route>::prefix(), $path)
+    };
+    (@group $vis:vis $name:ident, $type:ty, $state:ty $(, parent = $parent:ident, path = $path:literal)? $(, host = $host:literal)? $(, trailing_slash = $trailing_slash:ident)? $(, auto_options = $auto_options:literal)? $(, cors = $cors:expr)? $(, catch_panics = $catch_panics:literal)? $(, panic_response = $panic_response:expr)? $(, rejection_format = $rejection_format:expr)? $(, cache_control = $cache_control:literal)? $(, compression = $compression:literal)? $(, metrics = $metrics:literal)? $(, tag = $tag:literal)? $(, description = $group_description:literal)?) => {
         #[derive(Copy, Clone, Debug)]
         $vis struct $name {
             path: &'static str,
-            handler: fn(axum::routing::Router<$type>, usize) -> axum::routing::Router<$type>,
+            handler: fn($crate::__private::axum::routing::Router<$type>, usize) -> $crate::__private::axum::routing::Router<$type>,
+            metadata: $crate::RouteMetadata,
+            api_handler: $crate::__private::ApiHandler<$type>,
+            nested: Option<$crate::__private::Nested>,
+            method_routes: Option<fn(&mut $crate::MethodRoutes<$type>)>,
         }
 
         impl $name {
             pub const fn new(
                 path: &'static str,
-                handler: fn(axum::routing::Router<$type>, usize) -> axum::routing::Router<$type>,
+                handler: fn($crate::__private::axum::routing::Router<$type>, usize) -> $crate::__private::axum::routing::Router<$type>,
+            ) -> Self {
+                Self {
+                    path,
+                    handler,
+                    metadata: $crate::RouteMetadata::EMPTY,
+                    api_handler: $crate::__private::ApiHandler::NONE,
+                    nested: None,
+                    method_routes: None,
+                }
+            }
+
+            pub const fn with_metadata(self, metadata: $crate::RouteMetadata) -> Self {
+                Self { metadata, ..self }
+            }
+
+            pub const fn with_api_handler(
+                self,
+                api_handler: $crate::__private::ApiHandler<$type>,
+            ) -> Self {
+                Self { api_handler, ..self }
+            }
+
+            pub const fn with_nested(self, nested: $crate::__private::Nested) -> Self {
+                Self {
+                    nested: Some(nested),
+                    ..self
+                }
+            }
+
+            /// Makes the route add its method routers to the `MethodRoutes` of
+            /// its group, see `Route::collect_routes`
+            pub const fn with_method_routes(
+                self,
+                method_routes: fn(&mut $crate::MethodRoutes<$type>),
             ) -> Self {
-                Self { path, handler }
+                Self {
+                    method_routes: Some(method_routes),
+                    ..self
+                }
             }
+
+            /// The tags of the leaf routes of this group that don't declare
+            /// any, see `Route::group_tag`
+            #[doc(hidden)]
+            pub const __TAGS: &'static [&'static str] =
+                $crate::route_group!(@tags $(parent $parent)? $(value $tag)?);
         }
 
         impl $crate::Route for $name {
@@ -57,47 +1111,1839 @@ macro_rules! route_group {
 
             fn attach(
                 &self,
-                router: axum::routing::Router<$type>,
+                router: $crate::__private::axum::routing::Router<$type>,
                 level: usize,
-            ) -> axum::routing::Router<$type> {
-                (self.handler)(router, level)
+            ) -> $crate::__private::axum::routing::Router<$type> {
+                match self.method_routes {
+                    Some(method_routes) => {
+                        let mut routes = $crate::MethodRoutes::new();
+                        method_routes(&mut routes);
+                        routes.attach((self.handler)(router, level))
+                    }
+                    None => (self.handler)(router, level),
+                }
+            }
+
+            fn collect_routes(&self, routes: &mut $crate::MethodRoutes<$type>) -> bool {
+                match self.method_routes {
+                    Some(method_routes) => {
+                        method_routes(routes);
+                        true
+                    }
+                    None => false,
+                }
             }
 
             fn path(&self) -> &'static str {
                 self.path
             }
+
+            fn name(&self) -> &'static str {
+                self.metadata.name.unwrap_or(self.path)
+            }
+
+            fn method(&self) -> Option<$crate::__private::axum::http::Method> {
+                self.metadata.method.map(Into::into)
+            }
+
+            fn summary(&self) -> Option<&'static str> {
+                self.metadata.summary
+            }
+
+            fn description(&self) -> Option<&'static str> {
+                self.metadata.description
+            }
+
+            fn tags(&self) -> &'static [&'static str] {
+                match (self.metadata.tags, self.nested) {
+                    ([], None) => Self::__TAGS,
+                    (tags, _) => tags,
+                }
+            }
+
+            fn priority(&self) -> i32 {
+                self.metadata.priority
+            }
+
+            fn host() -> Option<&'static str> {
+                None $(.or(Some($host)))?
+            }
+
+            fn trailing_slash() -> $crate::TrailingSlash {
+                $crate::route_group!(@trailing_slash $($trailing_slash)?)
+            }
+
+            fn auto_options() -> bool {
+                false $(|| $auto_options)?
+            }
+
+            $crate::__cors!($(parent $parent)? $(layer $cors)?);
+
+            $crate::__catch_panics!($(parent $parent)? $(value $catch_panics)? $(response $panic_response)?);
+
+            $crate::__rejection_format!($(parent $parent)? $(format $rejection_format)?);
+
+            fn cache_control() -> Option<&'static str> {
+                $crate::route_group!(@cache_control $(parent $parent)? $(value $cache_control)?)
+            }
+
+            $crate::__compression!($(parent $parent)? $(value $compression)?);
+
+            fn metrics() -> bool {
+                $crate::route_group!(@metrics $(parent $parent)? $(value $metrics)?)
+            }
+
+            fn group_tag() -> Option<&'static str> {
+                Self::__TAGS.first().copied()
+            }
+
+            fn group_description() -> Option<&'static str> {
+                None $(.or(Some($group_description)))?
+            }
+
+            fn prefix() -> String {
+                $crate::route_group!(@prefix $(parent $parent path $path)?)
+            }
+
+            fn info(&self) -> $crate::RouteInfo {
+                $crate::RouteInfo {
+                    group: stringify!($name),
+                    path: self.path,
+                    method: self.metadata.method,
+                    name: $crate::Route::name(self),
+                    kind: match self.nested {
+                        Some(_) => $crate::RouteKind::Group,
+                        None => $crate::RouteKind::Leaf,
+                    },
+                }
+            }
+
+            fn walk(&self, prefix: &str, f: &mut dyn FnMut(&str, &$crate::RouteMetadata)) {
+                let path = $crate::join_paths(prefix, self.path);
+                match self.nested {
+                    Some(nested) => (nested.walk)(&path, f),
+                    None => f(
+                        &path,
+                        &$crate::RouteMetadata {
+                            name: Some($crate::Route::name(self)),
+                            group: Some(stringify!($name)),
+                            tags: $crate::Route::tags(self),
+                            ..self.metadata
+                        },
+                    ),
+                }
+            }
+
+            fn walk_tags(&self, f: &mut dyn FnMut($crate::GroupTag)) {
+                if let Some(nested) = self.nested {
+                    (nested.walk_tags)(f);
+                }
+            }
+
+            fn walk_groups(&self, prefix: &str, f: &mut dyn FnMut(&str)) {
+                if let Some(nested) = self.nested {
+                    let path = $crate::join_paths(prefix, self.path);
+                    f(&path);
+                    (nested.walk_groups)(&path, f);
+                }
+            }
+
+            fn registered() -> impl Iterator<Item = &'static Self> {
+                $crate::__registry!(iter $name)
+            }
+
+            fn write_tree(&self, out: &mut String, prefix: &str, level: usize) {
+                match self.nested {
+                    Some(nested) => {
+                        let path = $crate::join_paths(prefix, self.path);
+                        out.push_str(&format!("{:level$}{path}", ""));
+                        match (nested.tag)() {
+                            Some(tag) if Some(tag) != <Self as $crate::Route>::group_tag() => {
+                                out.push_str(&format!(" (tag: {tag})\n"))
+                            }
+                            _ => out.push('\n'),
+                        }
+                        (nested.tree)(out, &path, level + 4);
+                    }
+                    None => $crate::__private::write_leaf(
+                        out,
+                        level,
+                        $crate::Route::method(self),
+                        self.path,
+                        &self.metadata,
+                    ),
+                }
+            }
         }
 
-        $crate::inventory::collect!($name);
-    };
-}
+        impl $crate::GroupState for $name {
+            type State = $state;
+        }
 
-/// Returns an iterator over the routes of the provided group
-#[macro_export]
-macro_rules! routes {
-    ($type:ty) => {
-        $crate::inventory::iter::<$type>
+        $crate::__impl_api_route!($name, $type);
+
+        $crate::__registry!(collect $vis $name);
     };
 }
 
-/// Creates a router from the provided group
+#[cfg(feature = "aide")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_api_route {
+    (@generic $name:ident, $param:ident, [$($bounds:tt)*], $type:ty) => {
+        // Generic groups have no aide variant, their routes are merged
+        // undocumented
+        impl<$param: $($bounds)*> $crate::ApiRoute for $name<$param> {
+            fn attach_api(
+                &self,
+                router: $crate::__private::aide::axum::ApiRouter<$type>,
+                level: usize,
+            ) -> $crate::__private::aide::axum::ApiRouter<$type> {
+                router.merge($crate::Route::attach(
+                    self,
+                    $crate::__private::axum::routing::Router::new(),
+                    level,
+                ))
+            }
+        }
+    };
+    ($name:ident, $type:ty) => {
+        impl $crate::ApiRoute for $name {
+            fn attach_api(
+                &self,
+                router: $crate::__private::aide::axum::ApiRouter<$type>,
+                level: usize,
+            ) -> $crate::__private::aide::axum::ApiRouter<$type> {
+                match self.api_handler.get() {
+                    Some(handler) => handler(router, level),
+                    // Routes without an aide variant are merged undocumented
+                    None => router.merge($crate::Route::attach(
+                        self,
+                        $crate::__private::axum::routing::Router::new(),
+                        level,
+                    )),
+                }
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "aide"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_api_route {
+    ($(@generic)? $name:ident, $($rest:tt)*) => {};
+}
+
+#[cfg(feature = "aide")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __with_api_nest {
+    ($route:expr, $name:ty, $path:literal $(, state = $state:expr)?) => {
+        $route.with_api_handler($crate::__private::ApiHandler::new(|router, level| {
+            router.nest(
+                &$crate::__private::path_template($path),
+                $crate::create_api_router::<$name>(level + 4) $(.with_state($state))?,
+            )
+        }))
+    };
+}
+
+#[cfg(not(feature = "aide"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __with_api_nest {
+    ($route:expr, $name:ty, $path:literal $(, state = $state:expr)?) => {
+        $route
+    };
+}
+
+#[cfg(feature = "cors")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cors {
+    () => {};
+    (parent $parent:ident) => {
+        fn cors() -> Option<$crate::cors::CorsLayer> {
+            <$parent as $crate::Route>::cors()
+        }
+    };
+    ($(parent $parent:ident)? layer $cors:expr) => {
+        fn cors() -> Option<$crate::cors::CorsLayer> {
+            Some($cors)
+        }
+    };
+}
+
+#[cfg(not(feature = "cors"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cors {
+    ($(parent $parent:ident)?) => {};
+    ($(parent $parent:ident)? layer $cors:expr) => {
+        compile_error!("the `cors` argument of `route_group!` requires the `cors` feature");
+    };
+}
+
+#[cfg(feature = "catch-panic")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __catch_panics {
+    () => {};
+    (parent $parent:ident) => {
+        fn catch_panics() -> Option<$crate::PanicHandler> {
+            <$parent as $crate::Route>::catch_panics()
+        }
+    };
+    ($(parent $parent:ident)? value $catch_panics:literal) => {
+        fn catch_panics() -> Option<$crate::PanicHandler> {
+            let response: $crate::PanicHandler = $crate::default_panic_response;
+            $catch_panics.then_some(response)
+        }
+    };
+    ($(parent $parent:ident)? value $catch_panics:literal response $panic_response:expr) => {
+        fn catch_panics() -> Option<$crate::PanicHandler> {
+            let response: $crate::PanicHandler = $panic_response;
+            $catch_panics.then_some(response)
+        }
+    };
+    ($(parent $parent:ident)? response $panic_response:expr) => {
+        compile_error!(
+            "the `panic_response` argument of `route_group!` requires `catch_panics = true`"
+        );
+    };
+}
+
+#[cfg(not(feature = "catch-panic"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __catch_panics {
+    ($(parent $parent:ident)?) => {};
+    ($(parent $parent:ident)? $(value $catch_panics:literal)? $(response $panic_response:expr)?) => {
+        compile_error!("the `catch_panics` and `panic_response` arguments of `route_group!` require the `catch-panic` feature");
+    };
+}
+
+#[cfg(feature = "compression")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __compression {
+    (@route $group:ident, $method_router:expr) => {
+        $method_router.route_layer($crate::__private::compression_layer(
+            <$group as $crate::Route>::compression(),
+        ))
+    };
+    (@route $group:ident, $method_router:expr, $compression:literal) => {
+        $method_router.route_layer($crate::__private::compression_layer($compression))
+    };
+    () => {};
+    (parent $parent:ident) => {
+        fn compression() -> bool {
+            <$parent as $crate::Route>::compression()
+        }
+    };
+    ($(parent $parent:ident)? value $compression:literal) => {
+        fn compression() -> bool {
+            $compression
+        }
+    };
+}
+
+#[cfg(not(feature = "compression"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __compression {
+    (@route $group:ident, $method_router:expr) => {
+        $method_router
+    };
+    (@route $group:ident, $method_router:expr, $compression:literal) => {
+        compile_error!(
+            "the `compression` argument of `static_response!` requires the `compression` feature"
+        )
+    };
+    ($(parent $parent:ident)?) => {};
+    ($(parent $parent:ident)? value $compression:literal) => {
+        compile_error!(
+            "the `compression` argument of `route_group!` requires the `compression` feature"
+        );
+    };
+}
+
+#[cfg(feature = "rejection-format")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rejection_format {
+    () => {};
+    (parent $parent:ident) => {
+        fn rejection_format() -> Option<$crate::RejectionFormat> {
+            <$parent as $crate::Route>::rejection_format()
+        }
+    };
+    ($(parent $parent:ident)? format $rejection_format:expr) => {
+        fn rejection_format() -> Option<$crate::RejectionFormat> {
+            #[allow(unused_imports)]
+            use $crate::__private::json_rejection as json;
+            let format: $crate::RejectionFormat = $rejection_format;
+            Some(format)
+        }
+    };
+}
+
+#[cfg(not(feature = "rejection-format"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rejection_format {
+    ($(parent $parent:ident)?) => {};
+    ($(parent $parent:ident)? format $rejection_format:expr) => {
+        compile_error!(
+            "the `rejection_format` argument of `route_group!` requires the `rejection-format` feature"
+        );
+    };
+}
+
+#[cfg(not(feature = "backend-linkme"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __registry {
+    (collect $vis:vis $name:ident) => {
+        $crate::inventory::collect!($name);
+    };
+    (iter $name:ident) => {
+        $crate::inventory::iter::<$name>.into_iter()
+    };
+    (submit $slice:path: $type:ty, $vis:vis static $name:ident = $value:expr) => {
+        $vis static $name: $type = $value;
+
+        $crate::inventory::submit! {
+            $name
+        }
+    };
+    (submit $slice:path: $type:ty, $value:expr) => {
+        $crate::inventory::submit!($value);
+    };
+}
+
+// Each group declares a distributed slice named after itself, statics living
+// in another namespace than braced structs
+#[cfg(feature = "backend-linkme")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __registry {
+    (collect $vis:vis $name:ident) => {
+        #[allow(non_upper_case_globals)]
+        #[$crate::__private::linkme::distributed_slice]
+        #[linkme(crate = $crate::__private::linkme)]
+        $vis static $name: [$name];
+    };
+    (iter $name:ident) => {
+        $name.iter()
+    };
+    (submit $slice:path: $type:ty, $vis:vis static $name:ident = $value:expr) => {
+        #[$crate::__private::linkme::distributed_slice($slice)]
+        #[linkme(crate = $crate::__private::linkme)]
+        $vis static $name: $type = $value;
+    };
+    (submit $slice:path: $type:ty, $value:expr) => {
+        const _: () = {
+            #[$crate::__private::linkme::distributed_slice($slice)]
+            #[linkme(crate = $crate::__private::linkme)]
+            static ROUTE: $type = $value;
+        };
+    };
+}
+
+/// Nests a group with a state of its own into a parent group, which provides
+/// the state of the nested group when the router is created
+///
+/// The nested group is declared as a root group with its own state, so that
+/// the parent doesn't depend on its state type:
+///
+/// ```
+/// # use axum_distributed_routing::{mount_group, route_group};
+/// # #[derive(Clone)]
+/// # struct AppState;
+/// # #[derive(Clone)]
+/// # pub struct BillingState;
+/// # fn make_billing_state() -> BillingState {
+/// #     BillingState
+/// # }
+/// # route_group!(Routes, AppState);
+/// route_group!(pub Billing, BillingState);
+///
+/// mount_group!(Routes, "/billing", Billing, state = make_billing_state());
+/// # fn main() {}
+/// ```
+///
+/// The state expression is evaluated each time the parent is attached to a
+/// router, see `create_router!`. The mounted routes are listed under the
+/// parent by `full_paths`, `print_routes` and `routes_manifest!`, but their
+/// URL builders, which only know the groups the routes are declared in,
+/// don't include the mount path. The mount path can't capture path
+/// parameters.
+#[macro_export]
+macro_rules! mount_group {
+    ($parent:ident, $path:literal, $name:ident, state = $state:expr $(, priority = $priority:expr)? $(,)?) => {
+        const _: () = assert!(
+            !$crate::__private::has_capture($path),
+            "the path of a mounted group can't capture path parameters",
+        );
+        $crate::route_group!(@nest $name, $parent, $path $(, priority = $priority)?, state = $state);
+    };
+}
+
+/// Nests a generic group, declared with `route_group!` in e.g. a library,
+/// into a group of the application, for the state of the application
+///
+/// Generic groups can't be registered in the parent by the library, which
+/// doesn't know the state, so each application instantiates the ones it uses:
+///
+/// ```
+/// # use axum_distributed_routing::{instantiate_group, route_group};
+/// # pub trait HasAuthConfig {}
+/// # route_group!(pub Auth<S: HasAuthConfig + Clone + Send + Sync + 'static>, S);
+/// #[derive(Clone)]
+/// struct AppState;
+///
+/// impl HasAuthConfig for AppState {}
+///
+/// route_group!(Routes, AppState);
+///
+/// instantiate_group!(Auth<AppState>, Routes, "/auth");
+/// # fn main() {}
+/// ```
+///
+/// Without a parent, only checks that the state satisfies the bounds of the
+/// group, which can then be used as a root group, e.g.
+/// `create_router!(Auth<AppState>)`.
+#[macro_export]
+macro_rules! instantiate_group {
+    ($name:ident<$state:ty>, $parent:ident, $path:literal $(, priority = $priority:expr)? $(,)?) => {
+        $crate::route_group!(@nest $name<$state>, $parent, $path $(, priority = $priority)?);
+    };
+    ($name:ident<$state:ty> $(,)?) => {
+        const _: fn() -> $crate::__private::axum::Router<$state> =
+            || $crate::create_router::<$name<$state>>(0);
+    };
+}
+
+/// Returns an iterator over the descriptions (`RouteInfo`) of the routes of
+/// the provided group
+///
+/// Nested groups are listed as a single route of kind `RouteKind::Group`.
+#[macro_export]
+macro_rules! routes {
+    ($type:ty) => {
+        $crate::routes::<$type>()
+    };
+}
+
+/// Returns an iterator over the routes of the provided group
+#[macro_export]
+macro_rules! raw_routes {
+    ($type:ty) => {
+        <$type as $crate::Route>::registered()
+    };
+}
+
+/// Panics unless a route of the provided group, or of its nested groups,
+/// matches a method and a path
+///
+/// The path is the one from the provided group, including the prefixes of
+/// nested groups, e.g. `/api/health` from the root group. Path parameters can
+/// be written with or without their type, e.g. `/users/{id}` matches
+/// `/users/{id:u64}`. Routes matching any method (e.g. `route_service!`)
+/// match every method. The panic message lists the registered routes closest
+/// to the path.
+///
+/// ```
+/// # use axum_distributed_routing::{assert_route_exists, route, route_group};
+/// # route_group!(Routes, ());
+/// # route_group!(Api, (), Routes, "/api");
+/// # route!(group = Api, path = "/health", method = GET, async health {});
+/// # fn main() {
+/// assert_route_exists!(Routes, GET "/api/health");
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_route_exists {
+    ($type:ty, $method:ident $path:expr) => {
+        $crate::__private::assert_route::<$type>(stringify!($method), $path, true)
+    };
+}
+
+/// Panics if a route of the provided group, or of its nested groups, matches
+/// a method and a path (see `assert_route_exists!`), e.g. to check that
+/// routes disabled with `cfg` are not registered
+///
+/// ```
+/// # use axum_distributed_routing::{assert_route_absent, route, route_group};
+/// # route_group!(Routes, ());
+/// # route_group!(Admin, (), Routes, "/admin");
+/// route!(
+///     group = Admin,
+///     path = "/users/{id:u64}",
+///     method = DELETE,
+///     cfg = feature = "user-deletion",
+///     async delete_user {}
+/// );
+///
+/// # fn main() {
+/// assert_route_absent!(Routes, DELETE "/admin/users/{id}");
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_route_absent {
+    ($type:ty, $method:ident $path:expr) => {
+        $crate::__private::assert_route::<$type>(stringify!($method), $path, false)
+    };
+}
+
+/// Registers a tower service in a group, e.g. `tower_http`'s `ServeDir` or a
+/// `tonic` service
+///
+/// The service is attached with `Router::route_service` at the exact path, or
+/// with `Router::nest_service` under the path with `nest = true`. The service
+/// expression is evaluated each time the group is attached to a router.
+///
+/// ```
+/// # use std::convert::Infallible;
+/// # use axum::body::Body;
+/// # use axum::extract::Request;
+/// # use axum::response::Response;
+/// # use axum_distributed_routing::{route_group, route_service};
+/// # route_group!(Routes, ());
+/// route_service!(
+///     group = Routes,
+///     path = "/assets",
+///     service = tower::service_fn(|request: Request| async move {
+///         Ok::<_, Infallible>(Response::new(Body::from(request.uri().to_string())))
+///     }),
+///     nest = true
+/// );
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! route_service {
+    (group = $group:ident, path = $path:literal, service = $service:expr, nest = true $(,)?) => {
+        $crate::route_service!(@submit $group, $path, |router, _| router.nest_service($path, $service));
+    };
+    (group = $group:ident, path = $path:literal, service = $service:expr $(, nest = false)? $(,)?) => {
+        $crate::route_service!(@submit $group, $path, |router, _| router.route_service($path, $service));
+    };
+    (@submit $group:ident, $path:literal, $handler:expr) => {
+        $crate::__registry!(submit $group: $group, $group::new($path, $handler));
+        $crate::__registry!(submit $crate::__private::ROUTE_INFOS: $crate::RouteInfo, $crate::RouteInfo {
+            group: stringify!($group),
+            path: $path,
+            method: None,
+            name: $path,
+            kind: $crate::RouteKind::Leaf,
+        });
+    };
+}
+
+/// Registers a redirect in a group, for any method
+///
+/// The path parameters of `from` are forwarded into the placeholders of the
+/// same name in `to`. The status defaults to `308 Permanent Redirect`, and can
+/// be set to `301`, `302`, `303`, `307` or `308`.
+///
+/// ```
+/// # use axum_distributed_routing::{redirect, route_group};
+/// # route_group!(Routes, ());
+/// redirect!(group = Routes, from = "/docs", to = "/docs/latest");
+/// redirect!(
+///     group = Routes,
+///     from = "/old-api/{id}",
+///     to = "/api/v1/items/{id}",
+///     status = 307
+/// );
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! redirect {
+    (group = $group:ident, from = $from:literal, to = $to:literal $(, status = $status:literal)? $(,)?) => {
+        const _: () = assert!(
+            matches!($crate::redirect!(@status $($status)?), 301 | 302 | 303 | 307 | 308),
+            "the status of a redirect must be one of 301, 302, 303, 307 and 308",
+        );
+        $crate::__registry!(submit $group: $group, $group::new($from, |router, _| router)
+        .with_method_routes(|routes| {
+            $crate::__private::collect_leaf::<$group>(
+                routes,
+                $from,
+                $crate::__private::axum::routing::any(|params| async move {
+                    $crate::__private::redirect($to, $crate::redirect!(@status $($status)?), params)
+                }),
+            )
+        })
+        .with_metadata($crate::RouteMetadata {
+            summary: Some(concat!("Redirects to ", $to)),
+            ..$crate::RouteMetadata::EMPTY
+        }));
+        $crate::__registry!(submit $crate::__private::ROUTE_INFOS: $crate::RouteInfo, $crate::RouteInfo {
+            group: stringify!($group),
+            path: $from,
+            method: None,
+            name: $from,
+            kind: $crate::RouteKind::Leaf,
+        });
+    };
+    (@status) => {
+        308
+    };
+    (@status $status:literal) => {
+        $status
+    };
+}
+
+/// Registers a `GET` route in a group responding with a constant body, e.g.
+/// from `include_str!` or `include_bytes!`
+///
+/// `HEAD` requests are answered with the same headers and no body. A
+/// `Cache-Control` header can be added with `cache_control`. With the
+/// `compression` feature, the body is compressed like the responses of
+/// `route!`, following the group unless `compression` is given.
+///
+/// ```
+/// # use axum_distributed_routing::{route_group, static_response};
+/// # route_group!(Routes, ());
+/// static_response!(
+///     group = Routes,
+///     path = "/robots.txt",
+///     content_type = "text/plain",
+///     body = "User-agent: *\nDisallow:\n",
+///     cache_control = "public, max-age=86400"
+/// );
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! static_response {
+    (group = $group:ident, path = $path:literal, content_type = $content_type:literal, body = $body:expr $(, cache_control = $cache_control:literal)? $(, compression = $compression:literal)? $(,)?) => {
+        // Checks the header values at compile time
+        const _: $crate::__private::axum::http::HeaderValue =
+            $crate::__private::axum::http::HeaderValue::from_static($content_type);
+        $(
+            const _: $crate::__private::axum::http::HeaderValue =
+                $crate::__private::axum::http::HeaderValue::from_static($cache_control);
+        )?
+        $crate::__registry!(submit $group: $group, $group::new($path, |router, _| router)
+        .with_method_routes(|routes| {
+            $crate::__private::collect_leaf::<$group>(
+                routes,
+                $path,
+                $crate::__compression!(
+                    @route $group,
+                    $crate::__private::axum::routing::get(|| async {
+                        $crate::__private::static_response($content_type, None $(.or(Some($cache_control)))?, $body)
+                    })
+                    $(, $compression)?
+                ),
+            )
+        })
+        .with_metadata($crate::RouteMetadata {
+            method: Some($crate::Method::Get),
+            ..$crate::RouteMetadata::EMPTY
+        }));
+        $crate::__registry!(submit $crate::__private::ROUTE_INFOS: $crate::RouteInfo, $crate::RouteInfo {
+            group: stringify!($group),
+            path: $path,
+            method: Some($crate::Method::Get),
+            name: $path,
+            kind: $crate::RouteKind::Leaf,
+        });
+    };
+}
+
+/// Creates a router from the provided group
+///
+/// Several groups sharing a state can be merged into one router with
+/// `create_router!(A, B, C)`, axum rejecting conflicting routes.
+///
+/// Groups declared with a `host` (see `route_group!`) only serve the requests
+/// whose `Host` header matches it, exact hosts being tried before wildcards.
+/// Other requests are served by the groups without a host, which form the
+/// default router:
+///
+/// ```
+/// # use axum_distributed_routing::{create_router, route_group};
+/// # #[derive(Clone)]
+/// # struct AppState;
+/// route_group!(Admin, AppState, host = "admin.example.com");
+/// route_group!(Api, AppState, host = "api.example.com");
+/// route_group!(Public, AppState);
+///
+/// # fn main() {
+/// let router: axum::Router<AppState> = create_router!(Admin, Api, Public);
+/// # }
+/// ```
+///
+/// The router of the matching host is then given the state on each request,
+/// `GroupBuilder::with_state` provides it once instead:
+///
+/// ```
+/// # use axum_distributed_routing::{GroupBuilder, route_group};
+/// # #[derive(Clone)]
+/// # struct AppState;
+/// # route_group!(Admin, AppState, host = "admin.example.com");
+/// # route_group!(Public, AppState);
+/// # fn main() {
+/// let router: axum::Router = GroupBuilder::new()
+///     .group::<Admin>()
+///     .group::<Public>()
+///     .with_state(AppState);
+/// # }
+/// ```
 #[macro_export]
 macro_rules! create_router {
+    ($type:ty $(, $other:ty)* $(,)?) => {
+        $crate::GroupBuilder::<<$type as $crate::Route>::State>::new()
+            .group::<$type>()
+            $(.group::<$other>())*
+            .build()
+    };
+}
+
+/// Returns the router of the provided group, built by `create_router!` on the
+/// first call and cloned on the next ones, see `cached_router`
+///
+/// ```
+/// # use axum_distributed_routing::{cached_router, route_group};
+/// # #[derive(Clone)]
+/// # struct AppState;
+/// # route_group!(Routes, AppState);
+/// # fn main() {
+/// # let state = AppState;
+/// let router: axum::Router = cached_router!(Routes).with_state(state);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! cached_router {
     ($type:ty) => {
-        $crate::create_router::<$type>(0)
+        $crate::cached_router::<$type>()
+    };
+}
+
+/// Creates a router from the provided groups, like `create_router!`, traced
+/// with a `TraceLayer` naming the spans after the routes (see `trace_layer`)
+///
+/// ```
+/// # use axum_distributed_routing::{route_group, traced_router};
+/// # route_group!(Routes, ());
+/// # fn main() {
+/// let router: axum::Router = traced_router!(Routes);
+/// # }
+/// ```
+///
+/// The route of a request is known once it is routed, so requests to groups
+/// declared with a `host` are recorded without their route.
+#[cfg(feature = "trace")]
+#[macro_export]
+macro_rules! traced_router {
+    ($type:ty $(, $other:ty)* $(,)?) => {
+        $crate::create_router!($type $(, $other)*).layer($crate::trace_layer::<$type>())
     };
 }
 
 #[doc(hidden)]
-pub fn create_router<T: Route + 'static>(level: usize) -> Router<T::State>
+pub mod __private {
+    use std::any::{Any, TypeId};
+    use std::collections::HashMap;
+    use std::marker::PhantomData;
+    use std::sync::{Mutex, OnceLock, PoisonError};
+
+    pub use axum;
+
+    #[cfg(feature = "backend-linkme")]
+    pub use linkme;
+
+    /// The descriptions of every route, see `all_routes`
+    #[cfg(feature = "backend-linkme")]
+    #[linkme::distributed_slice]
+    pub static ROUTE_INFOS: [crate::RouteInfo];
+
+    #[cfg(feature = "aide")]
+    pub use aide;
+
+    #[cfg(feature = "garde")]
+    pub use garde;
+
+    /// The format of `route_group!`'s `rejection_format = json`
+    #[cfg(feature = "rejection-format")]
+    pub fn json_rejection(rejection: crate::Rejection) -> axum::response::Response {
+        rejection.into_json_response()
+    }
+
+    /// Runs the block of a `blocking` route on the blocking pool of tokio,
+    /// see `route!`
+    pub async fn run_blocking<T>(
+        block: impl FnOnce() -> T + Send + 'static,
+    ) -> axum::response::Response
+    where
+        T: axum::response::IntoResponse + Send + 'static,
+    {
+        use axum::response::IntoResponse;
+
+        match tokio::task::spawn_blocking(block).await {
+            Ok(response) => response.into_response(),
+            // The block panicked, its panic was already printed
+            Err(_) => axum::http::StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+        }
+    }
+
+    /// Compresses the responses of a route with gzip or brotli, following
+    /// the `Accept-Encoding` of the request, when `enabled`, see `route!`
+    ///
+    /// The responses excluded by `DefaultPredicate` (small bodies, images,
+    /// event streams...) are never compressed.
+    #[cfg(feature = "compression")]
+    pub fn compression_layer(
+        enabled: bool,
+    ) -> tower_http::compression::CompressionLayer<impl tower_http::compression::Predicate> {
+        use axum::http::{Extensions, HeaderMap, StatusCode, Version};
+        use tower_http::compression::{CompressionLayer, DefaultPredicate, Predicate};
+
+        CompressionLayer::new().compress_when(
+            DefaultPredicate::new()
+                .and(move |_: StatusCode, _: Version, _: &HeaderMap, _: &Extensions| enabled),
+        )
+    }
+
+    /// The layers of a group, applied to its routes but not to its nested
+    /// groups, see `create_router`
+    pub struct GroupLayers {
+        cache_control: Option<&'static str>,
+        #[cfg(feature = "cors")]
+        cors: Option<tower_http::cors::CorsLayer>,
+        #[cfg(feature = "catch-panic")]
+        catch_panics: Option<crate::PanicHandler>,
+    }
+
+    impl GroupLayers {
+        pub fn of<T: crate::Route>() -> Self {
+            Self {
+                cache_control: T::cache_control(),
+                #[cfg(feature = "cors")]
+                cors: T::cors(),
+                #[cfg(feature = "catch-panic")]
+                catch_panics: T::catch_panics(),
+            }
+        }
+
+        pub fn is_empty(&self) -> bool {
+            if self.cache_control.is_some() {
+                return false;
+            }
+            #[cfg(feature = "cors")]
+            if self.cors.is_some() {
+                return false;
+            }
+            #[cfg(feature = "catch-panic")]
+            if self.catch_panics.is_some() {
+                return false;
+            }
+            true
+        }
+
+        pub fn apply<S>(self, mut router: axum::Router<S>) -> axum::Router<S>
+        where
+            S: Clone + Send + Sync + 'static,
+        {
+            // The header set by the layers of the routes is kept
+            if let Some(value) = self.cache_control {
+                router = router.layer(axum::middleware::map_response(
+                    move |mut response: axum::response::Response| async move {
+                        set_cache_control(&mut response, value);
+                        response
+                    },
+                ));
+            }
+            // Panics are caught inside the CORS layer, so that the browser
+            // can read the response
+            #[cfg(feature = "catch-panic")]
+            if let Some(response) = self.catch_panics {
+                router = router.layer(tower_http::catch_panic::CatchPanicLayer::custom(response));
+            }
+            #[cfg(feature = "cors")]
+            if let Some(cors) = self.cors {
+                router = router.layer(cors);
+            }
+            router
+        }
+    }
+
+    /// Tags an aide operation with the tag of its group, see
+    /// `Route::group_tag`
+    #[cfg(feature = "aide")]
+    pub fn tag_operation<'t, T: crate::Route>(
+        op: aide::transform::TransformOperation<'t>,
+    ) -> aide::transform::TransformOperation<'t> {
+        match T::group_tag() {
+            Some(tag) => op.tag(tag),
+            None => op,
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    pub use tracing;
+
+    #[cfg(feature = "client")]
+    pub use reqwest;
+
+    pub use serde;
+    pub use serde_urlencoded;
+
+    /// Appends a path parameter to a URL built by the `<name>_url` function
+    /// of a route, percent-encoding everything but the unreserved characters
+    pub fn push_segment(url: &mut String, value: &dyn std::fmt::Display) {
+        for byte in value.to_string().bytes() {
+            match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                    url.push(byte as char)
+                }
+                _ => url.push_str(&format!("%{byte:02X}")),
+            }
+        }
+    }
+
+    #[cfg(feature = "swagger-ui")]
+    pub use crate::swagger_ui::swagger_ui;
+
+    /// Walks the routes of a nested group, see `Route::walk`
+    pub type Walk = fn(&str, &mut dyn FnMut(&str, &crate::RouteMetadata));
+
+    /// Walks the groups nested in a nested group, see `Route::walk_groups`
+    pub type WalkGroups = fn(&str, &mut dyn FnMut(&str));
+
+    /// The functions recursing into a nested group, stored by the route
+    /// mounting it
+    #[derive(Copy, Clone, Debug)]
+    pub struct Nested {
+        pub walk: Walk,
+        pub tree: fn(&mut String, &str, usize),
+        pub tag: fn() -> Option<&'static str>,
+        pub walk_tags: fn(&mut dyn FnMut(crate::GroupTag)),
+        pub walk_groups: WalkGroups,
+    }
+
+    impl Nested {
+        pub const fn of<T: crate::Route + 'static>() -> Self {
+            Self {
+                walk: walk::<T>,
+                tree: write_tree::<T>,
+                tag: T::group_tag,
+                walk_tags: walk_tags::<T>,
+                walk_groups: walk_groups::<T>,
+            }
+        }
+    }
+
+    pub fn walk<T: crate::Route + 'static>(
+        prefix: &str,
+        f: &mut dyn FnMut(&str, &crate::RouteMetadata),
+    ) {
+        for route in T::registered() {
+            route.walk(prefix, f);
+        }
+    }
+
+    pub fn walk_tags<T: crate::Route + 'static>(f: &mut dyn FnMut(crate::GroupTag)) {
+        if let Some(name) = T::group_tag() {
+            f(crate::GroupTag {
+                name,
+                description: T::group_description(),
+            });
+        }
+        for route in T::registered() {
+            route.walk_tags(f);
+        }
+    }
+
+    pub fn walk_groups<T: crate::Route + 'static>(prefix: &str, f: &mut dyn FnMut(&str)) {
+        for route in T::registered() {
+            route.walk_groups(prefix, f);
+        }
+    }
+
+    /// The routes of a generic group for one of its states, built on the
+    /// first call and leaked, statics not being generic
+    pub fn generic_routes<T: Send + Sync + 'static>(routes: fn() -> Vec<T>) -> &'static [T] {
+        type Routes = Mutex<HashMap<TypeId, &'static (dyn Any + Send + Sync)>>;
+        static ROUTES: OnceLock<Routes> = OnceLock::new();
+
+        let mut generic = ROUTES
+            .get_or_init(Routes::default)
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let routes = *generic
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::leak(Box::new(routes())));
+        routes
+            .downcast_ref::<Vec<T>>()
+            .expect("the routes are stored by type")
+    }
+
+    /// The routes of a group in attach order, see `Route::priority`
+    pub fn sorted_routes<T: crate::Route + 'static>() -> Vec<&'static T> {
+        let mut routes = T::registered().collect::<Vec<_>>();
+        routes.sort_by_key(|route| (std::cmp::Reverse(route.priority()), route.path()));
+        routes
+    }
+
+    /// Writes the routes of a group sorted by path then method, see
+    /// `Route::write_tree`
+    pub fn write_tree<T: crate::Route + 'static>(out: &mut String, prefix: &str, level: usize) {
+        let mut routes = T::registered().collect::<Vec<_>>();
+        routes.sort_by_cached_key(|route| (route.path(), route.method().map(|m| m.to_string())));
+        for route in routes {
+            route.write_tree(out, prefix, level);
+        }
+    }
+
+    pub fn write_leaf(
+        out: &mut String,
+        level: usize,
+        method: Option<axum::http::Method>,
+        path: &str,
+        metadata: &crate::RouteMetadata,
+    ) {
+        out.push_str(&format!("{:level$}", ""));
+        if let Some(method) = method {
+            out.push_str(&format!("{method} "));
+        }
+        out.push_str(path);
+        if !metadata.aliases.is_empty() {
+            out.push_str(&format!(" (aliases: {})", metadata.aliases.join(", ")));
+        }
+        if !metadata.guards.is_empty() {
+            out.push_str(&format!(" (requires: {})", metadata.guards.join(", ")));
+        }
+        if let Some(deprecation) = metadata.deprecation {
+            out.push_str(&format!(" (deprecated since {})", deprecation.since));
+        }
+        out.push('\n');
+    }
+
+    /// The path with axum's capture syntax, see `path_template`
+    pub fn path_template(path: &str) -> String {
+        crate::path_template(path)
+    }
+
+    /// The captures of a path, as `(name, type)`, e.g.
+    /// `[("org_id", Some("u64"))]` for `/orgs/{org_id:u64}`
+    fn captures(path: &str) -> Vec<(&str, Option<&str>)> {
+        path.split('{')
+            .skip(1)
+            .filter_map(|capture| capture.split_once('}'))
+            .map(|(capture, _)| match capture.split_once(':') {
+                Some((name, ty)) => (name.trim(), Some(ty.trim())),
+                None => (capture.trim(), None),
+            })
+            .collect()
+    }
+
+    /// Checks that the path parameters a route inherits from its group with
+    /// `inherit` are the captures of the paths the group is nested at, in
+    /// order, as they are extracted along the own path parameters of the
+    /// route
+    ///
+    /// # Panics
+    /// Panics with the captures of the group if they differ, or if one of
+    /// them is declared with another type than in the group path.
+    pub fn check_inherited<T: crate::Route + 'static>(route: &str, inherited: &[(&str, &str)]) {
+        let prefix = T::prefix();
+        let captures = captures(&prefix);
+        let names = captures.iter().map(|(name, _)| *name);
+        let group = std::any::type_name::<T>();
+        let group = group.rsplit("::").next().unwrap_or(group);
+        if !names.eq(inherited.iter().map(|(name, _)| *name)) {
+            let expected = captures
+                .iter()
+                .map(|(name, ty)| format!("{name}: {}", ty.unwrap_or("Type")))
+                .collect::<Vec<_>>();
+            panic!(
+                "the route `{route}` of {group} must inherit the path parameters of {prefix} with `inherit = [{}]`",
+                expected.join(", ")
+            );
+        }
+        for ((name, expected), (_, ty)) in captures.iter().zip(inherited) {
+            if let Some(expected) = expected
+                && expected.replace(' ', "") != *ty
+            {
+                panic!(
+                    "the route `{route}` of {group} inherits `{name}` as `{ty}`, but it is declared as `{expected}` in {prefix}"
+                );
+            }
+        }
+    }
+
+    /// Replaces the captures of the prefix of a group with the values of the
+    /// path parameters a route inherits, in order, see `check_inherited`
+    pub fn fill_captures(prefix: &str, values: &[&dyn std::fmt::Display]) -> String {
+        let mut url = String::with_capacity(prefix.len());
+        let mut values = values.iter();
+        let mut rest = prefix;
+        while let Some((literal, capture)) = rest.split_once('{') {
+            url.push_str(literal);
+            let Some((capture, after)) = capture.split_once('}') else {
+                url.push('{');
+                rest = capture;
+                break;
+            };
+            match values.next() {
+                Some(value) => push_segment(&mut url, *value),
+                None => url.push_str(&format!("{{{capture}}}")),
+            }
+            rest = after;
+        }
+        url.push_str(rest);
+        url
+    }
+
+    /// Panics unless the provided group has (or, with `exists = false`,
+    /// doesn't have) a route for `method` and `path`, see
+    /// `assert_route_exists!`
+    #[track_caller]
+    pub fn assert_route<T: crate::Route + 'static>(method: &str, path: &str, exists: bool) {
+        let target = crate::path_template(path);
+        let mut routes = Vec::new();
+        walk::<T>("", &mut |path, metadata| {
+            routes.push((
+                metadata.method.map(crate::Method::as_str),
+                crate::path_template(path),
+            ));
+        });
+        let found = routes
+            .iter()
+            .any(|(m, path)| *path == target && m.is_none_or(|m| m.eq_ignore_ascii_case(method)));
+        let group = std::any::type_name::<T>();
+        let group = group.rsplit("::").next().unwrap_or(group);
+        if found && !exists {
+            panic!("{method} {path} is registered in {group}");
+        }
+        if found || !exists {
+            return;
+        }
+
+        // The routes sharing the longest prefix with the path, which include
+        // the other methods of the path if any
+        let common = |path: &str| {
+            path.bytes()
+                .zip(target.bytes())
+                .take_while(|(a, b)| a == b)
+                .count()
+        };
+        routes.sort_by(|(a_method, a), (b_method, b)| {
+            common(b)
+                .cmp(&common(a))
+                .then_with(|| (a, a_method).cmp(&(b, b_method)))
+        });
+        let nearby = routes
+            .iter()
+            .take(5)
+            .map(|(method, path)| format!("\n    {} {path}", method.unwrap_or("*")))
+            .collect::<String>();
+        if nearby.is_empty() {
+            panic!("{method} {path} is not registered in {group}, which has no routes");
+        }
+        panic!("{method} {path} is not registered in {group}, nearby routes:{nearby}");
+    }
+
+    /// Attaches the aide variant of a route to an `ApiRouter`
+    #[cfg(feature = "aide")]
+    pub type ApiAttach<S> = fn(aide::axum::ApiRouter<S>, usize) -> aide::axum::ApiRouter<S>;
+
+    /// The aide variant of a route, stored by groups alongside the axum one.
+    ///
+    /// Without the `aide` feature this is an empty placeholder, so that group
+    /// definitions don't depend on the feature.
+    pub struct ApiHandler<S> {
+        #[cfg(feature = "aide")]
+        handler: Option<ApiAttach<S>>,
+        state: PhantomData<fn() -> S>,
+    }
+
+    impl<S> ApiHandler<S> {
+        pub const NONE: Self = Self {
+            #[cfg(feature = "aide")]
+            handler: None,
+            state: PhantomData,
+        };
+
+        #[cfg(feature = "aide")]
+        pub const fn new(handler: ApiAttach<S>) -> Self {
+            Self {
+                handler: Some(handler),
+                state: PhantomData,
+            }
+        }
+
+        #[cfg(feature = "aide")]
+        pub fn get(&self) -> Option<ApiAttach<S>> {
+            self.handler
+        }
+    }
+
+    impl<S> Clone for ApiHandler<S> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<S> Copy for ApiHandler<S> {}
+
+    impl<S> std::fmt::Debug for ApiHandler<S> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("ApiHandler").finish_non_exhaustive()
+        }
+    }
+
+    /// Used by `route!` to collect a leaf route, along with the path
+    /// differing by a trailing slash depending on the `TrailingSlash` of its
+    /// group
+    pub fn collect_leaf<T: crate::Route>(
+        routes: &mut crate::MethodRoutes<T::State>,
+        path: &'static str,
+        method_router: axum::routing::MethodRouter<T::State>,
+    ) {
+        match (T::trailing_slash(), trailing_slash_variant(path)) {
+            (crate::TrailingSlash::Merge, Some(variant)) => {
+                routes.route(path, method_router.clone());
+                routes.route(&variant, method_router);
+            }
+            (crate::TrailingSlash::Redirect, Some(variant)) => {
+                routes.route(path, method_router);
+                routes.fallback(&variant, axum::routing::any(redirect_trailing_slash));
+            }
+            _ => routes.route(path, method_router),
+        }
+    }
+
+    /// The path differing from `path` by a trailing slash, none for `/` and
+    /// paths ending with a wildcard
+    fn trailing_slash_variant(path: &str) -> Option<String> {
+        match path.strip_suffix('/') {
+            _ if path == "/" || path.contains("{*") => None,
+            Some(path) => Some(path.to_owned()),
+            None => Some(format!("{path}/")),
+        }
+    }
+
+    /// Redirects to the path of the request with its trailing slash added or
+    /// removed
+    async fn redirect_trailing_slash(
+        axum::extract::OriginalUri(uri): axum::extract::OriginalUri,
+    ) -> axum::response::Redirect {
+        let path = uri.path();
+        let path = match path.strip_suffix('/') {
+            Some(path) => path.to_owned(),
+            None => format!("{path}/"),
+        };
+
+        match uri.query() {
+            Some(query) => axum::response::Redirect::permanent(&format!("{path}?{query}")),
+            None => axum::response::Redirect::permanent(&path),
+        }
+    }
+
+    /// Used by `redirect!` to redirect to `to`, its placeholders being replaced
+    /// with the path parameters of the request
+    pub fn redirect(
+        to: &'static str,
+        status: u16,
+        params: Result<
+            axum::extract::RawPathParams,
+            axum::extract::rejection::RawPathParamsRejection,
+        >,
+    ) -> axum::response::Response {
+        use axum::response::IntoResponse;
+
+        let mut location = to.to_owned();
+        if let Ok(params) = params {
+            for (name, value) in &params {
+                location = location.replace(&format!("{{{name}}}"), &encode_path_segment(value));
+            }
+        }
+
+        let status = axum::http::StatusCode::from_u16(status)
+            .unwrap_or(axum::http::StatusCode::PERMANENT_REDIRECT);
+        match axum::http::HeaderValue::try_from(location) {
+            Ok(location) => (status, [(axum::http::header::LOCATION, location)]).into_response(),
+            Err(_) => axum::http::StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+        }
+    }
+
+    /// Percent-encodes the characters that aren't allowed in a path segment
+    fn encode_path_segment(value: &str) -> String {
+        let mut encoded = String::with_capacity(value.len());
+        for byte in value.bytes() {
+            if byte.is_ascii_alphanumeric() || b"-._~!$&'()*+,;=:@".contains(&byte) {
+                encoded.push(byte as char);
+            } else {
+                encoded.push_str(&format!("%{byte:02X}"));
+            }
+        }
+        encoded
+    }
+
+    /// Used by `static_response!` to respond with a constant body, the header
+    /// values being checked by the macro
+    pub fn static_response<B: AsRef<[u8]> + ?Sized>(
+        content_type: &'static str,
+        cache_control: Option<&'static str>,
+        body: &'static B,
+    ) -> axum::response::Response {
+        let mut response = axum::response::Response::new(axum::body::Body::from(
+            axum::body::Bytes::from_static(body.as_ref()),
+        ));
+        let headers = response.headers_mut();
+        headers.insert(
+            axum::http::header::CONTENT_TYPE,
+            axum::http::HeaderValue::from_static(content_type),
+        );
+        if let Some(cache_control) = cache_control {
+            headers.insert(
+                axum::http::header::CACHE_CONTROL,
+                axum::http::HeaderValue::from_static(cache_control),
+            );
+        }
+        response
+    }
+
+    /// Answers `OPTIONS` requests to the paths of the routes of a group, see
+    /// `Route::auto_options`
+    ///
+    /// The aliases of the routes are answered too, and the paths differing by
+    /// a trailing slash when the group serves them, see `collect_leaf`.
+    pub fn with_auto_options<T: crate::Route + 'static>(
+        mut router: axum::Router<T::State>,
+    ) -> axum::Router<T::State> {
+        let mut paths = std::collections::BTreeMap::<_, Vec<_>>::new();
+        for route in T::registered() {
+            let Some(method) = route.method() else {
+                continue;
+            };
+            let mut aliases: &[&str] = &[];
+            route.walk("", &mut |_, metadata| aliases = metadata.aliases);
+            for path in std::iter::once(route.path()).chain(aliases.iter().copied()) {
+                let path = crate::path_template(path);
+                let variant = match T::trailing_slash() {
+                    crate::TrailingSlash::Merge => trailing_slash_variant(&path),
+                    _ => None,
+                };
+                for path in std::iter::once(path).chain(variant) {
+                    paths.entry(path).or_default().push(method.clone());
+                }
+            }
+        }
+
+        for (path, methods) in paths {
+            if methods.contains(&axum::http::Method::OPTIONS) {
+                continue;
+            }
+            let allow = methods
+                .iter()
+                .map(axum::http::Method::as_str)
+                .collect::<Vec<_>>()
+                .join(", ");
+            let Ok(allow) = axum::http::HeaderValue::from_str(&allow) else {
+                continue;
+            };
+            router = router.route(
+                &path,
+                axum::routing::options(move || async move {
+                    (
+                        axum::http::StatusCode::NO_CONTENT,
+                        [(axum::http::header::ALLOW, allow)],
+                    )
+                }),
+            );
+        }
+        router
+    }
+
+    /// Runs the handler of a `route!` in its span, recording the path
+    /// parameters, the matched path and the response status
+    #[cfg(feature = "tracing")]
+    pub async fn instrumented<H, T, S>(
+        span: tracing::Span,
+        handler: H,
+        mut request: axum::extract::Request,
+        state: S,
+    ) -> axum::response::Response
+    where
+        H: axum::handler::Handler<T, S>,
+        S: Send + 'static,
+    {
+        use axum::RequestExt;
+        use tracing::Instrument;
+
+        if let Some(path) = request.extensions().get::<axum::extract::MatchedPath>() {
+            span.record("http.route", path.as_str());
+        }
+        if let Ok(params) = request
+            .extract_parts::<axum::extract::RawPathParams>()
+            .await
+        {
+            for (name, value) in &params {
+                span.record(name, value);
+            }
+        }
+
+        let response = handler.call(request, state).instrument(span.clone()).await;
+        span.record("http.status_code", response.status().as_u16());
+        response
+    }
+
+    /// Records the `http_requests_total` counter and the
+    /// `http_request_duration_seconds` histogram of a `route!`, when its group
+    /// records metrics
+    #[cfg(feature = "metrics")]
+    pub async fn record_metrics<T: crate::Route>(
+        route: &'static str,
+        method: crate::Method,
+        request: axum::extract::Request,
+        next: axum::middleware::Next,
+    ) -> axum::response::Response {
+        if !T::metrics() {
+            return next.run(request).await;
+        }
+
+        let start = std::time::Instant::now();
+        let response = next.run(request).await;
+        let status = response.status().as_u16().to_string();
+        metrics::counter!(
+            "http_requests_total",
+            "route" => route,
+            "method" => method.as_str(),
+            "status" => status
+        )
+        .increment(1);
+        metrics::histogram!(
+            "http_request_duration_seconds",
+            "route" => route,
+            "method" => method.as_str()
+        )
+        .record(start.elapsed().as_secs_f64());
+        response
+    }
+
+    /// Used by `mount_group!` to reject mount paths capturing path
+    /// parameters at compile time
+    pub const fn has_capture(path: &str) -> bool {
+        let path = path.as_bytes();
+        let mut i = 0;
+        while i < path.len() {
+            if path[i] == b'{' {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    /// Used by `route_group!` to reject paths such as `/a//b` at compile time
+    pub const fn has_empty_segment(path: &str) -> bool {
+        let path = path.as_bytes();
+        let mut i = 1;
+        while i < path.len() {
+            if path[i] == b'/' && path[i - 1] == b'/' {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    /// Used by `route!`'s `auto_head = false` to answer `HEAD` requests,
+    /// listing the `GET` of the route in the `Allow` header
+    pub async fn head_not_allowed() -> impl axum::response::IntoResponse {
+        (
+            axum::http::StatusCode::METHOD_NOT_ALLOWED,
+            [(axum::http::header::ALLOW, "GET")],
+        )
+    }
+
+    /// Used by `route!` to reject the `state` key on groups without a state.
+    ///
+    /// The inherent constant only exists for `()`, every other state type
+    /// resolves to the `UnitStateFallback` constant instead.
+    pub struct UnitStateCheck<T>(PhantomData<T>);
+
+    impl UnitStateCheck<()> {
+        pub const IS_UNIT: bool = true;
+    }
+
+    pub trait UnitStateFallback {
+        const IS_UNIT: bool = false;
+    }
+
+    impl<T> UnitStateFallback for UnitStateCheck<T> {}
+
+    /// Used by `route!`'s `status` key to replace the default `200 OK` status.
+    ///
+    /// Routes whose return type mentions `StatusCode` set their status
+    /// themselves and aren't given this layer. Any other status comes from an
+    /// error and is kept.
+    pub async fn default_status<const STATUS: u16>(
+        mut response: axum::response::Response,
+    ) -> axum::response::Response {
+        if response.status() == axum::http::StatusCode::OK
+            && let Ok(status) = axum::http::StatusCode::from_u16(STATUS)
+        {
+            *response.status_mut() = status;
+        }
+        response
+    }
+
+    /// Sets the `Cache-Control` header of a successful response that doesn't
+    /// have one, used by `route!`'s `cache_control` key and the groups
+    /// declaring a `cache_control`
+    pub fn set_cache_control(response: &mut axum::response::Response, value: &'static str) {
+        if response.status().is_success() {
+            response
+                .headers_mut()
+                .entry(axum::http::header::CACHE_CONTROL)
+                .or_insert_with(|| axum::http::HeaderValue::from_static(value));
+        }
+    }
+
+    /// Used by `route!`'s `response_headers` key, names and values are
+    /// validated by the macro.
+    pub fn set_response_header(
+        response: &mut axum::response::Response,
+        name: &'static str,
+        value: &'static str,
+        append: bool,
+    ) {
+        let Ok(name) = axum::http::HeaderName::from_bytes(name.as_bytes()) else {
+            return;
+        };
+        let value = axum::http::HeaderValue::from_static(value);
+
+        if append {
+            response.headers_mut().append(name, value);
+        } else {
+            response.headers_mut().insert(name, value);
+        }
+    }
+}
+
+#[doc(hidden)]
+pub fn routes<T: Route + 'static>() -> impl Iterator<Item = RouteInfo> {
+    T::registered().map(Route::info)
+}
+
+/// Returns the absolute path, the method and the name of every leaf route of
+/// the provided group, walking nested groups recursively
+pub fn full_paths<T: Route + 'static>() -> Vec<(String, Option<axum::http::Method>, &'static str)> {
+    let mut paths = Vec::new();
+    __private::walk::<T>("", &mut |path, metadata| {
+        let method = metadata.method.map(Into::into);
+        paths.push((path.to_owned(), method, metadata.name.unwrap_or_default()));
+    });
+    paths
+}
+
+/// Returns the groups nested directly in the provided group, sorted by path
+///
+/// The tree of groups can be rebuilt by calling it again with the type of
+/// each child, whose routes are listed by `routes!`:
+///
+/// ```
+/// # use axum_distributed_routing::{children_of, route_group};
+/// # route_group!(Routes, ());
+/// # route_group!(Api, (), Routes, "/api");
+/// # fn main() {
+/// let children = children_of::<Routes>();
+/// assert_eq!(children[0].name, "Api");
+/// assert_eq!(children[0].path, "/api");
+/// # }
+/// ```
+pub fn children_of<T: Route + 'static>() -> Vec<ChildGroupInfo> {
+    let prefix = T::prefix();
+    let mut children = T::registered()
+        .map(Route::info)
+        .filter(|info| info.kind == RouteKind::Group)
+        .map(|info| ChildGroupInfo {
+            name: info.name,
+            path: info.path,
+            prefix: join_paths(&prefix, info.path),
+        })
+        .collect::<Vec<_>>();
+    children.sort_by_key(|child| child.path);
+    children
+}
+
+/// Returns the number of leaf routes of the provided group, walking nested
+/// groups recursively
+///
+/// ```
+/// # use axum_distributed_routing::{route, route_count, route_group};
+/// # route_group!(Routes, ());
+/// # route_group!(Api, (), Routes, "/api");
+/// # route!(group = Routes, path = "/", method = GET, async index {});
+/// # route!(group = Api, path = "/health", method = GET, async health {});
+/// # fn main() {
+/// assert_eq!(route_count::<Routes>(), 2, "a group is missing from the binary");
+/// # }
+/// ```
+pub fn route_count<T: Route + 'static>() -> usize {
+    let mut count = 0;
+    __private::walk::<T>("", &mut |_, _| count += 1);
+    count
+}
+
+/// The shape of a group, see `group_summary`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GroupSummary {
+    /// The number of leaf routes, see `route_count`
+    pub leaf_count: usize,
+    /// The number of groups nested under the group, at any depth
+    pub nested_group_count: usize,
+    /// The distinct absolute paths of the leaf routes, sorted
+    pub paths: Vec<String>,
+}
+
+/// Describes the leaf routes and the nested groups of the provided group,
+/// walking nested groups recursively
+pub fn group_summary<T: Route + 'static>() -> GroupSummary {
+    let mut leaf_count = 0;
+    let mut paths = Vec::new();
+    __private::walk::<T>("", &mut |path, _| {
+        leaf_count += 1;
+        paths.push(path.to_owned());
+    });
+    paths.sort();
+    paths.dedup();
+
+    let mut nested_group_count = 0;
+    __private::walk_groups::<T>("", &mut |_| nested_group_count += 1);
+
+    GroupSummary {
+        leaf_count,
+        nested_group_count,
+        paths,
+    }
+}
+
+/// Renders the routes of the provided group as an indented tree, sorted by
+/// path then method
+///
+/// ```text
+/// Routes
+///     GET /expr/{val:i32}
+///     /api
+///         GET /health
+/// ```
+///
+/// Nested groups are displayed with their absolute path, and their routes are
+/// indented by 4 more spaces, following the `level` of `Route::attach`.
+pub fn route_tree_string<T: Route + 'static>() -> String {
+    let name = std::any::type_name::<T>();
+    let name = name.rsplit("::").next().unwrap_or(name);
+    let mut out = match T::host() {
+        Some(host) => format!("{name} ({host})\n"),
+        None => format!("{name}\n"),
+    };
+    __private::write_tree::<T>(&mut out, "", 4);
+    out
+}
+
+/// Prints the routes of the provided group, see `route_tree_string`
+pub fn print_routes<T: Route + 'static>() {
+    print!("{}", route_tree_string::<T>());
+}
+
+/// Returns the router of the provided group, built like `create_router!` on
+/// the first call and cloned on the next ones, e.g. for tests building the
+/// application each
+///
+/// The router is cached before its state is provided, so it is given with
+/// `Router::with_state` after each call. The expressions of the group and of
+/// its nested groups, such as `cors` policies or the states of
+/// `mount_group!`, are only evaluated by the first call. So are the
+/// `enabled_if` functions of the routes (see `route!`), whose result is then
+/// kept by the next calls.
+pub fn cached_router<T: Route + 'static>() -> Router<T::State> {
+    type Routers = Mutex<HashMap<TypeId, Box<dyn Any + Send + Sync>>>;
+    static ROUTERS: OnceLock<Routers> = OnceLock::new();
+
+    let routers = ROUTERS.get_or_init(Routers::default);
+    let cached = |routers: &Routers| {
+        let routers = routers.lock().unwrap_or_else(PoisonError::into_inner);
+        let router = routers.get(&TypeId::of::<T>())?;
+        router.downcast_ref::<Router<T::State>>().cloned()
+    };
+    if let Some(router) = cached(routers) {
+        return router;
+    }
+
+    // Built without holding the lock, the first router stored wins if the
+    // group is built concurrently
+    let router: Router<T::State> = create_router!(T);
+    routers
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entry(TypeId::of::<T>())
+        .or_insert_with(|| Box::new(router));
+    cached(routers).expect("the router of the group was just cached")
+}
+
+/// Serves a router like `axum::serve`, providing the address of each client
+/// to the `ConnectInfo<SocketAddr>` extractor of `route!`'s `connect_info` key
+///
+/// ```no_run
+/// # use axum_distributed_routing::{create_router, route_group, serve_with_connect_info};
+/// # route_group!(Routes, ());
+/// # #[tokio::main]
+/// # async fn main() -> std::io::Result<()> {
+/// let listener = tokio::net::TcpListener::bind("127.0.0.1:3000").await?;
+/// serve_with_connect_info(listener, create_router!(Routes)).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Custom `Connected` types are served with
+/// `axum::serve(listener, router.into_make_service_with_connect_info::<C>())`.
+pub async fn serve_with_connect_info<L>(listener: L, router: Router) -> std::io::Result<()>
 where
-    inventory::iter<T>: IntoIterator<Item = &'static T>,
+    L: Listener,
+    L::Addr: std::fmt::Debug,
+    SocketAddr: for<'a> Connected<IncomingStream<'a, L>>,
 {
+    axum::serve(
+        listener,
+        router.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await
+}
+
+#[doc(hidden)]
+pub fn create_router<T: Route + 'static>(level: usize) -> Router<T::State> {
+    // The layers of the group only apply to its routes, so nested groups are
+    // attached after them and apply their own
+    let layers = __private::GroupLayers::of::<T>();
     let mut router = Router::new();
-    for route in inventory::iter::<T> {
+    let mut routes = MethodRoutes::new();
+    let mut groups = Vec::new();
+    for route in __private::sorted_routes::<T>() {
+        if !layers.is_empty() && route.info().kind == RouteKind::Group {
+            groups.push(route);
+        } else if !route.collect_routes(&mut routes) {
+            // Leaf routes are collected and inserted together, before the
+            // next route that isn't one to keep the attach order
+            router = std::mem::take(&mut routes).attach(router);
+            router = route.attach(router, level);
+        }
+    }
+    router = routes.attach(router);
+    if T::auto_options() {
+        router = __private::with_auto_options::<T>(router);
+    }
+    router = layers.apply(router);
+    for route in groups {
         router = route.attach(router, level);
     }
     router
 }
-
-// TODO: tests
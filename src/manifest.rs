@@ -0,0 +1,85 @@
+use serde::Serialize;
+
+use crate::{GroupTag, Method, Route, RouteMetadata};
+
+/// Lists every route of the provided group as a `RouteManifest`
+///
+/// ```
+/// # use axum_distributed_routing::{route_group, routes_manifest};
+/// # route_group!(Routes, ());
+/// # fn main() {
+/// let manifest = routes_manifest!(Routes);
+/// println!("{}", serde_json::to_string_pretty(&manifest).unwrap());
+/// # }
+/// ```
+#[macro_export]
+macro_rules! routes_manifest {
+    ($type:ty) => {
+        $crate::routes_manifest::<$type>()
+    };
+}
+
+/// A machine-readable list of the leaf routes of a group, e.g. for an API
+/// gateway or contract tests
+///
+/// Routes are sorted by path then method, and tags by name, so that the
+/// serialized manifest doesn't depend on link order.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct RouteManifest {
+    /// The tags of the groups, see `group_tags`
+    pub tags: Vec<GroupTag>,
+    pub routes: Vec<ManifestRoute>,
+}
+
+/// A route of a `RouteManifest`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ManifestRoute {
+    /// The HTTP method, `None` for routes matching any method (e.g.
+    /// `route_service!`)
+    pub method: Option<Method>,
+    /// The absolute path with axum's capture syntax, e.g. `/api/users/{id}`
+    pub path: String,
+    /// The name of the handler
+    pub name: &'static str,
+    /// The name of the group the route is registered in
+    pub group: &'static str,
+    /// The path parameters, as `(name, type)`
+    pub params: &'static [(&'static str, &'static str)],
+    pub tags: &'static [&'static str],
+    pub summary: Option<&'static str>,
+    pub description: Option<&'static str>,
+    pub deprecated: bool,
+}
+
+impl ManifestRoute {
+    fn new(path: &str, metadata: &RouteMetadata) -> Self {
+        Self {
+            method: metadata.method,
+            path: crate::path_template(path),
+            name: metadata.name.unwrap_or_default(),
+            group: metadata.group.unwrap_or_default(),
+            params: metadata.params,
+            tags: metadata.tags,
+            summary: metadata.summary,
+            description: metadata.description,
+            deprecated: metadata.deprecation.is_some(),
+        }
+    }
+}
+
+/// Lists every route of the provided group as a `RouteManifest`, walking
+/// nested groups recursively
+pub fn routes_manifest<T: Route + 'static>() -> RouteManifest {
+    let mut routes = Vec::new();
+    crate::__private::walk::<T>("", &mut |path, metadata| {
+        routes.push(ManifestRoute::new(path, metadata));
+    });
+    routes.sort_by(|a, b| {
+        let key = |route: &ManifestRoute| (route.method.map(Method::as_str), route.name);
+        a.path.cmp(&b.path).then_with(|| key(a).cmp(&key(b)))
+    });
+    RouteManifest {
+        tags: crate::group_tags::<T>(),
+        routes,
+    }
+}
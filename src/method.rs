@@ -0,0 +1,143 @@
+use std::fmt;
+use std::str::FromStr;
+
+use axum::routing::MethodFilter;
+
+/// The HTTP method of a route, as declared with `route!`'s `method` key
+///
+/// Routes record it in their `RouteMetadata`. It converts to `http::Method`
+/// and to axum's `MethodFilter`, and is written and parsed in uppercase, e.g.
+/// `GET`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Method {
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+    Head,
+    Options,
+    Trace,
+    Connect,
+}
+
+impl Method {
+    /// Every method, in declaration order
+    pub const ALL: [Method; 9] = [
+        Method::Get,
+        Method::Post,
+        Method::Put,
+        Method::Patch,
+        Method::Delete,
+        Method::Head,
+        Method::Options,
+        Method::Trace,
+        Method::Connect,
+    ];
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Method::Get => "GET",
+            Method::Post => "POST",
+            Method::Put => "PUT",
+            Method::Patch => "PATCH",
+            Method::Delete => "DELETE",
+            Method::Head => "HEAD",
+            Method::Options => "OPTIONS",
+            Method::Trace => "TRACE",
+            Method::Connect => "CONNECT",
+        }
+    }
+}
+
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+/// The error of parsing a `Method` from a string that isn't one of the
+/// methods a route can be declared with
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseMethodError(String);
+
+impl fmt::Display for ParseMethodError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unsupported HTTP method `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParseMethodError {}
+
+impl FromStr for Method {
+    type Err = ParseMethodError;
+
+    /// Parses a method in uppercase, methods being case-sensitive
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Method::ALL
+            .into_iter()
+            .find(|method| method.as_str() == s)
+            .ok_or_else(|| ParseMethodError(s.to_owned()))
+    }
+}
+
+impl From<Method> for axum::http::Method {
+    fn from(method: Method) -> Self {
+        match method {
+            Method::Get => axum::http::Method::GET,
+            Method::Post => axum::http::Method::POST,
+            Method::Put => axum::http::Method::PUT,
+            Method::Patch => axum::http::Method::PATCH,
+            Method::Delete => axum::http::Method::DELETE,
+            Method::Head => axum::http::Method::HEAD,
+            Method::Options => axum::http::Method::OPTIONS,
+            Method::Trace => axum::http::Method::TRACE,
+            Method::Connect => axum::http::Method::CONNECT,
+        }
+    }
+}
+
+impl TryFrom<&axum::http::Method> for Method {
+    type Error = ParseMethodError;
+
+    /// Fails for extension methods, which routes can't be declared with
+    fn try_from(method: &axum::http::Method) -> Result<Self, Self::Error> {
+        method.as_str().parse()
+    }
+}
+
+impl TryFrom<axum::http::Method> for Method {
+    type Error = ParseMethodError;
+
+    fn try_from(method: axum::http::Method) -> Result<Self, Self::Error> {
+        Method::try_from(&method)
+    }
+}
+
+impl From<Method> for MethodFilter {
+    fn from(method: Method) -> Self {
+        match method {
+            Method::Get => MethodFilter::GET,
+            Method::Post => MethodFilter::POST,
+            Method::Put => MethodFilter::PUT,
+            Method::Patch => MethodFilter::PATCH,
+            Method::Delete => MethodFilter::DELETE,
+            Method::Head => MethodFilter::HEAD,
+            Method::Options => MethodFilter::OPTIONS,
+            Method::Trace => MethodFilter::TRACE,
+            Method::Connect => MethodFilter::CONNECT,
+        }
+    }
+}
+
+impl PartialEq<axum::http::Method> for Method {
+    fn eq(&self, other: &axum::http::Method) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl serde::Serialize for Method {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
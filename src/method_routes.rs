@@ -0,0 +1,100 @@
+use std::collections::{HashMap, HashSet};
+
+use axum::Router;
+use axum::routing::MethodRouter;
+
+/// The method routers of the leaf routes of a group, merged by path before
+/// being inserted into a router
+///
+/// `create_router!` collects the routes declared with `route!` (see
+/// `Route::collect_routes`) instead of attaching them one at a time, so that
+/// each path is inserted once.
+pub struct MethodRoutes<S = ()> {
+    routes: Vec<(String, MethodRouter<S>)>,
+    paths: HashMap<String, usize>,
+    fallbacks: HashSet<String>,
+}
+
+impl<S> MethodRoutes<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    pub fn new() -> Self {
+        Self {
+            routes: Vec::new(),
+            paths: HashMap::new(),
+            fallbacks: HashSet::new(),
+        }
+    }
+
+    /// Adds a method router at `path`, merged with the one already added at
+    /// this path if any
+    ///
+    /// # Panics
+    /// Panics if both method routers handle a same method, like
+    /// `Router::route`.
+    pub fn route(&mut self, path: &str, method_router: MethodRouter<S>) {
+        match self.paths.get(path) {
+            Some(&i) => {
+                let existing = &mut self.routes[i].1;
+                *existing = std::mem::replace(existing, MethodRouter::new()).merge(method_router);
+            }
+            None => {
+                self.paths.insert(path.to_owned(), self.routes.len());
+                self.routes.push((path.to_owned(), method_router));
+            }
+        }
+    }
+
+    /// Adds a method router handling any method at `path`, like one made with
+    /// `axum::routing::any`, unless one was already added at this path
+    ///
+    /// The methods handled by the other method routers of the path take
+    /// precedence over it.
+    pub fn fallback(&mut self, path: &str, method_router: MethodRouter<S>) {
+        if self.fallbacks.insert(path.to_owned()) {
+            self.route(path, method_router);
+        }
+    }
+
+    /// The number of paths added
+    pub fn len(&self) -> usize {
+        self.routes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.routes.is_empty()
+    }
+
+    /// Inserts the method routers into `router`, in the order their paths
+    /// were added
+    ///
+    /// # Panics
+    /// Panics if a path conflicts with a route of the router.
+    pub fn attach(self, mut router: Router<S>) -> Router<S> {
+        for (path, method_router) in self.routes {
+            router = router.route(&path, method_router);
+        }
+        router
+    }
+}
+
+impl<S> Default for MethodRoutes<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> std::fmt::Debug for MethodRoutes<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MethodRoutes")
+            .field(
+                "paths",
+                &self.routes.iter().map(|(path, _)| path).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
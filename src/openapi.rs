@@ -0,0 +1,186 @@
+use serde_json::{Map, Value, json};
+
+use crate::{Method, Route, RouteMetadata};
+
+/// Generates a minimal OpenAPI 3.1 document from the provided group
+///
+/// The title and version of the document default to the ones of the calling
+/// crate, they can be overridden with `openapi_spec!(Routes, "title", "1.0.0")`.
+#[macro_export]
+macro_rules! openapi_spec {
+    ($type:ty) => {
+        $crate::openapi_spec::<$type>(env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
+    };
+    ($type:ty, $title:expr, $version:expr) => {
+        $crate::openapi_spec::<$type>($title, $version)
+    };
+}
+
+/// Generates a minimal OpenAPI 3.1 document from the provided group
+///
+/// Nested groups are walked recursively, and the tags of the groups are listed
+/// with their description. Path parameters are described with
+/// their primitive type, while query, body and response schemas only carry
+/// the name of their Rust type. Routes without a method (e.g. registered by
+/// hand) and `CONNECT` routes are not listed.
+pub fn openapi_spec<T: Route + 'static>(title: &str, version: &str) -> Value {
+    let mut paths = Map::new();
+    crate::__private::walk::<T>("", &mut |path, metadata| {
+        let Some(method) = metadata.method.filter(|method| *method != Method::Connect) else {
+            return;
+        };
+
+        let item = paths
+            .entry(crate::path_template(path))
+            .or_insert_with(|| Value::Object(Map::new()));
+        if let Value::Object(item) = item {
+            item.insert(method.as_str().to_ascii_lowercase(), operation(metadata));
+        }
+    });
+
+    let mut spec = json!({
+        "openapi": "3.1.0",
+        "info": {
+            "title": title,
+            "version": version,
+        },
+        "paths": paths,
+    });
+    let tags = crate::group_tags::<T>();
+    if !tags.is_empty() {
+        spec["tags"] = tags
+            .iter()
+            .map(|tag| match tag.description {
+                Some(description) => json!({ "name": tag.name, "description": description }),
+                None => json!({ "name": tag.name }),
+            })
+            .collect();
+    }
+    spec
+}
+
+fn operation(metadata: &RouteMetadata) -> Value {
+    let mut operation = Map::new();
+
+    if let Some(name) = metadata.name {
+        operation.insert("operationId".into(), name.into());
+    }
+    if let Some(summary) = metadata.summary {
+        operation.insert("summary".into(), summary.into());
+    }
+    if let Some(description) = metadata.description {
+        operation.insert("description".into(), description.into());
+    }
+    if !metadata.tags.is_empty() {
+        operation.insert("tags".into(), metadata.tags.into());
+    }
+    if metadata.deprecation.is_some() {
+        operation.insert("deprecated".into(), true.into());
+    }
+
+    let mut parameters = metadata
+        .params
+        .iter()
+        .map(|(name, ty)| {
+            json!({
+                "name": name,
+                "in": "path",
+                "required": true,
+                "schema": primitive_schema(ty),
+            })
+        })
+        .collect::<Vec<_>>();
+    if let Some(query) = metadata.query.filter(|query| is_pagination(query)) {
+        parameters.extend(pagination_parameters(query));
+    } else if let Some(query) = metadata.query {
+        // Query types are structs, described as a single exploded object
+        parameters.push(json!({
+            "name": query,
+            "in": "query",
+            "style": "form",
+            "explode": true,
+            "schema": { "type": "object", "title": query },
+        }));
+    }
+    if !parameters.is_empty() {
+        operation.insert("parameters".into(), parameters.into());
+    }
+
+    if let Some((content_type, ty)) = metadata.body {
+        operation.insert(
+            "requestBody".into(),
+            json!({
+                "required": !metadata.optional_body,
+                "content": { content_type: { "schema": type_schema(content_type, ty) } },
+            }),
+        );
+    }
+
+    let mut response = json!({ "description": "" });
+    if let Some((content_type, ty)) = metadata.response {
+        response["content"] = json!({ content_type: { "schema": type_schema(content_type, ty) } });
+    }
+    let status = metadata.status.unwrap_or(200).to_string();
+    operation.insert("responses".into(), json!({ status: response }));
+
+    Value::Object(operation)
+}
+
+/// Whether a query type is `Pagination`, e.g. `Pagination<50>`
+fn is_pagination(ty: &str) -> bool {
+    let name = ty.split('<').next().unwrap_or(ty);
+    name.rsplit("::").next() == Some("Pagination")
+}
+
+/// The `page` and `per_page` parameters of `Pagination`
+fn pagination_parameters(ty: &str) -> [Value; 2] {
+    let max = ty
+        .split_once('<')
+        .and_then(|(_, max)| max.strip_suffix('>')?.parse::<u32>().ok())
+        .unwrap_or(100);
+    [
+        json!({
+            "name": "page",
+            "in": "query",
+            "schema": { "type": "integer", "format": "int32", "minimum": 1, "default": 1 },
+        }),
+        json!({
+            "name": "per_page",
+            "in": "query",
+            "schema": {
+                "type": "integer",
+                "format": "int32",
+                "minimum": 1,
+                "maximum": max,
+                "default": 25,
+            },
+        }),
+    ]
+}
+
+/// The schema of a path parameter, unknown types are described as strings
+fn primitive_schema(ty: &str) -> Value {
+    match ty {
+        "i8" | "i16" | "i32" | "u8" | "u16" | "u32" => {
+            json!({ "type": "integer", "format": "int32" })
+        }
+        "i64" | "i128" | "isize" | "u64" | "u128" | "usize" => {
+            json!({ "type": "integer", "format": "int64" })
+        }
+        "f32" => json!({ "type": "number", "format": "float" }),
+        "f64" => json!({ "type": "number", "format": "double" }),
+        "bool" => json!({ "type": "boolean" }),
+        _ => json!({ "type": "string" }),
+    }
+}
+
+/// The schema of a body, only carrying the name of its type
+fn type_schema(content_type: &str, ty: &str) -> Value {
+    match content_type {
+        "application/octet-stream" | "multipart/form-data" => {
+            json!({ "type": "string", "format": "binary" })
+        }
+        "text/plain" => json!({ "type": "string" }),
+        _ => json!({ "title": ty }),
+    }
+}
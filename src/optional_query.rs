@@ -0,0 +1,52 @@
+use std::ops::Deref;
+
+use axum::extract::{FromRequestParts, Query};
+use axum::http::request::Parts;
+
+/// An extractor deserializing the query string like `Query`, or extracting
+/// `None` when the request has no query string, used by `route!`'s
+/// `query = Option<T>`
+///
+/// The whole query is optional, unlike `#[serde(default)]` fields which fill
+/// in the parameters missing from a query: a request without a query string
+/// extracts `None` and lets the handler pick the defaults, while a malformed
+/// query string is still rejected with a `400 Bad Request`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OptionalQuery<T>(pub Option<T>);
+
+impl<T> Deref for OptionalQuery<T> {
+    type Target = Option<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, S> FromRequestParts<S> for OptionalQuery<T>
+where
+    Query<T>: FromRequestParts<S>,
+    S: Send + Sync,
+{
+    type Rejection = <Query<T> as FromRequestParts<S>>::Rejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        if parts.uri.query().is_none_or(str::is_empty) {
+            return Ok(OptionalQuery(None));
+        }
+        let Query(value) = Query::<T>::from_request_parts(parts, state).await?;
+        Ok(OptionalQuery(Some(value)))
+    }
+}
+
+#[cfg(feature = "aide")]
+impl<T> aide::OperationInput for OptionalQuery<T>
+where
+    Query<T>: aide::OperationInput,
+{
+    fn operation_input(
+        ctx: &mut aide::generate::GenContext,
+        operation: &mut aide::openapi::Operation,
+    ) {
+        Query::<T>::operation_input(ctx, operation);
+    }
+}
@@ -0,0 +1,167 @@
+use axum::http::Uri;
+use axum::http::header::{HeaderName, HeaderValue, LINK};
+use axum::response::{IntoResponseParts, ResponseParts};
+use serde::{Deserialize, Serialize};
+
+/// The page of a list endpoint, deserialized from the `page` and `per_page`
+/// parameters of the query, e.g. with `route!`'s `query = Pagination`
+///
+/// `page` starts at 1 and defaults to 1. `per_page` defaults to 25 and is
+/// clamped between 1 and `MAX`, which defaults to 100:
+///
+/// ```
+/// # use axum::Json;
+/// # use axum::extract::OriginalUri;
+/// # use axum::response::IntoResponse;
+/// # use axum_distributed_routing::{Pagination, route, route_group};
+/// # route_group!(Api, ());
+/// # async fn fetch_widgets(offset: u64, limit: u32) -> (Vec<String>, u64) {
+/// #     (Vec::new(), 0)
+/// # }
+/// route!(
+///     group = Api,
+///     path = "/widgets",
+///     method = GET,
+///     query = Pagination<50>,
+///     parts = [uri: OriginalUri],
+///     async list_widgets -> impl IntoResponse {
+///         let (widgets, total) = fetch_widgets(query.offset(), query.limit()).await;
+///         (query.headers(&uri, total), Json(widgets))
+///     }
+/// );
+/// # fn main() {}
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "PaginationQuery")]
+pub struct Pagination<const MAX: u32 = 100> {
+    pub page: u32,
+    pub per_page: u32,
+}
+
+impl<const MAX: u32> Pagination<MAX> {
+    pub const DEFAULT_PER_PAGE: u32 = 25;
+
+    /// Creates a page, `page` and `per_page` being clamped like when
+    /// deserialized
+    pub fn new(page: u32, per_page: u32) -> Self {
+        Self {
+            page: page.max(1),
+            per_page: per_page.clamp(1, MAX.max(1)),
+        }
+    }
+
+    /// The number of items before the page
+    pub fn offset(&self) -> u64 {
+        u64::from(self.page.saturating_sub(1)) * u64::from(self.per_page)
+    }
+
+    /// The number of items of the page
+    pub fn limit(&self) -> u32 {
+        self.per_page
+    }
+
+    /// The number of pages of `total` items, at least 1
+    pub fn page_count(&self, total: u64) -> u64 {
+        total.div_ceil(u64::from(self.per_page)).max(1)
+    }
+
+    /// The `X-Total-Count` and `Link` headers of the page, `uri` being the URI
+    /// of the request
+    ///
+    /// The links to the `first`, `prev`, `next` and `last` pages keep the
+    /// other parameters of the query. Routes of nested groups see their URI
+    /// without the prefix of the groups, so they pass the `OriginalUri`.
+    pub fn headers(&self, uri: &Uri, total: u64) -> PaginationHeaders {
+        let query = uri
+            .query()
+            .and_then(|query| serde_urlencoded::from_str::<Vec<(String, String)>>(query).ok())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(name, _)| name != "page" && name != "per_page")
+            .collect::<Vec<_>>();
+        let link = |page: u64, rel: &str| {
+            let mut params = query.clone();
+            params.push(("page".into(), page.to_string()));
+            params.push(("per_page".into(), self.per_page.to_string()));
+            let query = serde_urlencoded::to_string(params).unwrap_or_default();
+            format!("<{}?{query}>; rel=\"{rel}\"", uri.path())
+        };
+
+        let page = u64::from(self.page);
+        let last = self.page_count(total);
+        let mut links = vec![link(1, "first")];
+        if page > 1 {
+            links.push(link((page - 1).min(last), "prev"));
+        }
+        if page < last {
+            links.push(link(page + 1, "next"));
+        }
+        links.push(link(last, "last"));
+
+        PaginationHeaders {
+            total,
+            link: links.join(", "),
+        }
+    }
+}
+
+impl<const MAX: u32> Default for Pagination<MAX> {
+    fn default() -> Self {
+        Self::new(1, Self::DEFAULT_PER_PAGE)
+    }
+}
+
+/// The parameters of the query before they are clamped
+#[derive(Deserialize)]
+#[cfg_attr(feature = "aide", derive(schemars::JsonSchema))]
+struct PaginationQuery {
+    page: Option<u32>,
+    per_page: Option<u32>,
+}
+
+impl<const MAX: u32> From<PaginationQuery> for Pagination<MAX> {
+    fn from(query: PaginationQuery) -> Self {
+        Self::new(
+            query.page.unwrap_or(1),
+            query.per_page.unwrap_or(Self::DEFAULT_PER_PAGE),
+        )
+    }
+}
+
+// Documented as the parameters it is deserialized from
+#[cfg(feature = "aide")]
+impl<const MAX: u32> schemars::JsonSchema for Pagination<MAX> {
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        "Pagination".to_owned()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::schema::Schema {
+        PaginationQuery::json_schema(generator)
+    }
+}
+
+/// The `X-Total-Count` and `Link` headers of a page, see
+/// `Pagination::headers`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaginationHeaders {
+    pub total: u64,
+    /// The value of the `Link` header
+    pub link: String,
+}
+
+impl IntoResponseParts for PaginationHeaders {
+    type Error = std::convert::Infallible;
+
+    fn into_response_parts(self, mut res: ResponseParts) -> Result<ResponseParts, Self::Error> {
+        let headers = res.headers_mut();
+        headers.insert(HeaderName::from_static("x-total-count"), self.total.into());
+        if let Ok(link) = HeaderValue::try_from(self.link) {
+            headers.insert(LINK, link);
+        }
+        Ok(res)
+    }
+}
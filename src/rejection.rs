@@ -0,0 +1,263 @@
+use std::any::Any;
+use std::error::Error;
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use axum::Json;
+#[cfg(feature = "multipart")]
+use axum::extract::multipart::MultipartRejection;
+use axum::extract::path::ErrorKind;
+use axum::extract::rejection::{
+    BytesRejection, FormRejection, JsonRejection, PathRejection, QueryRejection, StringRejection,
+};
+use axum::extract::{FromRequest, FromRequestParts, RawPathParams, Request};
+use axum::http::StatusCode;
+use axum::http::request::Parts;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+
+use crate::{FormOrJsonRejection, Route};
+
+/// Builds the response to a request rejected by an extractor of a route,
+/// declared with `route_group!`'s `rejection_format` argument
+pub type RejectionFormat = fn(Rejection) -> Response;
+
+/// The longest body of a rejection read as its message
+const MESSAGE_LIMIT: usize = 16 * 1024;
+
+/// The extractor failure of a route, reported with the `RejectionFormat` of
+/// its group
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Rejection {
+    /// The status of the rejection of the extractor, e.g. `400 Bad Request`
+    #[serde(skip)]
+    pub status: StatusCode,
+    pub kind: RejectionKind,
+    /// The body of the rejection of the extractor
+    pub message: String,
+    /// The path parameter, or the field of the query or body, that failed
+    /// to deserialize, when known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+}
+
+/// The part of the request an extractor failed to extract
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RejectionKind {
+    Path,
+    Query,
+    Body,
+    /// Another part of the request, e.g. a header
+    Request,
+}
+
+impl Rejection {
+    /// Describes the rejection of an extractor, `params` being the path
+    /// parameters of the request, which name the path parameters failing to
+    /// deserialize
+    pub async fn new<R>(rejection: R, params: Option<&RawPathParams>) -> Self
+    where
+        R: IntoResponse + Send + 'static,
+    {
+        let (kind, field) = describe(&rejection, params);
+        let response = rejection.into_response();
+        let status = response.status();
+        let message = axum::body::to_bytes(response.into_body(), MESSAGE_LIMIT)
+            .await
+            .map(|body| String::from_utf8_lossy(&body).into_owned())
+            .unwrap_or_default();
+        Self {
+            status,
+            kind,
+            message,
+            field,
+        }
+    }
+
+    /// The format of `rejection_format = json`: the status of the rejection,
+    /// with a `{ "error": { "kind", "message", "field" } }` body
+    pub fn into_json_response(self) -> Response {
+        #[derive(Serialize)]
+        struct Body {
+            error: Rejection,
+        }
+
+        (self.status, Json(Body { error: self })).into_response()
+    }
+}
+
+/// The kind and field of the rejections of the extractors of `route!`
+fn describe(
+    rejection: &dyn Any,
+    params: Option<&RawPathParams>,
+) -> (RejectionKind, Option<String>) {
+    if let Some(rejection) = rejection.downcast_ref::<PathRejection>() {
+        return (RejectionKind::Path, path_field(rejection, params));
+    }
+    if let Some(rejection) = rejection.downcast_ref::<QueryRejection>() {
+        let field = deserialize_field::<serde_urlencoded::de::Error>(rejection);
+        return (RejectionKind::Query, field);
+    }
+    let field = match rejection.downcast_ref::<FormOrJsonRejection>() {
+        Some(FormOrJsonRejection::Json(rejection)) => json_field(rejection),
+        Some(FormOrJsonRejection::Form(rejection)) => form_field(rejection),
+        Some(FormOrJsonRejection::UnsupportedMediaType) => None,
+        None => match (
+            rejection.downcast_ref::<JsonRejection>(),
+            rejection.downcast_ref::<FormRejection>(),
+        ) {
+            (Some(rejection), _) => json_field(rejection),
+            (_, Some(rejection)) => form_field(rejection),
+            (None, None)
+                if rejection.is::<BytesRejection>() || rejection.is::<StringRejection>() =>
+            {
+                None
+            }
+            #[cfg(feature = "multipart")]
+            (None, None) if rejection.is::<MultipartRejection>() => None,
+            (None, None) => return (RejectionKind::Request, None),
+        },
+    };
+    (RejectionKind::Body, field)
+}
+
+fn path_field(rejection: &PathRejection, params: Option<&RawPathParams>) -> Option<String> {
+    let PathRejection::FailedToDeserializePathParams(error) = rejection else {
+        return None;
+    };
+    let key = |index: usize| Some(params?.iter().nth(index)?.0.to_owned());
+    match error.kind() {
+        ErrorKind::ParseErrorAtKey { key, .. }
+        | ErrorKind::InvalidUtf8InPathParam { key }
+        | ErrorKind::DeserializeError { key, .. } => Some(key.clone()),
+        // `route!` extracts the path parameters in a tuple, or alone
+        ErrorKind::ParseErrorAtIndex { index, .. } => key(*index),
+        ErrorKind::ParseError { .. } if params.is_some_and(|params| params.iter().count() == 1) => {
+            key(0)
+        }
+        _ => None,
+    }
+}
+
+fn json_field(rejection: &JsonRejection) -> Option<String> {
+    deserialize_field::<serde_json::Error>(rejection)
+}
+
+fn form_field(rejection: &FormRejection) -> Option<String> {
+    deserialize_field::<serde_urlencoded::de::Error>(rejection)
+}
+
+/// The field of a deserialization error in the sources of a rejection
+///
+/// The missing fields are only named by the message of the error.
+fn deserialize_field<E>(rejection: &(dyn Error + 'static)) -> Option<String>
+where
+    E: Error + 'static,
+{
+    let mut source = rejection.source();
+    let error = loop {
+        let error = source?;
+        if let Some(error) = error.downcast_ref::<serde_path_to_error::Error<E>>() {
+            break error;
+        }
+        source = error.source();
+    };
+    // `.` is the root, `?` an unknown path, e.g. after a syntax error
+    let path = Some(error.path().to_string()).filter(|path| path != "." && path != "?");
+    match (path, missing_field(error.inner())) {
+        (Some(path), Some(field)) => Some(format!("{path}.{field}")),
+        (path, field) => path.or(field),
+    }
+}
+
+fn missing_field(error: &dyn Display) -> Option<String> {
+    let message = error.to_string();
+    let field = message.strip_prefix("missing field `")?.split('`').next()?;
+    Some(field.to_owned())
+}
+
+/// An extractor reporting the rejection of `E` with the `RejectionFormat` of
+/// the group `G`, used by `route!` with the `rejection-format` feature
+///
+/// The rejection of `E` is left as is in the groups without a format.
+pub struct GroupRejection<E, G>(pub E, pub PhantomData<fn() -> G>);
+
+impl<E, G> Deref for GroupRejection<E, G>
+where
+    E: Deref,
+{
+    type Target = E::Target;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<E, G> std::fmt::Debug for GroupRejection<E, G>
+where
+    E: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("GroupRejection").field(&self.0).finish()
+    }
+}
+
+impl<E, G, S> FromRequestParts<S> for GroupRejection<E, G>
+where
+    E: FromRequestParts<S>,
+    E::Rejection: Send + 'static,
+    G: Route,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let rejection = match E::from_request_parts(parts, state).await {
+            Ok(value) => return Ok(GroupRejection(value, PhantomData)),
+            Err(rejection) => rejection,
+        };
+        Err(match G::rejection_format() {
+            Some(format) => {
+                let params = RawPathParams::from_request_parts(parts, state).await.ok();
+                format(Rejection::new(rejection, params.as_ref()).await)
+            }
+            None => rejection.into_response(),
+        })
+    }
+}
+
+impl<E, G, S> FromRequest<S> for GroupRejection<E, G>
+where
+    E: FromRequest<S>,
+    E::Rejection: Send + 'static,
+    G: Route,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let rejection = match E::from_request(req, state).await {
+            Ok(value) => return Ok(GroupRejection(value, PhantomData)),
+            Err(rejection) => rejection,
+        };
+        Err(match G::rejection_format() {
+            Some(format) => format(Rejection::new(rejection, None).await),
+            None => rejection.into_response(),
+        })
+    }
+}
+
+#[cfg(feature = "aide")]
+impl<E, G> aide::OperationInput for GroupRejection<E, G>
+where
+    E: aide::OperationInput,
+{
+    fn operation_input(
+        ctx: &mut aide::generate::GenContext,
+        operation: &mut aide::openapi::Operation,
+    ) {
+        E::operation_input(ctx, operation);
+    }
+}
@@ -0,0 +1,63 @@
+use axum::extract::NestedPath;
+use axum::response::Html;
+
+/// Defines a group serving the OpenAPI document of another group along with a
+/// Swagger UI page
+///
+/// The group is nested in `$parent` at `$path`, the document is served at
+/// `$path/openapi.json` and the page at `$path`. The page resolves the
+/// document from the path it is actually mounted at, so the parent group can
+/// itself be nested.
+///
+/// # Example
+/// ```
+/// # use axum_distributed_routing::{docs_group, route_group};
+/// # #[derive(Clone)]
+/// # struct AppState;
+/// route_group!(Routes, AppState);
+/// docs_group!(Docs, AppState, Routes, "/docs");
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! docs_group {
+    ($vis:vis $name:ident, $type:ty, $parent:ident, $path:literal) => {
+        $crate::route_group!($vis $name, $type, $parent, $path);
+
+        $crate::__registry!(submit $name: $name, $name::new("/", |router, _| {
+            router.route("/", $crate::__private::axum::routing::get($crate::__private::swagger_ui))
+        }));
+
+        $crate::__registry!(submit $name: $name, $name::new("/openapi.json", |router, _| {
+            router.route(
+                "/openapi.json",
+                $crate::__private::axum::routing::get(|| async { $crate::__private::axum::Json($crate::openapi_spec!($parent)) }),
+            )
+        }));
+    };
+}
+
+const PAGE: &str = r##"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="utf-8" />
+    <meta name="viewport" content="width=device-width, initial-scale=1" />
+    <title>API documentation</title>
+    <link rel="stylesheet" href="https://unpkg.com/swagger-ui-dist@5/swagger-ui.css" />
+</head>
+<body>
+    <div id="swagger-ui"></div>
+    <script src="https://unpkg.com/swagger-ui-dist@5/swagger-ui-bundle.js" crossorigin></script>
+    <script>
+        window.onload = () => {
+            window.ui = SwaggerUIBundle({ url: "{spec}", dom_id: "#swagger-ui" });
+        };
+    </script>
+</body>
+</html>
+"##;
+
+/// Serves the Swagger UI page of `docs_group!`
+pub async fn swagger_ui(nested: NestedPath) -> Html<String> {
+    let spec = format!("{}/openapi.json", nested.as_str().trim_end_matches('/'));
+    Html(PAGE.replace("{spec}", &spec))
+}
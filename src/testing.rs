@@ -0,0 +1,238 @@
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+
+use axum::Router;
+use axum::body::{Body, Bytes};
+use axum::http::header::{CONTENT_TYPE, HeaderName, HeaderValue};
+use axum::http::{HeaderMap, Method, Request, StatusCode};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use tower::ServiceExt;
+
+use crate::{GroupBuilder, Route};
+
+/// Sends requests to the router of a group without binding a port, for tests
+///
+/// Requests are built like with an HTTP client and sent when awaited, the
+/// router being called with `tower::ServiceExt::oneshot`.
+///
+/// ```
+/// # use axum::Json;
+/// # use axum::http::StatusCode;
+/// # use axum_distributed_routing::{TestClient, route, route_group};
+/// # use serde::{Deserialize, Serialize};
+/// # #[derive(Clone, Default)]
+/// # struct AppState;
+/// # route_group!(Routes, AppState);
+/// # route_group!(Api, AppState, Routes, "/api");
+/// # route!(group = Api, path = "/health", method = GET, async health {});
+/// # #[derive(Deserialize)]
+/// # struct ExprQuery {
+/// #     times: i32,
+/// # }
+/// # #[derive(Serialize, Deserialize)]
+/// # struct ExprBody {
+/// #     plus: i32,
+/// # }
+/// # route!(
+/// #     group = Routes,
+/// #     path = "/expr/{val:i32}",
+/// #     method = GET,
+/// #     query = ExprQuery,
+/// #     body = Json<ExprBody>,
+/// #     utoipa = false,
+/// #     aide = false,
+/// #     async expr -> String {
+/// #         format!("{val} * {} + {} = {}", query.times, body.plus, val * query.times + body.plus)
+/// #     }
+/// # );
+/// # #[tokio::main]
+/// # async fn main() {
+/// let client = TestClient::new::<Routes>(AppState::default());
+/// let response = client.get("/api/health").await;
+/// assert_eq!(response.status(), StatusCode::OK);
+///
+/// let response = client.get("/expr/3?times=2").json(&ExprBody { plus: 1 }).await;
+/// assert_eq!(response.text(), "3 * 2 + 1 = 7");
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct TestClient {
+    router: Router,
+}
+
+impl TestClient {
+    /// Creates a client calling the router of the provided group, see
+    /// `create_router!`, with its state (`()` for stateless groups)
+    pub fn new<T: Route + 'static>(state: T::State) -> Self
+    where
+        T::State: Clone + Send + Sync + 'static,
+    {
+        Self::from_router(GroupBuilder::new().group::<T>().with_state(state))
+    }
+
+    /// Creates a client calling the provided router, e.g. with layers applied
+    pub fn from_router(router: Router) -> Self {
+        Self { router }
+    }
+
+    /// Starts a request to `path`, which may contain a query string
+    pub fn request(&self, method: Method, path: &str) -> TestRequest {
+        TestRequest {
+            router: self.router.clone(),
+            request: Request::builder().method(method).uri(path),
+            body: Body::empty(),
+        }
+    }
+
+    pub fn get(&self, path: &str) -> TestRequest {
+        self.request(Method::GET, path)
+    }
+
+    pub fn post(&self, path: &str) -> TestRequest {
+        self.request(Method::POST, path)
+    }
+
+    pub fn put(&self, path: &str) -> TestRequest {
+        self.request(Method::PUT, path)
+    }
+
+    pub fn patch(&self, path: &str) -> TestRequest {
+        self.request(Method::PATCH, path)
+    }
+
+    pub fn delete(&self, path: &str) -> TestRequest {
+        self.request(Method::DELETE, path)
+    }
+
+    pub fn head(&self, path: &str) -> TestRequest {
+        self.request(Method::HEAD, path)
+    }
+}
+
+/// A request of a `TestClient`, sent when awaited
+///
+/// # Panics
+/// Awaiting the request panics if its path or one of its headers is invalid.
+#[must_use = "requests are only sent when awaited"]
+pub struct TestRequest {
+    router: Router,
+    request: axum::http::request::Builder,
+    body: Body,
+}
+
+impl TestRequest {
+    pub fn header<K, V>(self, name: K, value: V) -> Self
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<axum::http::Error>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<axum::http::Error>,
+    {
+        Self {
+            request: self.request.header(name, value),
+            ..self
+        }
+    }
+
+    /// Sets a JSON body, along with its `Content-Type`
+    ///
+    /// # Panics
+    /// Panics if the value can't be serialized.
+    pub fn json<T: Serialize + ?Sized>(self, value: &T) -> Self {
+        let body = serde_json::to_vec(value).expect("the body must serialize to JSON");
+        self.header(CONTENT_TYPE, "application/json").body(body)
+    }
+
+    /// Sets a form body, along with its `Content-Type`
+    ///
+    /// # Panics
+    /// Panics if the value can't be serialized.
+    pub fn form<T: Serialize + ?Sized>(self, value: &T) -> Self {
+        let body = serde_urlencoded::to_string(value)
+            .expect("the body must serialize to a flat list of fields");
+        self.header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(body)
+    }
+
+    /// Sets the body as is
+    pub fn body(self, body: impl Into<Body>) -> Self {
+        Self {
+            body: body.into(),
+            ..self
+        }
+    }
+}
+
+impl IntoFuture for TestRequest {
+    type Output = TestResponse;
+    type IntoFuture = Pin<Box<dyn Future<Output = TestResponse> + Send>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move {
+            let request = self
+                .request
+                .body(self.body)
+                .expect("the request must have a valid path and headers");
+            let response = self
+                .router
+                .oneshot(request)
+                .await
+                .unwrap_or_else(|never| match never {});
+            let (parts, body) = response.into_parts();
+            let body = axum::body::to_bytes(body, usize::MAX)
+                .await
+                .expect("the response body must be readable");
+            TestResponse {
+                status: parts.status,
+                headers: parts.headers,
+                body,
+            }
+        })
+    }
+}
+
+/// The response to a `TestRequest`, with its body read
+#[derive(Clone, Debug)]
+pub struct TestResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+impl TestResponse {
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// The value of a header, if it is present and valid UTF-8
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name)?.to_str().ok()
+    }
+
+    pub fn bytes(&self) -> &Bytes {
+        &self.body
+    }
+
+    /// The body as text, invalid UTF-8 being replaced
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    /// Deserializes the body as JSON
+    ///
+    /// # Panics
+    /// Panics with the body if it isn't valid JSON for `T`.
+    pub fn json<T: DeserializeOwned>(&self) -> T {
+        serde_json::from_slice(&self.body).unwrap_or_else(|error| {
+            panic!(
+                "the response body is not valid JSON ({error}): {}",
+                self.text()
+            )
+        })
+    }
+}
@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::extract::MatchedPath;
+use axum::http::{Method, Request, Response};
+use tower_http::classify::{ServerErrorsAsFailures, ServerErrorsFailureClass, SharedClassifier};
+use tower_http::trace::{
+    DefaultOnBodyChunk, DefaultOnEos, DefaultOnRequest, MakeSpan, OnFailure, OnResponse, TraceLayer,
+};
+use tracing::Span;
+use tracing::field::Empty;
+
+use crate::Route;
+
+/// The `TraceLayer` returned by `trace_layer`
+pub type RouteTraceLayer = TraceLayer<
+    SharedClassifier<ServerErrorsAsFailures>,
+    MakeRouteSpan,
+    DefaultOnRequest,
+    RecordResponse,
+    DefaultOnBodyChunk,
+    DefaultOnEos,
+    RecordFailure,
+>;
+
+/// Creates a `TraceLayer` whose spans are named after the routes of the
+/// provided group, see `traced_router!`
+pub fn trace_layer<T: Route + 'static>() -> RouteTraceLayer {
+    TraceLayer::new_for_http()
+        .make_span_with(MakeRouteSpan::new::<T>())
+        .on_response(RecordResponse)
+        .on_failure(RecordFailure)
+}
+
+/// Creates the span of a request from its matched path and the name of the
+/// route it matched
+///
+/// The span is named `request`, and carries the `name path` of the route in
+/// its `otel.name` field, e.g. `api_health /api/health`, which OpenTelemetry
+/// exporters use as the span name. Requests that didn't match a route are
+/// recorded with their method only, so that their URI doesn't leak into the
+/// span.
+#[derive(Clone, Debug)]
+pub struct MakeRouteSpan {
+    names: Arc<HashMap<(String, Method), &'static str>>,
+}
+
+impl MakeRouteSpan {
+    /// Collects the names of the routes of the provided group, walking nested
+    /// groups recursively
+    pub fn new<T: Route + 'static>() -> Self {
+        let names = crate::full_paths::<T>()
+            .into_iter()
+            .filter_map(|(path, method, name)| Some(((crate::path_template(&path), method?), name)))
+            .collect();
+        Self {
+            names: Arc::new(names),
+        }
+    }
+
+    /// The name of the route matching a path and a method, `HEAD` requests
+    /// being served by `GET` routes
+    fn name(&self, path: &str, method: &Method) -> Option<&'static str> {
+        let name = |method: &Method| self.names.get(&(path.to_owned(), method.clone())).copied();
+        match name(method) {
+            None if method == Method::HEAD => name(&Method::GET),
+            name => name,
+        }
+    }
+}
+
+impl<B> MakeSpan<B> for MakeRouteSpan {
+    fn make_span(&mut self, request: &Request<B>) -> Span {
+        let method = request.method();
+        let route = request
+            .extensions()
+            .get::<MatchedPath>()
+            .map(MatchedPath::as_str);
+        let name = route.and_then(|route| self.name(route, method));
+
+        let mut otel_name = name.map_or_else(|| method.to_string(), str::to_owned);
+        if let Some(route) = route {
+            let _ = write!(otel_name, " {route}");
+        }
+
+        tracing::info_span!(
+            "request",
+            otel.name = %otel_name,
+            otel.status_code = Empty,
+            http.method = %method,
+            http.route = route,
+            http.status_code = Empty,
+            route.name = name,
+            latency_ms = Empty,
+        )
+    }
+}
+
+/// Records the status and the latency of a response in the span of its
+/// request
+#[derive(Copy, Clone, Debug, Default)]
+pub struct RecordResponse;
+
+impl<B> OnResponse<B> for RecordResponse {
+    fn on_response(self, response: &Response<B>, latency: Duration, span: &Span) {
+        span.record("http.status_code", response.status().as_u16());
+        span.record("latency_ms", latency.as_millis() as u64);
+    }
+}
+
+/// Marks the span of a request as failed, for `5xx` responses and errors of
+/// the inner service
+#[derive(Copy, Clone, Debug, Default)]
+pub struct RecordFailure;
+
+impl OnFailure<ServerErrorsFailureClass> for RecordFailure {
+    fn on_failure(
+        &mut self,
+        failure_classification: ServerErrorsFailureClass,
+        latency: Duration,
+        span: &Span,
+    ) {
+        span.record("otel.status_code", "ERROR");
+        tracing::error!(
+            parent: span,
+            classification = %failure_classification,
+            latency_ms = latency.as_millis() as u64,
+            "request failed"
+        );
+    }
+}
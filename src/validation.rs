@@ -0,0 +1,81 @@
+use std::ops::Deref;
+
+use axum::extract::{FromRequest, FromRequestParts, Request};
+use axum::http::StatusCode;
+use axum::http::request::Parts;
+use axum::response::{IntoResponse, Response};
+use validator::{Validate, ValidationErrors};
+
+/// An extractor validating the value extracted by `T` with
+/// `validator::Validate`, used by `route!`'s `validate` key
+///
+/// `T` must dereference to the validated type, like `Json`, `Form` or
+/// `Query`. Invalid values are rejected with the `ValidationErrorResponse`
+/// implementation of the state.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Valid<T>(pub T);
+
+/// Builds the response of requests rejected by `Valid`
+///
+/// This is implemented on the state of the router, the default rejects the
+/// request with a `422 Unprocessable Entity` and the errors as JSON:
+///
+/// ```
+/// # use axum_distributed_routing::ValidationErrorResponse;
+/// # struct AppState;
+/// impl ValidationErrorResponse for AppState {}
+/// ```
+pub trait ValidationErrorResponse {
+    fn validation_error_response(errors: ValidationErrors) -> Response {
+        (StatusCode::UNPROCESSABLE_ENTITY, axum::Json(errors)).into_response()
+    }
+}
+
+impl ValidationErrorResponse for () {}
+
+impl<T, S> FromRequestParts<S> for Valid<T>
+where
+    T: FromRequestParts<S> + Deref,
+    T::Target: Validate,
+    S: ValidationErrorResponse + Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let value = T::from_request_parts(parts, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+        Validate::validate(&*value).map_err(S::validation_error_response)?;
+        Ok(Valid(value))
+    }
+}
+
+impl<T, S> FromRequest<S> for Valid<T>
+where
+    T: FromRequest<S> + Deref,
+    T::Target: Validate,
+    S: ValidationErrorResponse + Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let value = T::from_request(req, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+        Validate::validate(&*value).map_err(S::validation_error_response)?;
+        Ok(Valid(value))
+    }
+}
+
+#[cfg(feature = "aide")]
+impl<T> aide::OperationInput for Valid<T>
+where
+    T: aide::OperationInput,
+{
+    fn operation_input(
+        ctx: &mut aide::generate::GenContext,
+        operation: &mut aide::openapi::Operation,
+    ) {
+        T::operation_input(ctx, operation);
+    }
+}
@@ -0,0 +1,74 @@
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use axum::extract::{FromRequest, FromRequestParts, Request};
+use axum::http::request::Parts;
+use axum::response::IntoResponse;
+
+/// An extractor replacing the rejection of `E` with `R`, used by `route!`'s
+/// `rejection` key
+///
+/// `R` is built from the rejection of `E` with `From`, so that extraction
+/// failures can be reported in the application's own format.
+pub struct WithRejection<E, R>(pub E, pub PhantomData<fn() -> R>);
+
+impl<E, R> Deref for WithRejection<E, R>
+where
+    E: Deref,
+{
+    type Target = E::Target;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<E, R> std::fmt::Debug for WithRejection<E, R>
+where
+    E: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("WithRejection").field(&self.0).finish()
+    }
+}
+
+impl<E, R, S> FromRequestParts<S> for WithRejection<E, R>
+where
+    E: FromRequestParts<S>,
+    R: From<E::Rejection> + IntoResponse,
+    S: Send + Sync,
+{
+    type Rejection = R;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let value = E::from_request_parts(parts, state).await?;
+        Ok(WithRejection(value, PhantomData))
+    }
+}
+
+impl<E, R, S> FromRequest<S> for WithRejection<E, R>
+where
+    E: FromRequest<S>,
+    R: From<E::Rejection> + IntoResponse,
+    S: Send + Sync,
+{
+    type Rejection = R;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let value = E::from_request(req, state).await?;
+        Ok(WithRejection(value, PhantomData))
+    }
+}
+
+#[cfg(feature = "aide")]
+impl<E, R> aide::OperationInput for WithRejection<E, R>
+where
+    E: aide::OperationInput,
+{
+    fn operation_input(
+        ctx: &mut aide::generate::GenContext,
+        operation: &mut aide::openapi::Operation,
+    ) {
+        E::operation_input(ctx, operation);
+    }
+}
@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use aide::axum::ApiRouter;
+use aide::openapi::OpenApi;
+use axum::{Extension, Json};
+use axum_distributed_routing::{
+    Pagination, TestClient, create_api_router, route, route_group, versioned_group,
+};
+use serde_json::Value;
+
+route_group!(Routes, ());
+route_group!(Api, (), Routes, "/api", tag = "Public API");
+
+route!(
+    group = Api,
+    path = "/health",
+    method = GET,
+    summary = "Health check",
+    description = "Checks that the service is up",
+    tags = ["monitoring"],
+    async health -> String { "ok".to_owned() }
+);
+
+route!(
+    group = Api,
+    path = "/users/{id:u64}",
+    method = GET,
+    async get_user -> String { id.to_string() }
+);
+
+versioned_group!(pub Versions, (), Api, versions = [1, 2]);
+
+route!(
+    group = Versions,
+    versions = [1, 2],
+    path = "/items/{id:u32}",
+    method = GET,
+    async get_item -> String { format!("item {id}") }
+);
+
+async fn serve_docs(Extension(api): Extension<Arc<OpenApi>>) -> Json<OpenApi> {
+    Json((*api).clone())
+}
+
+fn router() -> axum::Router {
+    let mut api = OpenApi::default();
+    let router = ApiRouter::new()
+        .merge(create_api_router!(Routes))
+        .route("/docs", axum::routing::get(serve_docs))
+        .finish_api(&mut api);
+    router.layer(Extension(Arc::new(api)))
+}
+
+#[tokio::test]
+async fn documents_routes_with_their_metadata() {
+    let client = TestClient::from_router(router());
+    let spec = client.get("/docs").await.json::<Value>();
+
+    let health = &spec["paths"]["/api/health"]["get"];
+    assert_eq!(health["summary"], "Health check");
+    assert_eq!(health["description"], "Checks that the service is up");
+    assert_eq!(health["tags"], serde_json::json!(["monitoring"]));
+
+    // Routes without tags are tagged with the tag of their group
+    let get_user = &spec["paths"]["/api/users/{id}"]["get"];
+    assert_eq!(get_user["tags"], serde_json::json!(["Public API"]));
+}
+
+route!(
+    group = Api,
+    path = "/internal",
+    method = GET,
+    aide = false,
+    async internal -> &'static str { "internal" }
+);
+
+#[tokio::test]
+async fn serves_the_documented_routes() {
+    let client = TestClient::from_router(router());
+    assert_eq!(client.get("/api/health").await.text(), "ok");
+    assert_eq!(client.get("/api/users/7").await.text(), "7");
+}
+
+#[tokio::test]
+async fn serves_the_routes_opting_out_undocumented() {
+    let client = TestClient::from_router(router());
+    let spec = client.get("/docs").await.json::<Value>();
+
+    assert!(spec["paths"].get("/api/internal").is_none());
+    assert_eq!(client.get("/api/internal").await.text(), "internal");
+}
+
+#[tokio::test]
+async fn documents_the_routes_of_each_version() {
+    let client = TestClient::from_router(router());
+    let spec = client.get("/docs").await.json::<Value>();
+
+    for version in ["v1", "v2"] {
+        let get_item = &spec["paths"][format!("/api/{version}/items/{{id}}")]["get"];
+        assert_eq!(get_item["tags"], serde_json::json!(["Public API"]));
+        let response = client.get(&format!("/api/{version}/items/3")).await;
+        assert_eq!(response.text(), "item 3");
+    }
+}
+
+route!(
+    group = Api,
+    path = "/items",
+    method = GET,
+    query = Pagination<50>,
+    async list_items -> String { query.page.to_string() }
+);
+
+#[tokio::test]
+async fn documents_pagination_as_its_parameters() {
+    let client = TestClient::from_router(router());
+    let spec = client.get("/docs").await.json::<Value>();
+
+    let parameters = spec["paths"]["/api/items"]["get"]["parameters"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|parameter| (parameter["name"].clone(), parameter["in"].clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        parameters,
+        [
+            (serde_json::json!("page"), serde_json::json!("query")),
+            (serde_json::json!("per_page"), serde_json::json!("query"))
+        ]
+    );
+}
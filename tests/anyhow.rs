@@ -0,0 +1,60 @@
+use anyhow::{Context, anyhow};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum_distributed_routing::{AnyhowError, TestClient, route, route_group};
+
+route_group!(Routes, ());
+
+route!(
+    group = Routes,
+    path = "/parse/{value:String}",
+    method = GET,
+    error = anyhow,
+    async parse -> String {
+        let value: u32 = value.parse().context("parsing the value")?;
+        Ok((value * 2).to_string())
+    }
+);
+
+route!(
+    group = Routes,
+    path = "/fail",
+    method = GET,
+    error = anyhow,
+    async fail -> &'static str {
+        Err(anyhow!("nothing to see"))?
+    }
+);
+
+#[tokio::test]
+async fn answers_successes_as_is() {
+    let response = TestClient::new::<Routes>(()).get("/parse/21").await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "42");
+}
+
+#[tokio::test]
+async fn answers_errors_with_their_chain_in_debug_builds() {
+    let client = TestClient::new::<Routes>(());
+
+    let response = client.get("/parse/abc").await;
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(
+        response.text(),
+        "parsing the value: invalid digit found in string"
+    );
+
+    let response = client.get("/fail").await;
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(response.text(), "nothing to see");
+}
+
+#[test]
+fn converts_any_error() {
+    let error = AnyhowError::from(std::io::Error::other("disk full"));
+    assert_eq!(error.0.to_string(), "disk full");
+
+    let response = error.into_response();
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
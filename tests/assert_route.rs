@@ -0,0 +1,36 @@
+use axum_distributed_routing::{assert_route_absent, assert_route_exists, route, route_group};
+
+route_group!(Routes, ());
+route_group!(Api, (), Routes, "/api");
+
+route!(group = Routes, path = "/", method = GET, async index {});
+route!(group = Api, path = "/health", method = GET, async health {});
+route!(group = Api, path = "/users/{id:u64}", method = GET, async get_user {});
+route!(group = Api, path = "/users/{id:u64}", method = DELETE, async delete_user {});
+
+#[test]
+fn finds_the_routes_of_nested_groups() {
+    assert_route_exists!(Routes, GET "/api/health");
+    assert_route_exists!(Routes, GET "/api/users/{id}");
+    assert_route_exists!(Routes, DELETE "/api/users/{id:u64}");
+    assert_route_exists!(Api, GET "/health");
+}
+
+#[test]
+fn checks_that_routes_are_absent() {
+    assert_route_absent!(Routes, POST "/api/users/{id}");
+    assert_route_absent!(Routes, GET "/health");
+}
+
+#[test]
+#[should_panic(expected = "GET /api/users is not registered in Routes, nearby routes:\n    \
+    DELETE /api/users/{id}\n    GET /api/users/{id}\n    GET /api/health\n    GET /")]
+fn lists_the_nearby_routes_of_a_missing_route() {
+    assert_route_exists!(Routes, GET "/api/users");
+}
+
+#[test]
+#[should_panic(expected = "DELETE /api/users/{id} is registered in Routes")]
+fn panics_when_an_absent_route_is_registered() {
+    assert_route_absent!(Routes, DELETE "/api/users/{id}");
+}
@@ -0,0 +1,54 @@
+use axum::http::{Method, StatusCode};
+use axum_distributed_routing::{TestClient, TestResponse, route, route_group};
+
+route_group!(Routes, (), auto_options = true);
+route_group!(Merged, (), trailing_slash = merge, auto_options = true);
+
+route!(group = Routes, path = "/users", method = GET, aliases = ["/members"], async list_users -> &'static str { "list" });
+route!(group = Routes, path = "/users", method = POST, async create_user -> &'static str { "create" });
+route!(group = Routes, path = "/ping", method = GET, async ping -> &'static str { "pong" });
+route!(group = Routes, path = "/ping", method = OPTIONS, async ping_options -> &'static str { "custom" });
+
+route!(group = Merged, path = "/posts", method = GET, async list_posts -> &'static str { "list" });
+
+fn allowed(response: &TestResponse) -> Vec<&str> {
+    let mut methods: Vec<_> = response.header("allow").unwrap().split(", ").collect();
+    methods.sort();
+    methods
+}
+
+#[tokio::test]
+async fn lists_the_methods_of_a_path() {
+    let client = TestClient::new::<Routes>(());
+
+    let response = client.request(Method::OPTIONS, "/users").await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert_eq!(allowed(&response), ["GET", "POST"]);
+}
+
+#[tokio::test]
+async fn answers_on_aliases() {
+    let client = TestClient::new::<Routes>(());
+
+    let response = client.request(Method::OPTIONS, "/members").await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert_eq!(allowed(&response), ["GET"]);
+}
+
+#[tokio::test]
+async fn answers_on_merged_trailing_slash_variants() {
+    let client = TestClient::new::<Merged>(());
+
+    for path in ["/posts", "/posts/"] {
+        let response = client.request(Method::OPTIONS, path).await;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert_eq!(allowed(&response), ["GET"]);
+    }
+}
+
+#[tokio::test]
+async fn keeps_declared_options_routes() {
+    let client = TestClient::new::<Routes>(());
+
+    assert_eq!(client.request(Method::OPTIONS, "/ping").await.text(), "custom");
+}
@@ -0,0 +1,69 @@
+use axum::body::Bytes;
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, route, route_group};
+
+#[derive(Clone)]
+struct AppState {
+    factor: usize,
+}
+
+route_group!(Routes, AppState);
+
+route!(
+    group = Routes,
+    path = "/thread",
+    method = GET,
+    blocking,
+    async thread -> String { format!("{:?}", std::thread::current().id()) }
+);
+
+route!(
+    group = Routes,
+    path = "/repeat/{times:usize}",
+    method = POST,
+    state,
+    body = Bytes,
+    blocking,
+    async repeat -> Vec<u8> { body.repeat(times * state.factor) }
+);
+
+route!(
+    group = Routes,
+    path = "/boom",
+    method = GET,
+    blocking,
+    async boom -> &'static str { panic!("boom") }
+);
+
+fn client() -> TestClient {
+    TestClient::new::<Routes>(AppState { factor: 2 })
+}
+
+#[tokio::test]
+async fn runs_the_block_on_the_blocking_pool() {
+    let response = client().get("/thread").await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_ne!(
+        response.text(),
+        format!("{:?}", std::thread::current().id())
+    );
+}
+
+#[tokio::test]
+async fn moves_the_extractors_into_the_block() {
+    let response = client().post("/repeat/2").body("ab").await;
+
+    assert_eq!(response.text(), "abababab");
+}
+
+#[tokio::test]
+async fn answers_panics_with_internal_server_error() {
+    let client = client();
+
+    let response = client.get("/boom").await;
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+    // The router keeps serving requests
+    assert_eq!(client.post("/repeat/1").body("a").await.text(), "aa");
+}
@@ -0,0 +1,106 @@
+use axum::http::StatusCode;
+use axum::http::header::CACHE_CONTROL;
+use axum_distributed_routing::{TestClient, route, route_group, static_response};
+
+route_group!(Routes, ());
+route_group!(Api, (), Routes, "/api", cache_control = "no-store");
+route_group!(Users, (), Api, "/users");
+route_group!(
+    Assets,
+    (),
+    Api,
+    "/assets",
+    cache_control = "public, max-age=86400"
+);
+
+route!(group = Routes, path = "/", method = GET, async index {});
+route!(group = Api, path = "/health", method = GET, async health {});
+route!(group = Users, path = "/", method = GET, async list_users {});
+route!(group = Assets, path = "/logo.svg", method = GET, async logo {});
+
+route!(
+    group = Api,
+    path = "/stats",
+    method = GET,
+    cache_control = "public, max-age=300",
+    async stats {}
+);
+
+route!(
+    group = Api,
+    path = "/private",
+    method = GET,
+    async private -> ([(axum::http::HeaderName, &'static str); 1], &'static str) {
+        ([(CACHE_CONTROL, "private")], "private")
+    }
+);
+
+route!(
+    group = Api,
+    path = "/missing",
+    method = GET,
+    async missing -> StatusCode { StatusCode::NOT_FOUND }
+);
+
+static_response!(
+    group = Routes,
+    path = "/robots.txt",
+    content_type = "text/plain",
+    body = "User-agent: *",
+    cache_control = "public, max-age=3600"
+);
+
+async fn cache_control(path: &str) -> Option<String> {
+    let response = TestClient::new::<Routes>(()).get(path).await;
+    response.header("cache-control").map(str::to_owned)
+}
+
+#[tokio::test]
+async fn sets_the_header_of_the_group() {
+    assert_eq!(
+        cache_control("/api/health").await.as_deref(),
+        Some("no-store")
+    );
+    assert_eq!(cache_control("/").await, None);
+}
+
+#[tokio::test]
+async fn nested_groups_follow_their_parent_unless_they_declare_their_own() {
+    assert_eq!(
+        cache_control("/api/users").await.as_deref(),
+        Some("no-store")
+    );
+    assert_eq!(
+        cache_control("/api/assets/logo.svg").await.as_deref(),
+        Some("public, max-age=86400")
+    );
+}
+
+#[tokio::test]
+async fn routes_replace_the_header_of_their_group() {
+    assert_eq!(
+        cache_control("/api/stats").await.as_deref(),
+        Some("public, max-age=300")
+    );
+}
+
+#[tokio::test]
+async fn keeps_the_header_of_the_handler() {
+    assert_eq!(
+        cache_control("/api/private").await.as_deref(),
+        Some("private")
+    );
+}
+
+#[tokio::test]
+async fn only_applies_to_successful_responses() {
+    assert_eq!(cache_control("/api/missing").await, None);
+}
+
+#[tokio::test]
+async fn applies_to_static_responses() {
+    assert_eq!(
+        cache_control("/robots.txt").await.as_deref(),
+        Some("public, max-age=3600")
+    );
+}
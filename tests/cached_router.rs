@@ -0,0 +1,30 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use axum_distributed_routing::{TestClient, cached_router, mount_group, route, route_group};
+
+static BUILDS: AtomicUsize = AtomicUsize::new(0);
+
+/// The state of the mounted group, evaluated each time `Routes` is attached
+fn counted_state() -> String {
+    BUILDS.fetch_add(1, Ordering::SeqCst);
+    "mounted".to_owned()
+}
+
+route_group!(Routes, String);
+route_group!(Mounted, String);
+
+mount_group!(Routes, "/mounted", Mounted, state = counted_state());
+
+route!(group = Routes, path = "/name", method = GET, state = name, async get_name -> String { name });
+route!(group = Mounted, path = "/", method = GET, state, async mounted -> String { state });
+
+#[tokio::test]
+async fn builds_the_router_once() {
+    let first = TestClient::from_router(cached_router!(Routes).with_state("first".to_owned()));
+    let second = TestClient::from_router(cached_router!(Routes).with_state("second".to_owned()));
+
+    assert_eq!(BUILDS.load(Ordering::SeqCst), 1);
+    assert_eq!(first.get("/name").await.text(), "first");
+    assert_eq!(second.get("/name").await.text(), "second");
+    assert_eq!(second.get("/mounted").await.text(), "mounted");
+}
@@ -0,0 +1,73 @@
+use std::any::Any;
+
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum_distributed_routing::{TestClient, route, route_group};
+
+fn panic_response(payload: Box<dyn Any + Send + 'static>) -> Response {
+    let message = payload.downcast_ref::<&str>().copied().unwrap_or("unknown");
+    (
+        StatusCode::SERVICE_UNAVAILABLE,
+        format!("caught: {message}"),
+    )
+        .into_response()
+}
+
+route_group!(Routes, ());
+route_group!(Api, (), Routes, "/api", catch_panics = true);
+route_group!(Inner, (), Api, "/inner");
+route_group!(Debug, (), Api, "/debug", catch_panics = false);
+route_group!(
+    Custom,
+    (),
+    Routes,
+    "/custom",
+    catch_panics = true,
+    panic_response = panic_response
+);
+
+route!(group = Routes, path = "/boom", method = GET, async root_boom -> &'static str { panic!("boom") });
+route!(group = Api, path = "/boom", method = GET, async api_boom -> &'static str { panic!("boom") });
+route!(group = Api, path = "/ok", method = GET, async api_ok -> &'static str { "ok" });
+route!(group = Inner, path = "/boom", method = GET, async inner_boom -> &'static str { panic!("boom") });
+route!(group = Debug, path = "/boom", method = GET, async debug_boom -> &'static str { panic!("boom") });
+route!(group = Custom, path = "/boom", method = GET, async custom_boom -> &'static str { panic!("boom") });
+
+#[tokio::test]
+async fn answers_panics_with_the_default_response() {
+    let client = TestClient::new::<Routes>(());
+
+    let response = client.get("/api/boom").await;
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(response.header("content-type"), Some("application/json"));
+    assert_eq!(response.text(), r#"{"error":"internal server error"}"#);
+
+    assert_eq!(client.get("/api/ok").await.text(), "ok");
+}
+
+#[tokio::test]
+async fn nested_groups_follow_their_parent() {
+    let response = TestClient::new::<Routes>(()).get("/api/inner/boom").await;
+
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[tokio::test]
+async fn answers_panics_with_the_response_of_the_group() {
+    let response = TestClient::new::<Routes>(()).get("/custom/boom").await;
+
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(response.text(), "caught: boom");
+}
+
+#[tokio::test]
+#[should_panic(expected = "boom")]
+async fn leaves_panics_of_other_groups() {
+    TestClient::new::<Routes>(()).get("/boom").await;
+}
+
+#[tokio::test]
+#[should_panic(expected = "boom")]
+async fn nested_groups_can_opt_out() {
+    TestClient::new::<Routes>(()).get("/api/debug/boom").await;
+}
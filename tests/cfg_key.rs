@@ -0,0 +1,43 @@
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, full_paths, route, route_group};
+
+route_group!(Routes, ());
+
+route!(
+    group = Routes,
+    path = "/health",
+    method = GET,
+    cfg = all(),
+    async health -> &'static str { "ok" }
+);
+
+route!(
+    group = Routes,
+    path = "/debug",
+    method = GET,
+    cfg = any(),
+    async debug_info -> &'static str { "debug" }
+);
+
+// Would conflict with the static of the route if it was generated
+#[allow(dead_code)]
+static ROUTE_DEBUG_INFO: () = ();
+
+#[tokio::test]
+async fn compiles_the_route_when_the_predicate_holds() {
+    let response = TestClient::new::<Routes>(()).get("/health").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "ok");
+}
+
+#[tokio::test]
+async fn leaves_the_route_out_otherwise() {
+    let response = TestClient::new::<Routes>(()).get("/debug").await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let paths: Vec<_> = full_paths::<Routes>()
+        .into_iter()
+        .map(|(path, _, _)| path)
+        .collect();
+    assert_eq!(paths, ["/health"]);
+}
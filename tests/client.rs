@@ -0,0 +1,49 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum_distributed_routing::{Client, create_router, route, route_group};
+use serde::{Deserialize, Serialize};
+
+route_group!(Routes, ());
+route_group!(Api, (), Routes, "/api");
+
+#[derive(Serialize, Deserialize)]
+struct Filter {
+    sort: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Post {
+    title: String,
+}
+
+route!(
+    group = Api,
+    path = "/users/{id:u64}/posts",
+    method = POST,
+    query = Filter,
+    body = Json<Post>,
+    status = 201,
+    async create_post -> Json<Post> {
+        Json(Post { title: format!("{} by {id} ({})", body.title, query.sort) })
+    }
+);
+
+#[tokio::test]
+async fn calls_a_route_over_http() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, create_router!(Routes)).await.unwrap();
+    });
+
+    let client = Client::new(format!("http://{address}"));
+    let filter = Filter { sort: "new".to_owned() };
+    let post = Post { title: "Hello".to_owned() };
+    let response = call_create_post(&client, 7, &filter, &post).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_eq!(
+        response.json::<Post>().await.unwrap(),
+        Post { title: "Hello by 7 (new)".to_owned() }
+    );
+}
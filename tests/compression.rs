@@ -0,0 +1,97 @@
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, route, route_group, static_response};
+
+const BODY: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.";
+
+route_group!(Routes, ());
+route_group!(Api, (), Routes, "/api", compression = true);
+route_group!(Reports, (), Api, "/reports");
+route_group!(Downloads, (), Api, "/downloads", compression = false);
+
+route!(group = Routes, path = "/plain", method = GET, async plain -> &'static str { BODY });
+route!(group = Api, path = "/text", method = GET, async text -> &'static str { BODY });
+route!(group = Reports, path = "/latest", method = GET, async latest -> &'static str { BODY });
+route!(group = Downloads, path = "/file", method = GET, async file -> &'static str { BODY });
+route!(group = Routes, path = "/opt-in", method = GET, compression = true, async opt_in -> &'static str { BODY });
+route!(group = Api, path = "/opt-out", method = GET, compression = false, async opt_out -> &'static str { BODY });
+route!(group = Api, path = "/short", method = GET, async short -> &'static str { "ok" });
+
+static_response!(
+    group = Api,
+    path = "/static",
+    content_type = "text/plain",
+    body = BODY
+);
+static_response!(
+    group = Api,
+    path = "/static-raw",
+    content_type = "text/plain",
+    body = BODY,
+    compression = false
+);
+static_response!(
+    group = Routes,
+    path = "/static-gzip",
+    content_type = "text/plain",
+    body = BODY,
+    compression = true
+);
+
+/// The `Content-Encoding` of the response to a `GET` accepting gzip
+async fn encoding(path: &str) -> Option<String> {
+    let response = TestClient::new::<Routes>(())
+        .get(path)
+        .header("accept-encoding", "gzip")
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    response.header("content-encoding").map(str::to_owned)
+}
+
+#[tokio::test]
+async fn compresses_the_routes_of_the_group() {
+    assert_eq!(encoding("/api/text").await.as_deref(), Some("gzip"));
+    assert_eq!(encoding("/plain").await, None);
+}
+
+#[tokio::test]
+async fn nested_groups_follow_their_parent_unless_they_declare_their_own() {
+    assert_eq!(
+        encoding("/api/reports/latest").await.as_deref(),
+        Some("gzip")
+    );
+    assert_eq!(encoding("/api/downloads/file").await, None);
+}
+
+#[tokio::test]
+async fn routes_opt_in_or_out() {
+    assert_eq!(encoding("/opt-in").await.as_deref(), Some("gzip"));
+    assert_eq!(encoding("/api/opt-out").await, None);
+}
+
+#[tokio::test]
+async fn compresses_static_responses() {
+    assert_eq!(encoding("/api/static").await.as_deref(), Some("gzip"));
+    assert_eq!(encoding("/api/static-raw").await, None);
+    assert_eq!(encoding("/static-gzip").await.as_deref(), Some("gzip"));
+}
+
+#[tokio::test]
+async fn skips_small_bodies() {
+    assert_eq!(encoding("/api/short").await, None);
+}
+
+#[tokio::test]
+async fn follows_the_accept_encoding_of_the_request() {
+    let client = TestClient::new::<Routes>(());
+
+    let response = client.get("/api/text").await;
+    assert_eq!(response.header("content-encoding"), None);
+    assert_eq!(response.text(), BODY);
+
+    let response = client
+        .get("/api/text")
+        .header("accept-encoding", "br")
+        .await;
+    assert_eq!(response.header("content-encoding"), Some("br"));
+    assert_ne!(response.bytes().as_ref(), BODY.as_bytes());
+}
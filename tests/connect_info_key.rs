@@ -0,0 +1,45 @@
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+
+use axum::http::StatusCode;
+use axum_distributed_routing::{
+    TestClient, create_router, route, route_group, serve_with_connect_info,
+};
+
+route_group!(Routes, ());
+
+route!(
+    group = Routes,
+    path = "/whoami",
+    method = GET,
+    connect_info = addr,
+    async whoami -> String { addr.ip().to_string() }
+);
+
+/// Sends a bare HTTP/1.0 request, returning the whole response
+fn get(addr: SocketAddr, path: &str) -> String {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    write!(stream, "GET {path} HTTP/1.0\r\nHost: {addr}\r\n\r\n").unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+#[tokio::test]
+async fn extracts_the_address_of_the_client() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(serve_with_connect_info(listener, create_router!(Routes)));
+
+    let response = tokio::task::spawn_blocking(move || get(addr, "/whoami"))
+        .await
+        .unwrap();
+    assert!(response.starts_with("HTTP/1.0 200 OK"), "{response}");
+    assert!(response.ends_with("\r\n\r\n127.0.0.1"), "{response}");
+}
+
+#[tokio::test]
+async fn rejects_requests_served_without_connect_info() {
+    let response = TestClient::new::<Routes>(()).get("/whoami").await;
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
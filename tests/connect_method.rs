@@ -0,0 +1,22 @@
+use axum::http::{Method, StatusCode};
+use axum_distributed_routing::{TestClient, route, route_group};
+
+route_group!(Routes, ());
+
+route!(
+    group = Routes,
+    path = "/tunnel",
+    method = CONNECT,
+    async tunnel -> StatusCode { StatusCode::OK }
+);
+
+#[tokio::test]
+async fn routes_connect_requests() {
+    let client = TestClient::new::<Routes>(());
+
+    let response = client.request(Method::CONNECT, "/tunnel").await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = client.get("/tunnel").await;
+    assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+}
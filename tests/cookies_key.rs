@@ -0,0 +1,112 @@
+use axum::extract::FromRef;
+use axum::http::header::{COOKIE, SET_COOKIE};
+use axum_distributed_routing::cookie::{Cookie, CookieJar, Key, PrivateCookieJar, SignedCookieJar};
+use axum_distributed_routing::{TestClient, route, route_group};
+
+#[derive(Clone)]
+struct AppState {
+    key: Key,
+}
+
+impl FromRef<AppState> for Key {
+    fn from_ref(state: &AppState) -> Self {
+        state.key.clone()
+    }
+}
+
+route_group!(Routes, AppState);
+
+route!(
+    group = Routes,
+    path = "/visits",
+    method = GET,
+    cookies = jar,
+    aide = false,
+    async visits -> (CookieJar, String) {
+        let visits = jar
+            .get("visits")
+            .and_then(|cookie| cookie.value().parse::<u32>().ok())
+            .unwrap_or(0)
+            + 1;
+        (jar.add(Cookie::new("visits", visits.to_string())), visits.to_string())
+    }
+);
+
+route!(
+    group = Routes,
+    path = "/signed",
+    method = GET,
+    cookies = signed jar,
+    aide = false,
+    async signed -> (SignedCookieJar, String) {
+        let previous = jar.get("session").map(|cookie| cookie.value().to_owned());
+        (jar.add(Cookie::new("session", "alice")), previous.unwrap_or_default())
+    }
+);
+
+route!(
+    group = Routes,
+    path = "/private",
+    method = GET,
+    cookies = private jar,
+    aide = false,
+    async private -> (PrivateCookieJar, String) {
+        let previous = jar.get("secret").map(|cookie| cookie.value().to_owned());
+        (jar.add(Cookie::new("secret", "hunter2")), previous.unwrap_or_default())
+    }
+);
+
+fn client() -> TestClient {
+    TestClient::new::<Routes>(AppState {
+        key: Key::generate(),
+    })
+}
+
+/// The `name=value` pair of the `Set-Cookie` header of a response
+fn set_cookie(response: &axum_distributed_routing::TestResponse) -> String {
+    let header = response.header(SET_COOKIE.as_str()).expect("Set-Cookie");
+    header.split(';').next().unwrap().to_owned()
+}
+
+#[tokio::test]
+async fn round_trips_a_plain_cookie() {
+    let client = client();
+    let response = client.get("/visits").await;
+    assert_eq!(response.text(), "1");
+    let cookie = set_cookie(&response);
+    assert_eq!(cookie, "visits=1");
+
+    let response = client.get("/visits").header(COOKIE, cookie).await;
+    assert_eq!(response.text(), "2");
+    assert_eq!(set_cookie(&response), "visits=2");
+}
+
+#[tokio::test]
+async fn round_trips_a_signed_cookie() {
+    let client = client();
+    let response = client.get("/signed").await;
+    assert_eq!(response.text(), "");
+    let cookie = set_cookie(&response);
+    assert!(cookie.starts_with("session=") && cookie.ends_with("alice"));
+
+    let response = client.get("/signed").header(COOKIE, cookie).await;
+    assert_eq!(response.text(), "alice");
+
+    // A tampered cookie is dropped from the jar
+    let response = client
+        .get("/signed")
+        .header(COOKIE, "session=forged-alice")
+        .await;
+    assert_eq!(response.text(), "");
+}
+
+#[tokio::test]
+async fn round_trips_a_private_cookie() {
+    let client = client();
+    let response = client.get("/private").await;
+    let cookie = set_cookie(&response);
+    assert!(!cookie.contains("hunter2"));
+
+    let response = client.get("/private").header(COOKIE, cookie).await;
+    assert_eq!(response.text(), "hunter2");
+}
@@ -0,0 +1,82 @@
+use axum::http::header::{ACCESS_CONTROL_REQUEST_METHOD, ORIGIN};
+use axum::http::{HeaderValue, Method, StatusCode};
+use axum_distributed_routing::cors::CorsLayer;
+use axum_distributed_routing::{TestClient, route, route_group};
+
+const APP: &str = "https://app.example.com";
+const PARTNER: &str = "https://partner.example.com";
+
+fn allow(origin: &'static str) -> CorsLayer {
+    CorsLayer::new()
+        .allow_origin(HeaderValue::from_static(origin))
+        .allow_methods([Method::GET, Method::POST])
+}
+
+route_group!(Routes, ());
+route_group!(Api, (), Routes, "/api", cors = allow(APP));
+route_group!(Users, (), Api, "/users");
+route_group!(Partners, (), Routes, "/partners", cors = allow(PARTNER));
+
+route!(group = Api, path = "/health", method = GET, async health -> &'static str { "ok" });
+
+route!(
+    group = Users,
+    path = "/{id:u64}",
+    method = GET,
+    async get_user -> String { id.to_string() }
+);
+
+route!(
+    group = Partners,
+    path = "/orders",
+    method = POST,
+    async create_order -> StatusCode { StatusCode::CREATED }
+);
+
+#[tokio::test]
+async fn applies_the_policy_of_each_group() {
+    let client = TestClient::new::<Routes>(());
+
+    let response = client.get("/api/health").header(ORIGIN, APP).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.header("access-control-allow-origin"), Some(APP));
+
+    let response = client
+        .post("/partners/orders")
+        .header(ORIGIN, PARTNER)
+        .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_eq!(
+        response.header("access-control-allow-origin"),
+        Some(PARTNER)
+    );
+}
+
+#[tokio::test]
+async fn inherits_the_policy_of_the_nearest_ancestor() {
+    let response = TestClient::new::<Routes>(())
+        .get("/api/users/7")
+        .header(ORIGIN, APP)
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "7");
+    assert_eq!(response.header("access-control-allow-origin"), Some(APP));
+}
+
+#[tokio::test]
+async fn answers_preflight_requests() {
+    let response = TestClient::new::<Routes>(())
+        .request(Method::OPTIONS, "/partners/orders")
+        .header(ORIGIN, PARTNER)
+        .header(ACCESS_CONTROL_REQUEST_METHOD, "POST")
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.header("access-control-allow-origin"),
+        Some(PARTNER)
+    );
+    assert_eq!(
+        response.header("access-control-allow-methods"),
+        Some("GET,POST")
+    );
+}
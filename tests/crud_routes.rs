@@ -0,0 +1,167 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, crud_routes, full_paths, route_group};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default)]
+struct AppState {
+    widgets: Arc<Mutex<BTreeMap<u64, Widget>>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "aide", derive(schemars::JsonSchema))]
+struct Widget {
+    name: String,
+}
+
+route_group!(Routes, AppState);
+route_group!(Api, AppState, Routes, "/api");
+
+async fn list_widgets(State(state): State<AppState>) -> Json<Vec<Widget>> {
+    Json(state.widgets.lock().unwrap().values().cloned().collect())
+}
+
+async fn get_widget(
+    State(state): State<AppState>,
+    Path(id): Path<u64>,
+) -> Result<Json<Widget>, StatusCode> {
+    let widgets = state.widgets.lock().unwrap();
+    widgets
+        .get(&id)
+        .cloned()
+        .map(Json)
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+async fn create_widget(
+    State(state): State<AppState>,
+    Json(widget): Json<Widget>,
+) -> (StatusCode, Json<u64>) {
+    let mut widgets = state.widgets.lock().unwrap();
+    let id = widgets.len() as u64 + 1;
+    widgets.insert(id, widget);
+    (StatusCode::CREATED, Json(id))
+}
+
+async fn update_widget(
+    State(state): State<AppState>,
+    Path(id): Path<u64>,
+    Json(widget): Json<Widget>,
+) -> StatusCode {
+    match state.widgets.lock().unwrap().get_mut(&id) {
+        Some(existing) => {
+            *existing = widget;
+            StatusCode::NO_CONTENT
+        }
+        None => StatusCode::NOT_FOUND,
+    }
+}
+
+async fn delete_widget(State(state): State<AppState>, Path(id): Path<u64>) -> StatusCode {
+    match state.widgets.lock().unwrap().remove(&id) {
+        Some(_) => StatusCode::NO_CONTENT,
+        None => StatusCode::NOT_FOUND,
+    }
+}
+
+crud_routes!(
+    group = Api,
+    path = "/widgets",
+    id = u64,
+    list = list_widgets,
+    get = get_widget,
+    create = create_widget,
+    update = update_widget,
+    delete = delete_widget,
+);
+
+async fn list_logs() -> &'static str {
+    "logs"
+}
+
+async fn create_log() -> StatusCode {
+    StatusCode::ACCEPTED
+}
+
+async fn get_tag(Path(name): Path<String>) -> String {
+    name
+}
+
+crud_routes!(
+    group = Api,
+    path = "/logs",
+    list = list_logs,
+    create = create_log
+);
+crud_routes!(group = Api, path = "/tags/", id = String, get = get_tag);
+
+#[tokio::test]
+async fn registers_every_endpoint() {
+    let client = TestClient::new::<Routes>(AppState::default());
+    let widget = |name: &str| Widget { name: name.into() };
+
+    let response = client.post("/api/widgets").json(&widget("gear")).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_eq!(response.json::<u64>(), 1);
+
+    let response = client.get("/api/widgets/1").await;
+    assert_eq!(response.json::<Widget>(), widget("gear"));
+
+    let response = client.put("/api/widgets/1").json(&widget("cog")).await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = client.get("/api/widgets").await;
+    assert_eq!(response.json::<Vec<Widget>>(), [widget("cog")]);
+
+    let response = client.delete("/api/widgets/1").await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = client.get("/api/widgets/1").await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn only_registers_the_declared_endpoints() {
+    let client = TestClient::new::<Routes>(AppState::default());
+
+    assert_eq!(client.get("/api/logs").await.text(), "logs");
+    assert_eq!(
+        client.post("/api/logs").await.status(),
+        StatusCode::ACCEPTED
+    );
+    assert_eq!(
+        client.delete("/api/logs").await.status(),
+        StatusCode::METHOD_NOT_ALLOWED
+    );
+    assert_eq!(client.get("/api/tags/rust").await.text(), "rust");
+    assert_eq!(
+        client.get("/api/tags/").await.status(),
+        StatusCode::NOT_FOUND
+    );
+}
+
+#[test]
+fn lists_the_endpoints_with_their_methods() {
+    let mut paths = full_paths::<Routes>()
+        .into_iter()
+        .filter(|(path, _, _)| path.starts_with("/api/widgets"))
+        .map(|(path, method, name)| (path, method.map(|method| method.to_string()), name))
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    let route = |path: &str, method: &str, name| (path.to_owned(), Some(method.to_owned()), name);
+    assert_eq!(
+        paths,
+        [
+            route("/api/widgets", "GET", "list_widgets"),
+            route("/api/widgets", "POST", "create_widget"),
+            route("/api/widgets/{id:u64}", "DELETE", "delete_widget"),
+            route("/api/widgets/{id:u64}", "GET", "get_widget"),
+            route("/api/widgets/{id:u64}", "PUT", "update_widget"),
+        ]
+    );
+}
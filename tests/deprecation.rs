@@ -0,0 +1,48 @@
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, route, route_group};
+
+route_group!(Routes, ());
+
+route!(
+    group = Routes,
+    path = "/v1/users",
+    method = GET,
+    deprecated = "2025-06-01",
+    sunset = "2025-12-31",
+    successor = "/v2/users",
+    async list_users_v1 -> &'static str { "v1" }
+);
+
+route!(
+    group = Routes,
+    path = "/v2/users",
+    method = GET,
+    async list_users_v2 -> &'static str { "v2" }
+);
+
+#[tokio::test]
+async fn announces_the_deprecation_of_the_route() {
+    let response = TestClient::new::<Routes>(()).get("/v1/users").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "v1");
+
+    assert_eq!(response.header("deprecation"), Some("@1748736000"));
+    assert_eq!(
+        response.header("sunset"),
+        Some("Wed, 31 Dec 2025 00:00:00 GMT")
+    );
+    assert_eq!(
+        response.header("link"),
+        Some("</v2/users>; rel=\"successor-version\"")
+    );
+}
+
+#[tokio::test]
+async fn leaves_the_other_routes_alone() {
+    let response = TestClient::new::<Routes>(()).get("/v2/users").await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    for header in ["deprecation", "sunset", "link"] {
+        assert_eq!(response.header(header), None, "{header}");
+    }
+}
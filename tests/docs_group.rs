@@ -0,0 +1,39 @@
+use axum::http::StatusCode;
+use axum::http::header::CONTENT_TYPE;
+use axum_distributed_routing::{TestClient, docs_group, route, route_group};
+use serde_json::Value;
+
+route_group!(Routes, ());
+route_group!(Api, (), Routes, "/api");
+docs_group!(Docs, (), Api, "/docs");
+
+route!(
+    group = Api,
+    path = "/health",
+    method = GET,
+    summary = "Health check",
+    async health -> &'static str { "ok" }
+);
+
+#[tokio::test]
+async fn serves_a_page_referencing_the_nested_spec() {
+    let response = TestClient::new::<Routes>(()).get("/api/docs").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(
+        response
+            .header(CONTENT_TYPE.as_str())
+            .unwrap()
+            .starts_with("text/html")
+    );
+    assert!(response.text().contains(r#"url: "/api/docs/openapi.json""#));
+}
+
+#[tokio::test]
+async fn serves_the_spec_of_the_parent_group() {
+    let response = TestClient::new::<Routes>(())
+        .get("/api/docs/openapi.json")
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let spec = response.json::<Value>();
+    assert_eq!(spec["paths"]["/health"]["get"]["summary"], "Health check");
+}
@@ -0,0 +1,42 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, create_router, route, route_group};
+
+static BETA: AtomicBool = AtomicBool::new(false);
+
+fn beta_enabled() -> bool {
+    BETA.load(Ordering::SeqCst)
+}
+
+route_group!(Routes, ());
+
+route!(
+    group = Routes,
+    path = "/beta",
+    method = GET,
+    enabled_if = beta_enabled,
+    async beta -> &'static str { "beta" }
+);
+
+route!(
+    group = Routes,
+    path = "/stable",
+    method = GET,
+    async stable -> &'static str { "stable" }
+);
+
+#[tokio::test]
+async fn calls_the_predicate_each_time_the_router_is_created() {
+    let disabled = TestClient::from_router(create_router!(Routes));
+    assert_eq!(disabled.get("/beta").await.status(), StatusCode::NOT_FOUND);
+    assert_eq!(disabled.get("/stable").await.status(), StatusCode::OK);
+
+    BETA.store(true, Ordering::SeqCst);
+    let enabled = TestClient::from_router(create_router!(Routes));
+    assert_eq!(enabled.get("/beta").await.text(), "beta");
+    assert_eq!(enabled.get("/stable").await.status(), StatusCode::OK);
+
+    // The routers already created are left as is
+    assert_eq!(disabled.get("/beta").await.status(), StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,95 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum_distributed_routing::{TestClient, route, route_group};
+use serde::Serialize;
+
+#[derive(Debug)]
+enum ApiError {
+    NotFound(u64),
+    Invalid(std::num::ParseIntError),
+}
+
+impl From<std::num::ParseIntError> for ApiError {
+    fn from(error: std::num::ParseIntError) -> Self {
+        ApiError::Invalid(error)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        match self {
+            ApiError::NotFound(id) => {
+                (StatusCode::NOT_FOUND, format!("user {id} not found")).into_response()
+            }
+            ApiError::Invalid(error) => {
+                (StatusCode::BAD_REQUEST, error.to_string()).into_response()
+            }
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+struct User {
+    id: u64,
+}
+
+fn find_user(id: u64) -> Result<User, ApiError> {
+    if id == 1 {
+        Ok(User { id })
+    } else {
+        Err(ApiError::NotFound(id))
+    }
+}
+
+route_group!(Routes, ());
+route_group!(Api, (), Routes, "/api", error = ApiError);
+
+route!(
+    group = Routes,
+    path = "/users/{id:u64}",
+    method = GET,
+    error = ApiError,
+    aide = false,
+    async get_user -> Json<User> {
+        let user = find_user(id)?;
+        Ok(Json(user))
+    }
+);
+
+route!(
+    group = Api,
+    path = "/parse/{value:String}",
+    method = GET,
+    error,
+    aide = false,
+    async parse -> String {
+        let value: u64 = value.parse()?;
+        Ok(value.to_string())
+    }
+);
+
+#[tokio::test]
+async fn responds_with_the_success_value() {
+    let response = TestClient::new::<Routes>(()).get("/users/1").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), r#"{"id":1}"#);
+}
+
+#[tokio::test]
+async fn responds_with_the_error_returned_by_the_question_mark() {
+    let response = TestClient::new::<Routes>(()).get("/users/2").await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(response.text(), "user 2 not found");
+}
+
+#[tokio::test]
+async fn uses_the_default_error_of_the_group() {
+    let client = TestClient::new::<Routes>(());
+    assert_eq!(client.get("/api/parse/42").await.text(), "42");
+
+    let response = client.get("/api/parse/abc").await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(response.text(), "invalid digit found in string");
+}
@@ -0,0 +1,43 @@
+use axum::extract::Request;
+use axum::http::StatusCode;
+use axum::middleware::{self, Next};
+use axum::response::Response;
+use axum_distributed_routing::{TestClient, create_router, route, route_group};
+
+#[derive(Clone)]
+struct CurrentUser(String);
+
+#[derive(Clone)]
+struct RequestId(u64);
+
+route_group!(Routes, ());
+
+route!(
+    group = Routes,
+    path = "/me",
+    method = GET,
+    extensions = [user: CurrentUser, rid: RequestId],
+    async me -> String { format!("{} ({})", user.0, rid.0) }
+);
+
+async fn authenticate(mut request: Request, next: Next) -> Response {
+    request
+        .extensions_mut()
+        .insert(CurrentUser("alice".to_owned()));
+    request.extensions_mut().insert(RequestId(7));
+    next.run(request).await
+}
+
+#[tokio::test]
+async fn reads_extensions_inserted_by_a_middleware() {
+    let router = create_router!(Routes).layer(middleware::from_fn(authenticate));
+    let response = TestClient::from_router(router).get("/me").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "alice (7)");
+}
+
+#[tokio::test]
+async fn rejects_missing_extensions() {
+    let response = TestClient::new::<Routes>(()).get("/me").await;
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
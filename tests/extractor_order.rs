@@ -0,0 +1,91 @@
+use axum::http::StatusCode;
+use axum::{Extension, Json};
+use axum_distributed_routing::{TestClient, create_router, route, route_group};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+route_group!(Routes, ());
+
+#[derive(Clone)]
+struct Tag(&'static str);
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::IntoParams), into_params(parameter_in = Query))]
+#[cfg_attr(feature = "aide", derive(schemars::JsonSchema))]
+struct Filter {
+    limit: u32,
+}
+
+#[derive(Deserialize, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "aide", derive(schemars::JsonSchema))]
+struct Note {
+    text: String,
+}
+
+route!(
+    group = Routes,
+    path = "/items/{id:u64}",
+    method = POST,
+    extensions = [tag: Tag],
+    query = Filter,
+    async annotate(Json(note): Json<Note>) -> String {
+        format!("{id} {} {} {}", tag.0, query.limit, note.text)
+    }
+);
+
+/// A client whose requests carry the extension when `tagged`
+fn client(tagged: bool) -> TestClient {
+    let router = create_router!(Routes);
+    if tagged {
+        TestClient::from_router(router.layer(Extension(Tag("new"))))
+    } else {
+        TestClient::from_router(router)
+    }
+}
+
+#[tokio::test]
+async fn rejects_with_the_first_failing_extractor() {
+    // Path parameters are extracted first
+    let response = client(false).post("/items/abc?limit=x").await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert!(
+        response.text().contains("Cannot parse"),
+        "{}",
+        response.text()
+    );
+
+    // Then the extensions
+    let response = client(false).post("/items/7?limit=x").await;
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(
+        response.text().contains("Missing request extension"),
+        "{}",
+        response.text()
+    );
+
+    // Then the query
+    let response = client(true).post("/items/7?limit=x").await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert!(
+        response
+            .text()
+            .contains("Failed to deserialize query string"),
+        "{}",
+        response.text()
+    );
+
+    // And the extra parameters last, consuming the body
+    let response = client(true).post("/items/7?limit=10").await;
+    assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+}
+
+#[tokio::test]
+async fn extracts_every_extractor() {
+    let response = client(true)
+        .post("/items/7?limit=10")
+        .json(&json!({ "text": "hello" }))
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "7 new 10 hello");
+}
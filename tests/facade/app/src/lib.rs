@@ -0,0 +1,19 @@
+use facade::routing::{route, route_group};
+use serde::Deserialize;
+
+route_group!(pub Routes, ());
+route_group!(pub Api, (), Routes, "/api");
+
+#[derive(Deserialize)]
+pub struct Greeting {
+    pub name: String,
+}
+
+route!(
+    crate = ::facade::routing,
+    group = Api,
+    path = "/users/{id:u64}",
+    method = GET,
+    query = Greeting,
+    async get_user -> String { format!("hello {}, user {id}", query.name) }
+);
@@ -0,0 +1,9 @@
+use facade::routing::TestClient;
+use facade_app::Routes;
+
+#[tokio::test]
+async fn serves_routes_declared_through_the_facade() {
+    let response = TestClient::new::<Routes>(()).get("/api/users/7?name=Ada").await;
+
+    assert_eq!(response.text(), "hello Ada, user 7");
+}
@@ -0,0 +1,6 @@
+//! A platform crate re-exporting the routing macros, so that the crates of
+//! the platform don't depend on `axum-distributed-routing` directly
+
+pub mod routing {
+    pub use axum_distributed_routing::*;
+}
@@ -0,0 +1,57 @@
+use axum::http::StatusCode;
+use axum::http::header::CONTENT_TYPE;
+use axum_distributed_routing::{TestClient, route, route_group};
+use serde::{Deserialize, Serialize};
+
+route_group!(Routes, ());
+
+#[derive(Deserialize, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "aide", derive(schemars::JsonSchema))]
+struct Login {
+    name: String,
+    remember: bool,
+}
+
+route!(
+    group = Routes,
+    path = "/login",
+    method = POST,
+    body = FormOrJson<Login>,
+    async login -> String { format!("{} {}", body.name, body.remember) }
+);
+
+fn payload() -> Login {
+    Login {
+        name: "ada".to_owned(),
+        remember: true,
+    }
+}
+
+#[tokio::test]
+async fn accepts_the_same_payload_as_json_and_as_a_form() {
+    let client = TestClient::new::<Routes>(());
+
+    let response = client.post("/login").json(&payload()).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "ada true");
+
+    let response = client.post("/login").form(&payload()).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "ada true");
+}
+
+#[tokio::test]
+async fn rejects_other_content_types() {
+    let client = TestClient::new::<Routes>(());
+
+    let response = client
+        .post("/login")
+        .header(CONTENT_TYPE, "text/plain")
+        .body("ada")
+        .await;
+    assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+
+    let response = client.post("/login").body("name=ada&remember=true").await;
+    assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+}
@@ -0,0 +1,74 @@
+use axum::http::{Method, StatusCode};
+use axum_distributed_routing::{TestClient, full_paths, join_paths, route, route_group};
+
+route_group!(Routes, ());
+route_group!(Api, (), Routes, "/api");
+route_group!(Admin, (), Api, "/admin/");
+
+route!(
+    group = Routes,
+    path = "/",
+    method = GET,
+    async index -> &'static str { "index" }
+);
+
+route!(
+    group = Api,
+    path = "/",
+    method = GET,
+    async api_index -> &'static str { "api" }
+);
+
+route!(
+    group = Api,
+    path = "/health",
+    method = GET,
+    async api_health -> &'static str { "ok" }
+);
+
+route!(
+    group = Admin,
+    path = "/users",
+    method = DELETE,
+    async delete_users -> &'static str { "deleted" }
+);
+
+#[test]
+fn joins_prefixes_like_nest() {
+    assert_eq!(join_paths("/api", "/health"), "/api/health");
+    assert_eq!(join_paths("/api", "/"), "/api");
+    assert_eq!(join_paths("/api", ""), "/api");
+    assert_eq!(join_paths("/api/", "/health"), "/api/health");
+    assert_eq!(join_paths("", "/health"), "/health");
+    assert_eq!(join_paths("/", "/health"), "/health");
+    assert_eq!(join_paths("/", "/"), "/");
+    assert_eq!(join_paths("", "/"), "/");
+}
+
+#[test]
+fn lists_the_absolute_paths_of_nested_routes() {
+    let mut paths = full_paths::<Routes>();
+    paths.sort();
+    assert_eq!(
+        paths,
+        [
+            ("/".to_owned(), Some(Method::GET), "index"),
+            ("/api".to_owned(), Some(Method::GET), "api_index"),
+            (
+                "/api/admin/users".to_owned(),
+                Some(Method::DELETE),
+                "delete_users"
+            ),
+            ("/api/health".to_owned(), Some(Method::GET), "api_health"),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn serves_the_listed_paths() {
+    let client = TestClient::new::<Routes>(());
+    for (path, method, _) in full_paths::<Routes>() {
+        let response = client.request(method.unwrap(), &path).await;
+        assert_eq!(response.status(), StatusCode::OK, "{path}");
+    }
+}
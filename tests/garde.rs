@@ -0,0 +1,194 @@
+use axum::Json;
+use axum::extract::FromRef;
+use axum::http::StatusCode;
+use axum_distributed_routing::{GardeErrorResponse, TestClient, route, route_group};
+use garde::{Error, Path, Report, Validate};
+use serde_json::json;
+
+#[derive(Clone)]
+struct PasswordRules {
+    min_length: usize,
+}
+
+#[derive(Clone)]
+struct AppState {
+    rules: PasswordRules,
+}
+
+impl FromRef<AppState> for PasswordRules {
+    fn from_ref(state: &AppState) -> Self {
+        state.rules.clone()
+    }
+}
+
+impl GardeErrorResponse for AppState {}
+
+route_group!(Routes, AppState);
+
+// Implemented by hand, as the derive needs the `derive` feature of garde
+#[derive(serde::Deserialize)]
+struct CreateUser {
+    email: String,
+}
+
+impl Validate for CreateUser {
+    type Context = ();
+
+    fn validate_into(&self, _: &(), parent: &mut dyn FnMut() -> Path, report: &mut Report) {
+        if !self.email.contains('@') {
+            report.append(
+                parent().join("email"),
+                Error::new("not a valid email address"),
+            );
+        }
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct SetPassword {
+    password: String,
+}
+
+impl Validate for SetPassword {
+    type Context = PasswordRules;
+
+    fn validate_into(
+        &self,
+        rules: &PasswordRules,
+        parent: &mut dyn FnMut() -> Path,
+        report: &mut Report,
+    ) {
+        if self.password.len() < rules.min_length {
+            report.append(
+                parent().join("password"),
+                Error::new(format!(
+                    "must be at least {} characters long",
+                    rules.min_length
+                )),
+            );
+        }
+    }
+}
+
+fn strict_rules(state: &AppState) -> PasswordRules {
+    PasswordRules {
+        min_length: state.rules.min_length * 2,
+    }
+}
+
+route!(
+    group = Routes,
+    path = "/users",
+    method = POST,
+    body = Json<CreateUser>,
+    validate = garde,
+    async create_user -> String { body.0.email }
+);
+
+route!(
+    group = Routes,
+    path = "/password",
+    method = PUT,
+    body = Json<SetPassword>,
+    validate = garde(context),
+    async set_password -> StatusCode { StatusCode::NO_CONTENT }
+);
+
+route!(
+    group = Routes,
+    path = "/admin/password",
+    method = PUT,
+    body = Json<SetPassword>,
+    validate = garde(context = strict_rules),
+    async set_admin_password -> StatusCode { StatusCode::NO_CONTENT }
+);
+
+route!(
+    group = Routes,
+    path = "/pin",
+    method = PUT,
+    body = Json<SetPassword>,
+    validate = garde(context = PasswordRules { min_length: 4 }),
+    async set_pin -> StatusCode { StatusCode::NO_CONTENT }
+);
+
+fn client() -> TestClient {
+    TestClient::new::<Routes>(AppState {
+        rules: PasswordRules { min_length: 8 },
+    })
+}
+
+#[tokio::test]
+async fn validates_without_context() {
+    let client = client();
+
+    let response = client
+        .post("/users")
+        .json(&json!({ "email": "ada@example.com" }))
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "ada@example.com");
+
+    let response = client.post("/users").json(&json!({ "email": "ada" })).await;
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        response.json::<serde_json::Value>(),
+        json!({ "email": "not a valid email address" })
+    );
+}
+
+#[tokio::test]
+async fn takes_the_context_from_the_state() {
+    let client = client();
+
+    let response = client
+        .put("/password")
+        .json(&json!({ "password": "hunter22" }))
+        .await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = client
+        .put("/password")
+        .json(&json!({ "password": "hunter2" }))
+        .await;
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        response.json::<serde_json::Value>(),
+        json!({ "password": "must be at least 8 characters long" })
+    );
+}
+
+#[tokio::test]
+async fn builds_the_context_with_a_function_of_the_state() {
+    let client = client();
+
+    let response = client
+        .put("/admin/password")
+        .json(&json!({ "password": "hunter22" }))
+        .await;
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        response.json::<serde_json::Value>(),
+        json!({ "password": "must be at least 16 characters long" })
+    );
+
+    let response = client
+        .put("/admin/password")
+        .json(&json!({ "password": "correct horse battery" }))
+        .await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn builds_the_context_with_an_expression() {
+    let client = client();
+
+    let response = client
+        .put("/pin")
+        .json(&json!({ "password": "1234" }))
+        .await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = client.put("/pin").json(&json!({ "password": "123" })).await;
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+}
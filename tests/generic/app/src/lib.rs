@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use axum_distributed_routing::{instantiate_group, route, route_group};
+use generic_auth::{Auth, AuthConfig, HasAuthConfig};
+
+#[derive(Clone)]
+pub struct AppState {
+    pub auth: Arc<AuthConfig>,
+}
+
+impl HasAuthConfig for AppState {
+    fn auth_config(&self) -> &AuthConfig {
+        &self.auth
+    }
+}
+
+route_group!(pub Routes, AppState);
+
+route!(group = Routes, path = "/health", method = GET, async health -> &'static str { "ok" });
+
+instantiate_group!(Auth<AppState>, Routes, "/auth");
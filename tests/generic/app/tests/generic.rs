@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use axum::http::{Method, StatusCode};
+use axum_distributed_routing::{TestClient, full_paths};
+use generic_app::{AppState, Routes};
+use generic_auth::AuthConfig;
+use serde_json::json;
+
+fn client() -> TestClient {
+    TestClient::new::<Routes>(AppState {
+        auth: Arc::new(AuthConfig {
+            realm: "app".to_owned(),
+            password: "secret".to_owned(),
+        }),
+    })
+}
+
+#[tokio::test]
+async fn serves_the_library_routes_with_the_app_state() {
+    let client = client();
+
+    assert_eq!(client.get("/auth/realm").await.text(), "app");
+    assert_eq!(client.get("/auth/users/7").await.text(), "user 7");
+    assert_eq!(client.get("/health").await.text(), "ok");
+}
+
+#[tokio::test]
+async fn reads_the_config_from_the_app_state() {
+    let client = client();
+
+    let login = |password| {
+        client
+            .post("/auth/login")
+            .json(&json!({ "password": password }))
+    };
+    assert_eq!(login("secret").await.status(), StatusCode::NO_CONTENT);
+    assert_eq!(login("guess").await.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[test]
+fn lists_the_library_routes_under_the_mount_path() {
+    let paths = full_paths::<Routes>();
+
+    assert!(paths.contains(&("/auth/realm".to_owned(), Some(Method::GET), "realm")));
+    assert!(paths.contains(&("/auth/login".to_owned(), Some(Method::POST), "login")));
+}
+
+#[tokio::test]
+async fn serves_the_group_as_a_root_group() {
+    let client = TestClient::new::<generic_auth::Auth<AppState>>(AppState {
+        auth: Arc::new(AuthConfig {
+            realm: "root".to_owned(),
+            password: "secret".to_owned(),
+        }),
+    });
+
+    assert_eq!(client.get("/realm").await.text(), "root");
+}
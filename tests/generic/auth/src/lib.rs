@@ -0,0 +1,46 @@
+//! A library of auth routes, generic over the state of the application
+//! mounting them
+
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum_distributed_routing::route_group;
+use serde::Deserialize;
+
+pub struct AuthConfig {
+    pub realm: String,
+    pub password: String,
+}
+
+pub trait HasAuthConfig {
+    fn auth_config(&self) -> &AuthConfig;
+}
+
+route_group!(pub Auth<S: HasAuthConfig + Clone + Send + Sync + 'static>, S, routes = [
+    GET "/realm" => realm::<S>,
+    POST "/login" => login::<S>,
+    GET "/users/{id}" => user,
+]);
+
+async fn realm<S: HasAuthConfig>(State(state): State<S>) -> String {
+    state.auth_config().realm.clone()
+}
+
+#[derive(Deserialize)]
+pub struct Credentials {
+    pub password: String,
+}
+
+async fn login<S: HasAuthConfig>(
+    State(state): State<S>,
+    Json(credentials): Json<Credentials>,
+) -> StatusCode {
+    match credentials.password == state.auth_config().password {
+        true => StatusCode::NO_CONTENT,
+        false => StatusCode::UNAUTHORIZED,
+    }
+}
+
+async fn user(Path(id): Path<u64>) -> String {
+    format!("user {id}")
+}
@@ -0,0 +1,43 @@
+use axum::extract::Path;
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum_distributed_routing::{GroupBuilder, TestClient, route, route_group};
+
+route_group!(Routes, ());
+route_group!(Api, (), Routes, "/api");
+
+route!(
+    group = Api,
+    path = "/users/{id:u64}",
+    method = GET,
+    async get_user -> String { format!("user {id}") }
+);
+
+async fn webhook(Path(id): Path<String>) -> String {
+    format!("hook {id}")
+}
+
+#[tokio::test]
+async fn serves_static_and_runtime_routes_under_the_same_prefix() {
+    let router = GroupBuilder::new()
+        .group::<Routes>()
+        .route("/api/hooks/{id}", get(webhook))
+        .with_state(());
+    let client = TestClient::from_router(router);
+
+    let response = client.get("/api/users/7").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "user 7");
+
+    let response = client.get("/api/hooks/deploy").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "hook deploy");
+}
+
+#[test]
+#[should_panic(expected = "Overlapping method route")]
+fn panics_on_a_runtime_route_conflicting_with_a_static_one() {
+    let _ = GroupBuilder::new()
+        .group::<Routes>()
+        .route("/api/users/{id}", get(webhook));
+}
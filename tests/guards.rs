@@ -0,0 +1,93 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use axum::extract::{Request, State};
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, route, route_group};
+
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Clone)]
+struct AppState {
+    admin_token: String,
+}
+
+route_group!(Routes, AppState);
+
+async fn authenticated(request: Request) -> Result<Request, StatusCode> {
+    match request.headers().contains_key("authorization") {
+        true => Ok(request),
+        false => Err(StatusCode::UNAUTHORIZED),
+    }
+}
+
+async fn require_admin(
+    State(state): State<AppState>,
+    request: Request,
+) -> Result<Request, (StatusCode, &'static str)> {
+    match request.headers().get("authorization") {
+        Some(token) if token == state.admin_token.as_str() => Ok(request),
+        _ => Err((StatusCode::FORBIDDEN, "admins only")),
+    }
+}
+
+route!(
+    group = Routes,
+    path = "/profile",
+    method = GET,
+    require = authenticated,
+    async profile -> &'static str { "profile" }
+);
+
+route!(
+    group = Routes,
+    path = "/stats",
+    method = GET,
+    require = [authenticated, require_admin],
+    async stats -> &'static str {
+        CALLS.fetch_add(1, Ordering::SeqCst);
+        "stats"
+    }
+);
+
+fn client() -> TestClient {
+    TestClient::new::<Routes>(AppState {
+        admin_token: "Bearer admin".to_owned(),
+    })
+}
+
+#[tokio::test]
+async fn rejects_requests_without_the_header() {
+    let response = client().get("/profile").await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = client()
+        .get("/profile")
+        .header("authorization", "Bearer alice")
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "profile");
+}
+
+#[tokio::test]
+async fn runs_the_guards_in_order_before_the_handler() {
+    let client = client();
+
+    let response = client.get("/stats").await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = client
+        .get("/stats")
+        .header("authorization", "Bearer alice")
+        .await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    assert_eq!(response.text(), "admins only");
+    assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+
+    let response = client
+        .get("/stats")
+        .header("authorization", "Bearer admin")
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "stats");
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,68 @@
+use axum::Json;
+use axum::extract::{Path, Query};
+use axum_distributed_routing::{route, route_group};
+use serde::Deserialize;
+
+route_group!(Routes, ());
+
+#[derive(Deserialize)]
+#[cfg_attr(
+    feature = "utoipa",
+    derive(utoipa::IntoParams),
+    into_params(parameter_in = Query)
+)]
+#[cfg_attr(feature = "aide", derive(schemars::JsonSchema))]
+struct ExprQuery {
+    times: i32,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "aide", derive(schemars::JsonSchema))]
+struct ExprBody {
+    plus: i32,
+}
+
+route!(
+    group = Routes,
+    path = "/expr/{val:i32}",
+    method = GET,
+    query = ExprQuery,
+    body = Json<ExprBody>,
+    async expr -> String {
+        format!("{}", val * query.times + body.plus)
+    }
+);
+
+route!(
+    group = Routes,
+    path = "/echo/{word:String}",
+    method = GET,
+    async echo -> String { word }
+);
+
+#[tokio::test]
+async fn calls_the_generated_handler() {
+    let response = expr(
+        Path(6),
+        Query(ExprQuery { times: 7 }),
+        Json(ExprBody { plus: 3 }),
+    )
+    .await;
+    assert_eq!(response, "45");
+}
+
+#[tokio::test]
+async fn calls_a_handler_with_a_single_path_parameter() {
+    assert_eq!(echo(Path("hello".to_owned())).await, "hello");
+}
+
+#[tokio::test]
+async fn registers_the_generated_handler() {
+    let client = axum_distributed_routing::TestClient::new::<Routes>(());
+    let response = client
+        .get("/expr/6?times=7")
+        .json(&serde_json::json!({ "plus": 3 }))
+        .await;
+    assert_eq!(response.text(), "45");
+}
@@ -0,0 +1,35 @@
+use axum::extract::Path;
+use axum::http::{Method, StatusCode};
+use axum_distributed_routing::{Route, TestClient, route, route_group};
+
+route_group!(Routes, ());
+
+mod handlers {
+    use super::*;
+
+    pub async fn get_user(Path(id): Path<u64>) -> String {
+        format!("user {id}")
+    }
+}
+
+route!(
+    group = Routes,
+    path = "/users/{id:u64}",
+    method = GET,
+    handler = handlers::get_user
+);
+
+#[tokio::test]
+async fn registers_an_existing_function() {
+    let client = TestClient::new::<Routes>(());
+    let response = client.get("/users/42").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "user 42");
+}
+
+#[test]
+fn keeps_the_route_metadata() {
+    assert_eq!(ROUTE_GET_USER.path(), "/users/{id:u64}");
+    assert_eq!(ROUTE_GET_USER.method(), Some(Method::GET));
+    assert_eq!(ROUTE_GET_USER.name(), "get_user");
+}
@@ -0,0 +1,65 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, route, route_group};
+
+#[derive(Clone)]
+struct AppState {
+    name: &'static str,
+    limits: (u32, u32),
+}
+
+route_group!(Routes, AppState);
+
+route!(
+    group = Routes,
+    path = "/name",
+    method = GET,
+    async name(State(s): State<AppState>) -> String { s.name.to_owned() }
+);
+
+route!(
+    group = Routes,
+    path = "/shout",
+    method = POST,
+    async shout(mut text: String) -> String {
+        text.make_ascii_uppercase();
+        text
+    }
+);
+
+route!(
+    group = Routes,
+    path = "/limits/{id:u32}",
+    method = GET,
+    async limits(State(AppState { limits: (min, max), .. }): State<AppState>) -> String {
+        format!("{id} {min}..{max}")
+    }
+);
+
+fn client() -> TestClient {
+    TestClient::new::<Routes>(AppState {
+        name: "app",
+        limits: (1, 10),
+    })
+}
+
+#[tokio::test]
+async fn destructures_the_state() {
+    let response = client().get("/name").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "app");
+}
+
+#[tokio::test]
+async fn binds_mutable_parameters() {
+    let response = client().post("/shout").body("hello").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "HELLO");
+}
+
+#[tokio::test]
+async fn destructures_nested_patterns() {
+    let response = client().get("/limits/3").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "3 1..10");
+}
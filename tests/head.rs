@@ -0,0 +1,77 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, route, route_group};
+
+static REPORTS: AtomicUsize = AtomicUsize::new(0);
+
+route_group!(Routes, ());
+
+route!(
+    group = Routes,
+    path = "/users",
+    method = GET,
+    async list_users -> ([(&'static str, &'static str); 1], &'static str) {
+        ([("x-total-count", "2")], "alice,bob")
+    }
+);
+
+route!(
+    group = Routes,
+    path = "/reports/{id:u64}",
+    method = GET,
+    head = { [("x-report", "cached")] },
+    async get_report -> String {
+        REPORTS.fetch_add(1, Ordering::SeqCst);
+        format!("report {id}")
+    }
+);
+
+route!(
+    group = Routes,
+    path = "/exports",
+    method = GET,
+    auto_head = false,
+    async export -> &'static str { "export" }
+);
+
+#[tokio::test]
+async fn answers_head_with_the_headers_of_get_and_no_body() {
+    let client = TestClient::new::<Routes>(());
+
+    let response = client.get("/users").await;
+    assert_eq!(response.text(), "alice,bob");
+
+    let response = client.head("/users").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.header("x-total-count"), Some("2"));
+    assert!(response.bytes().is_empty());
+}
+
+#[tokio::test]
+async fn runs_the_head_block_instead_of_the_handler() {
+    let client = TestClient::new::<Routes>(());
+
+    let response = client.head("/reports/7").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.header("x-report"), Some("cached"));
+    assert!(response.bytes().is_empty());
+    assert_eq!(REPORTS.load(Ordering::SeqCst), 0);
+
+    let response = client.get("/reports/7").await;
+    assert_eq!(response.text(), "report 7");
+    assert_eq!(response.header("x-report"), None);
+    assert_eq!(REPORTS.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn rejects_head_when_disabled() {
+    let client = TestClient::new::<Routes>(());
+
+    let response = client.head("/exports").await;
+    assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+    assert_eq!(response.header("allow"), Some("GET"));
+
+    let response = client.get("/exports").await;
+    assert_eq!(response.text(), "export");
+}
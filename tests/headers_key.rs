@@ -0,0 +1,71 @@
+use axum::http::header::{AUTHORIZATION, USER_AGENT};
+use axum::http::{HeaderMap, StatusCode};
+use axum_distributed_routing::headers::authorization::Bearer;
+use axum_distributed_routing::headers::{Authorization, UserAgent};
+use axum_distributed_routing::{TestClient, TypedHeader, route, route_group};
+
+route_group!(Routes, ());
+
+route!(
+    group = Routes,
+    path = "/document",
+    method = GET,
+    headers = {
+        auth: TypedHeader<Authorization<Bearer>>,
+        agent: Option<TypedHeader<UserAgent>>,
+    },
+    aide = false,
+    async document -> String {
+        format!("{} {:?}", auth.token(), agent.map(|agent| agent.to_string()))
+    }
+);
+
+route!(
+    group = Routes,
+    path = "/raw",
+    method = GET,
+    headers = HeaderMap,
+    async raw -> String {
+        headers
+            .get("x-request-id")
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default()
+            .to_owned()
+    }
+);
+
+#[tokio::test]
+async fn extracts_present_headers() {
+    let response = TestClient::new::<Routes>(())
+        .get("/document")
+        .header(AUTHORIZATION, "Bearer secret")
+        .header(USER_AGENT, "curl")
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "secret Some(\"curl\")");
+}
+
+#[tokio::test]
+async fn accepts_an_absent_optional_header() {
+    let response = TestClient::new::<Routes>(())
+        .get("/document")
+        .header(AUTHORIZATION, "Bearer secret")
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "secret None");
+}
+
+#[tokio::test]
+async fn rejects_an_absent_required_header() {
+    let response = TestClient::new::<Routes>(()).get("/document").await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn binds_a_single_type_as_headers() {
+    let response = TestClient::new::<Routes>(())
+        .get("/raw")
+        .header("x-request-id", "42")
+        .await;
+    assert_eq!(response.text(), "42");
+}
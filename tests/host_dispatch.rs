@@ -0,0 +1,75 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use axum_distributed_routing::{GroupBuilder, TestClient, route, route_group};
+
+/// Counts its clones, to check the state is not provided again on each request
+#[derive(Default)]
+struct AppState {
+    clones: Arc<AtomicUsize>,
+}
+
+impl Clone for AppState {
+    fn clone(&self) -> Self {
+        self.clones.fetch_add(1, Ordering::SeqCst);
+        Self {
+            clones: self.clones.clone(),
+        }
+    }
+}
+
+route_group!(Admin, AppState, host = "admin.example.com");
+route_group!(Tenants, AppState, host = "*.example.com");
+route_group!(Public, AppState);
+
+route!(group = Admin, path = "/", method = GET, async admin -> &'static str { "admin" });
+route!(group = Tenants, path = "/", method = GET, async tenant -> &'static str { "tenant" });
+route!(group = Public, path = "/", method = GET, async public -> &'static str { "public" });
+
+fn client(state: AppState) -> TestClient {
+    TestClient::from_router(
+        GroupBuilder::new()
+            .group::<Public>()
+            .group::<Tenants>()
+            .group::<Admin>()
+            .with_state(state),
+    )
+}
+
+#[tokio::test]
+async fn dispatches_by_host() {
+    let client = client(AppState::default());
+
+    for (host, body) in [
+        ("admin.example.com", "admin"),
+        ("shop.example.com:8080", "tenant"),
+        ("example.org", "public"),
+    ] {
+        assert_eq!(client.get("/").header("host", host).await.text(), body);
+    }
+    assert_eq!(client.get("/").await.text(), "public");
+}
+
+/// The clones of the state made by a request to `/` on `host`
+async fn clones_per_request(client: &TestClient, state: &AppState, host: &str) -> usize {
+    let before = state.clones.load(Ordering::SeqCst);
+    client.get("/").header("host", host).await;
+    state.clones.load(Ordering::SeqCst) - before
+}
+
+#[tokio::test]
+async fn provides_the_state_once() {
+    let state = AppState::default();
+    let hosts = client(state.clone());
+    let single = TestClient::from_router(
+        GroupBuilder::new()
+            .group::<Admin>()
+            .with_state(state.clone()),
+    );
+
+    // Only the clones made by the handlers themselves remain
+    assert_eq!(
+        clones_per_request(&hosts, &state, "admin.example.com").await,
+        clones_per_request(&single, &state, "admin.example.com").await,
+    );
+}
@@ -0,0 +1,106 @@
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, create_router, route, route_group};
+
+route_group!(Routes, ());
+route_group!(Orgs, (), Routes, "/orgs/{org_id:u64}");
+route_group!(Teams, (), Orgs, "/teams/{team:String}");
+
+route!(
+    group = Orgs,
+    path = "/projects/{project_id:u64}",
+    method = GET,
+    inherit = [org_id: u64],
+    async get_project -> String { format!("project {project_id} of org {org_id}") }
+);
+
+route!(
+    group = Teams,
+    path = "/members/{member:String}",
+    method = GET,
+    inherit = [org_id: u64, team: String],
+    async get_member -> String { format!("{member} of {team} in org {org_id}") }
+);
+
+route!(
+    group = Teams,
+    path = "/members",
+    method = GET,
+    inherit = [org_id: u64, team: String],
+    async list_members -> String { format!("members of {team} in org {org_id}") }
+);
+
+// Routes not extracting path parameters don't need to inherit them
+route!(group = Teams, path = "/ping", method = GET, async ping {});
+
+#[tokio::test]
+async fn extracts_the_captures_of_the_group() {
+    let client = TestClient::new::<Routes>(());
+
+    let response = client.get("/orgs/1/projects/2").await;
+    assert_eq!(response.text(), "project 2 of org 1");
+
+    let response = client.get("/orgs/1/teams/core/members").await;
+    assert_eq!(response.text(), "members of core in org 1");
+
+    let response = client.get("/orgs/1/teams/core/members/alice").await;
+    assert_eq!(response.text(), "alice of core in org 1");
+
+    let response = client.get("/orgs/1/teams/core/ping").await;
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn rejects_mistyped_captures() {
+    let response = TestClient::new::<Routes>(())
+        .get("/orgs/abc/projects/2")
+        .await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[test]
+fn takes_the_inherited_parameters_first_in_url_builders() {
+    assert_eq!(get_project_url(1, 2), "/orgs/1/projects/2");
+    assert_eq!(
+        get_member_url(1, "core team".into(), "alice".into()),
+        "/orgs/1/teams/core%20team/members/alice"
+    );
+    assert_eq!(ping_url(), "/orgs/{org_id:u64}/teams/{team:String}/ping");
+}
+
+route_group!(Missing, ());
+route_group!(MissingOrgs, (), Missing, "/orgs/{org_id:u64}");
+
+route!(
+    group = MissingOrgs,
+    path = "/projects/{project_id:u64}",
+    method = GET,
+    async missing_inherit -> String { project_id.to_string() }
+);
+
+#[test]
+#[should_panic(
+    expected = "the route `missing_inherit` of MissingOrgs must inherit the path parameters of /orgs/{org_id:u64} with `inherit = [org_id: u64]`"
+)]
+fn checks_that_every_capture_is_inherited() {
+    let _ = create_router!(Missing);
+}
+
+route_group!(Mistyped, ());
+route_group!(MistypedOrgs, (), Mistyped, "/orgs/{org_id:u64}");
+
+route!(
+    group = MistypedOrgs,
+    path = "/projects/{project_id:u64}",
+    method = GET,
+    inherit = [org_id: String],
+    async mistyped_inherit -> String { format!("{org_id}/{project_id}") }
+);
+
+#[test]
+#[should_panic(
+    expected = "the route `mistyped_inherit` of MistypedOrgs inherits `org_id` as `String`, but it is declared as `u64` in /orgs/{org_id:u64}"
+)]
+fn checks_the_types_of_inherited_captures() {
+    let _ = create_router!(Mistyped);
+}
@@ -0,0 +1,33 @@
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, route, route_group};
+
+route_group!(Routes, ());
+
+mod users {
+    use axum_distributed_routing::{route, route_group};
+
+    use super::Routes;
+
+    route_group!(pub Users, (), Routes, "/users");
+
+    route!(group = Users, path = "/{id:u32}", method = GET, async get_user -> String { format!("user {id}") });
+}
+
+mod health {
+    use axum_distributed_routing::route;
+
+    use super::Routes;
+
+    route!(group = Routes, path = "/health", method = GET, async health -> &'static str { "ok" });
+}
+
+route!(group = users::Users, path = "/", method = POST, status = 201, async create_user {});
+
+#[tokio::test]
+async fn collects_routes_registered_across_modules() {
+    let client = TestClient::new::<Routes>(());
+
+    assert_eq!(client.get("/health").await.text(), "ok");
+    assert_eq!(client.get("/users/7").await.text(), "user 7");
+    assert_eq!(client.post("/users").await.status(), StatusCode::CREATED);
+}
@@ -0,0 +1,123 @@
+use axum_distributed_routing::{
+    GroupTag, Method, route, route_group, route_service, routes_manifest,
+};
+
+route_group!(Routes, ());
+route_group!(
+    Api,
+    (),
+    Routes,
+    "/api",
+    tag = "Public API",
+    description = "Third parties"
+);
+route_group!(Users, (), Api, "/users/{user_id:u64}");
+
+route!(
+    group = Routes,
+    path = "/health",
+    method = GET,
+    summary = "Health check",
+    async health {}
+);
+
+route!(
+    group = Users,
+    path = "/posts/{post_id:u32}",
+    method = GET,
+    inherit = [user_id: u64],
+    async get_post -> String { format!("post {post_id} of user {user_id}") }
+);
+
+route!(
+    group = Users,
+    path = "/posts/{post_id:u32}",
+    method = DELETE,
+    deprecated = "2025-01-01",
+    async delete_post {}
+);
+
+route_service!(
+    group = Routes,
+    path = "/assets",
+    service = axum::routing::get(|| async { "asset" }),
+    nest = true
+);
+
+#[test]
+fn lists_nested_routes_with_absolute_templated_paths() {
+    let manifest = routes_manifest!(Routes);
+
+    let routes = manifest
+        .routes
+        .iter()
+        .map(|route| (route.method, route.path.as_str(), route.name, route.group))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        routes,
+        [
+            (
+                Some(Method::Delete),
+                "/api/users/{user_id}/posts/{post_id}",
+                "delete_post",
+                "Users"
+            ),
+            (
+                Some(Method::Get),
+                "/api/users/{user_id}/posts/{post_id}",
+                "get_post",
+                "Users"
+            ),
+            (None, "/assets", "/assets", "Routes"),
+            (Some(Method::Get), "/health", "health", "Routes"),
+        ]
+    );
+}
+
+#[test]
+fn exposes_route_metadata() {
+    let manifest = routes_manifest!(Routes);
+    let route = |name| {
+        manifest
+            .routes
+            .iter()
+            .find(|route| route.name == name)
+            .unwrap()
+    };
+
+    assert_eq!(route("health").summary, Some("Health check"));
+    assert_eq!(route("get_post").tags, ["Public API"]);
+    assert_eq!(
+        route("get_post").params,
+        [("user_id", "u64"), ("post_id", "u32")]
+    );
+    assert!(route("delete_post").deprecated);
+    assert!(!route("get_post").deprecated);
+    assert_eq!(
+        manifest.tags,
+        [GroupTag {
+            name: "Public API",
+            description: Some("Third parties"),
+        }]
+    );
+}
+
+#[test]
+fn serializes_to_json() {
+    let manifest = serde_json::to_value(routes_manifest!(Api)).unwrap();
+
+    assert_eq!(
+        manifest["routes"][0],
+        serde_json::json!({
+            "method": "DELETE",
+            "path": "/users/{user_id}/posts/{post_id}",
+            "name": "delete_post",
+            "group": "Users",
+            "params": [["post_id", "u32"]],
+            "tags": ["Public API"],
+            "summary": null,
+            "description": null,
+            "deprecated": true,
+        })
+    );
+}
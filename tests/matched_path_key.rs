@@ -0,0 +1,38 @@
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, route, route_group};
+
+route_group!(Routes, ());
+route_group!(Api, (), Routes, "/api");
+route_group!(Users, (), Api, "/users");
+
+route!(
+    group = Routes,
+    path = "/health",
+    method = GET,
+    matched_path = mp,
+    async health -> String { mp.as_str().to_owned() }
+);
+
+route!(
+    group = Users,
+    path = "/{id:u64}/posts/{post:u64}",
+    method = GET,
+    matched_path = mp,
+    async get_post -> String { format!("{} {id} {post}", mp.as_str()) }
+);
+
+#[tokio::test]
+async fn extracts_the_template_of_a_root_route() {
+    let response = TestClient::new::<Routes>(()).get("/health").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "/health");
+}
+
+#[tokio::test]
+async fn extracts_the_full_template_under_nested_groups() {
+    let response = TestClient::new::<Routes>(())
+        .get("/api/users/7/posts/3")
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "/api/users/{id}/posts/{post} 7 3");
+}
@@ -0,0 +1,78 @@
+use axum::Router;
+use axum::http::StatusCode;
+use axum::routing::{MethodFilter, on};
+use axum_distributed_routing::{Method, TestClient, route, route_group, routes};
+
+route_group!(Routes, ());
+
+route!(group = Routes, path = "/items", method = GET, async list_items -> &'static str { "list" });
+route!(group = Routes, path = "/items", method = POST, async create_item -> &'static str { "create" });
+
+#[test]
+fn displays_and_parses_every_method_in_uppercase() {
+    for method in Method::ALL {
+        assert_eq!(method.to_string(), method.as_str());
+        assert_eq!(method.as_str().parse::<Method>(), Ok(method));
+    }
+    assert_eq!(Method::Delete.to_string(), "DELETE");
+    assert_eq!(format!("{:>5}", Method::Get), "  GET");
+}
+
+#[test]
+fn rejects_unknown_and_lowercase_methods() {
+    let error = "get".parse::<Method>().unwrap_err();
+    assert_eq!(error.to_string(), "unsupported HTTP method `get`");
+    assert!("PURGE".parse::<Method>().is_err());
+}
+
+#[test]
+fn converts_to_and_from_http_methods() {
+    for method in Method::ALL {
+        let http = axum::http::Method::from(method);
+        assert_eq!(http.as_str(), method.as_str());
+        assert_eq!(method, http);
+        assert_eq!(Method::try_from(&http), Ok(method));
+        assert_eq!(Method::try_from(http), Ok(method));
+    }
+
+    let purge = axum::http::Method::from_bytes(b"PURGE").unwrap();
+    assert!(Method::try_from(purge).is_err());
+}
+
+#[test]
+fn converts_to_method_filters() {
+    assert_eq!(MethodFilter::from(Method::Get), MethodFilter::GET);
+    assert_eq!(MethodFilter::from(Method::Post), MethodFilter::POST);
+    assert_eq!(MethodFilter::from(Method::Put), MethodFilter::PUT);
+    assert_eq!(MethodFilter::from(Method::Patch), MethodFilter::PATCH);
+    assert_eq!(MethodFilter::from(Method::Delete), MethodFilter::DELETE);
+    assert_eq!(MethodFilter::from(Method::Head), MethodFilter::HEAD);
+    assert_eq!(MethodFilter::from(Method::Options), MethodFilter::OPTIONS);
+    assert_eq!(MethodFilter::from(Method::Trace), MethodFilter::TRACE);
+    assert_eq!(MethodFilter::from(Method::Connect), MethodFilter::CONNECT);
+}
+
+#[tokio::test]
+async fn combines_the_methods_of_the_routes_of_a_path() {
+    let mut methods: Vec<_> = routes::<Routes>()
+        .filter(|route| route.path == "/items")
+        .filter_map(|route| route.method)
+        .collect();
+    methods.sort();
+    assert_eq!(methods, [Method::Get, Method::Post]);
+
+    let filter = methods
+        .into_iter()
+        .map(MethodFilter::from)
+        .reduce(MethodFilter::or)
+        .unwrap();
+    assert_eq!(filter, MethodFilter::GET.or(MethodFilter::POST));
+
+    let client = TestClient::from_router(Router::new().route("/", on(filter, async || "ok")));
+    assert_eq!(client.get("/").await.status(), StatusCode::OK);
+    assert_eq!(client.post("/").await.status(), StatusCode::OK);
+    assert_eq!(
+        client.put("/").await.status(),
+        StatusCode::METHOD_NOT_ALLOWED
+    );
+}
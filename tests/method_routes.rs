@@ -0,0 +1,138 @@
+use axum::Router;
+use axum::http::StatusCode;
+use axum::routing::{any, get, post};
+use axum_distributed_routing::{
+    MethodRoutes, Route, TestClient, create_router, raw_routes, redirect, route, route_group,
+    route_service, static_response,
+};
+
+route_group!(Routes, ());
+route_group!(Api, (), Routes, "/api");
+
+mod read {
+    use axum_distributed_routing::route;
+
+    use super::Routes;
+
+    route!(group = Routes, path = "/items", method = GET, async list_items -> &'static str { "list" });
+}
+
+mod write {
+    use axum_distributed_routing::route;
+
+    use super::Routes;
+
+    route!(group = Routes, path = "/items", method = POST, async create_item -> &'static str { "create" });
+    route!(group = Routes, path = "/items", method = DELETE, async clear_items -> &'static str { "clear" });
+}
+
+route!(group = Api, path = "/health", method = GET, async health -> &'static str { "ok" });
+
+redirect!(group = Routes, from = "/old-items", to = "/items");
+static_response!(
+    group = Routes,
+    path = "/robots.txt",
+    content_type = "text/plain",
+    body = "User-agent: *"
+);
+route_service!(
+    group = Routes,
+    path = "/service",
+    service = get(|| async { "service" })
+);
+
+#[tokio::test]
+async fn merges_the_methods_of_a_path_across_modules() {
+    let client = TestClient::new::<Routes>(());
+
+    assert_eq!(client.get("/items").await.text(), "list");
+    assert_eq!(client.post("/items").await.text(), "create");
+    assert_eq!(client.delete("/items").await.text(), "clear");
+    assert_eq!(
+        client.put("/items").await.status(),
+        StatusCode::METHOD_NOT_ALLOWED
+    );
+}
+
+#[tokio::test]
+async fn keeps_the_other_kinds_of_routes() {
+    let client = TestClient::new::<Routes>(());
+
+    assert_eq!(client.get("/api/health").await.text(), "ok");
+    assert_eq!(
+        client.get("/old-items").await.header("location"),
+        Some("/items")
+    );
+    assert_eq!(client.get("/robots.txt").await.text(), "User-agent: *");
+    assert_eq!(client.get("/service").await.text(), "service");
+}
+
+#[test]
+fn inserts_each_path_once() {
+    let mut routes = MethodRoutes::new();
+    assert!(routes.is_empty());
+
+    let collected = raw_routes!(Routes)
+        .filter(|route| route.collect_routes(&mut routes))
+        .count();
+    assert_eq!(collected, 5);
+    assert_eq!(routes.len(), 3);
+}
+
+#[tokio::test]
+async fn merges_method_routers() {
+    let mut routes = MethodRoutes::new();
+    routes.route("/a", get(|| async { "get" }));
+    routes.route("/b", get(|| async { "b" }));
+    routes.route("/a", post(|| async { "post" }));
+    assert_eq!(routes.len(), 2);
+
+    let client = TestClient::from_router(routes.attach(Router::new()));
+    assert_eq!(client.get("/a").await.text(), "get");
+    assert_eq!(client.post("/a").await.text(), "post");
+    assert_eq!(client.get("/b").await.text(), "b");
+}
+
+#[tokio::test]
+async fn gives_precedence_to_methods_over_fallbacks() {
+    let mut routes = MethodRoutes::new();
+    routes.fallback("/a", any(|| async { "first fallback" }));
+    routes.route("/a", get(|| async { "get" }));
+    routes.fallback("/a", any(|| async { "second fallback" }));
+
+    let client = TestClient::from_router(routes.attach(Router::new()));
+    assert_eq!(client.get("/a").await.text(), "get");
+    assert_eq!(client.post("/a").await.text(), "first fallback");
+}
+
+#[test]
+#[should_panic(expected = "Overlapping method route")]
+fn panics_on_duplicate_methods() {
+    let mut routes = MethodRoutes::<()>::new();
+    routes.route("/a", get(|| async {}));
+    routes.route("/a", get(|| async {}));
+}
+
+route_group!(Duplicated, ());
+
+mod first {
+    use axum_distributed_routing::route;
+
+    use super::Duplicated;
+
+    route!(group = Duplicated, path = "/dup", method = GET, async first_dup {});
+}
+
+mod second {
+    use axum_distributed_routing::route;
+
+    use super::Duplicated;
+
+    route!(group = Duplicated, path = "/dup", method = GET, async second_dup {});
+}
+
+#[test]
+#[should_panic(expected = "Overlapping method route")]
+fn panics_on_routes_registered_twice() {
+    let _ = create_router!(Duplicated);
+}
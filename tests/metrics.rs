@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, route, route_group};
+use metrics::{Counter, Gauge, Histogram, Key, KeyName, Metadata, Recorder, SharedString, Unit};
+
+/// Records the counters incremented while it is the default recorder, by
+/// name and labels, e.g. `http_requests_total{route=get_user,method=GET,status=200}`
+#[derive(Default)]
+struct Counters(Mutex<HashMap<String, Arc<AtomicU64>>>);
+
+impl Counters {
+    fn get(&self, key: &str) -> u64 {
+        self.0
+            .lock()
+            .unwrap()
+            .get(key)
+            .map_or(0, |count| count.load(Ordering::Relaxed))
+    }
+
+    fn names(&self) -> Vec<String> {
+        self.0.lock().unwrap().keys().cloned().collect()
+    }
+}
+
+impl Recorder for Counters {
+    fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+    fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+    fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+    fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+        let labels = key
+            .labels()
+            .map(|label| format!("{}={}", label.key(), label.value()))
+            .collect::<Vec<_>>()
+            .join(",");
+        let count = self
+            .0
+            .lock()
+            .unwrap()
+            .entry(format!("{}{{{labels}}}", key.name()))
+            .or_default()
+            .clone();
+        Counter::from_arc(count)
+    }
+
+    fn register_gauge(&self, _: &Key, _: &Metadata<'_>) -> Gauge {
+        Gauge::noop()
+    }
+
+    fn register_histogram(&self, _: &Key, _: &Metadata<'_>) -> Histogram {
+        Histogram::noop()
+    }
+}
+
+route_group!(Routes, ());
+
+route!(
+    group = Routes,
+    path = "/users/{id:u64}",
+    method = GET,
+    async get_user -> Result<String, StatusCode> {
+        match id {
+            0 => Err(StatusCode::NOT_FOUND),
+            id => Ok(id.to_string()),
+        }
+    }
+);
+
+route!(
+    group = Routes,
+    path = "/health",
+    method = GET,
+    metrics = false,
+    async health -> &'static str { "ok" }
+);
+
+route_group!(Internal, (), metrics = false);
+
+route!(
+    group = Internal,
+    path = "/debug",
+    method = GET,
+    async debug -> &'static str { "debug" }
+);
+
+#[tokio::test]
+async fn counts_the_requests_by_route_method_and_status() {
+    let counters = Counters::default();
+    let _guard = metrics::set_default_local_recorder(&counters);
+    let client = TestClient::new::<Routes>(());
+
+    assert_eq!(client.get("/users/1").await.status(), StatusCode::OK);
+    assert_eq!(client.get("/users/2").await.status(), StatusCode::OK);
+    assert_eq!(client.get("/users/0").await.status(), StatusCode::NOT_FOUND);
+
+    assert_eq!(
+        counters.get("http_requests_total{route=get_user,method=GET,status=200}"),
+        2
+    );
+    assert_eq!(
+        counters.get("http_requests_total{route=get_user,method=GET,status=404}"),
+        1
+    );
+}
+
+#[tokio::test]
+async fn skips_the_routes_opting_out() {
+    let counters = Counters::default();
+    let _guard = metrics::set_default_local_recorder(&counters);
+
+    let response = TestClient::new::<Routes>(()).get("/health").await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    assert!(counters.names().is_empty());
+}
+
+#[tokio::test]
+async fn skips_the_groups_opting_out() {
+    let counters = Counters::default();
+    let _guard = metrics::set_default_local_recorder(&counters);
+
+    let response = TestClient::new::<Internal>(()).get("/debug").await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    assert!(counters.names().is_empty());
+}
@@ -0,0 +1,99 @@
+use axum::http::{Method, StatusCode};
+use axum_distributed_routing::{
+    TestClient, full_paths, mount_group, route, route_group, routes_manifest,
+};
+
+#[derive(Clone)]
+struct AppState {
+    name: &'static str,
+}
+
+#[derive(Clone)]
+struct BillingState {
+    currency: &'static str,
+}
+
+route_group!(Routes, AppState);
+route_group!(Billing, BillingState);
+route_group!(Invoices, BillingState, Billing, "/invoices");
+
+mount_group!(
+    Routes,
+    "/billing",
+    Billing,
+    state = BillingState { currency: "EUR" }
+);
+
+route!(
+    group = Routes,
+    path = "/name",
+    method = GET,
+    state,
+    async app_name -> &'static str { state.name }
+);
+
+route!(
+    group = Billing,
+    path = "/currency",
+    method = GET,
+    state,
+    async currency -> &'static str { state.currency }
+);
+
+route!(
+    group = Invoices,
+    path = "/{id:u64}",
+    method = GET,
+    state,
+    async get_invoice -> String { format!("invoice {id} in {}", state.currency) }
+);
+
+fn client() -> TestClient {
+    TestClient::new::<Routes>(AppState { name: "app" })
+}
+
+#[tokio::test]
+async fn provides_the_state_of_the_mounted_group() {
+    let client = client();
+
+    assert_eq!(client.get("/name").await.text(), "app");
+    assert_eq!(client.get("/billing/currency").await.text(), "EUR");
+    assert_eq!(
+        client.get("/billing/invoices/3").await.text(),
+        "invoice 3 in EUR"
+    );
+    assert_eq!(
+        client.get("/currency").await.status(),
+        StatusCode::NOT_FOUND
+    );
+}
+
+#[tokio::test]
+async fn mounted_groups_can_be_used_as_root_groups() {
+    let client = TestClient::new::<Billing>(BillingState { currency: "USD" });
+
+    assert_eq!(client.get("/currency").await.text(), "USD");
+}
+
+#[test]
+fn lists_the_mounted_routes_under_the_parent() {
+    let paths = full_paths::<Routes>();
+    assert!(paths.contains(&(
+        "/billing/invoices/{id:u64}".to_owned(),
+        Some(Method::GET),
+        "get_invoice"
+    )));
+
+    let manifest = routes_manifest!(Routes);
+    assert!(
+        manifest
+            .routes
+            .iter()
+            .any(|route| route.path == "/billing/currency" && route.group == "Billing")
+    );
+}
+
+#[test]
+fn url_builders_ignore_the_mount_path() {
+    assert_eq!(currency_url(), "/currency");
+}
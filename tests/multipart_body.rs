@@ -0,0 +1,80 @@
+use axum::extract::multipart::MultipartError;
+use axum::http::StatusCode;
+use axum::http::header::CONTENT_TYPE;
+use axum_distributed_routing::{TestClient, route, route_group};
+
+route_group!(Routes, ());
+
+route!(
+    group = Routes,
+    path = "/upload",
+    method = POST,
+    body = multipart,
+    body_limit = 1024,
+    error = MultipartError,
+    aide = false,
+    async upload -> String {
+        let mut received = Vec::new();
+        while let Some(field) = body.next_field().await? {
+            let name = field.name().unwrap_or_default().to_owned();
+            let file_name = field.file_name().map(str::to_owned);
+            let content = field.text().await?;
+            received.push(format!("{name} {file_name:?} {content}"));
+        }
+        Ok(received.join("\n"))
+    }
+);
+
+const BOUNDARY: &str = "X-BOUNDARY";
+
+fn multipart(parts: &[(&str, Option<&str>, &str)]) -> String {
+    let mut body = String::new();
+    for (name, file_name, content) in parts {
+        body.push_str(&format!("--{BOUNDARY}\r\n"));
+        match file_name {
+            Some(file_name) => body.push_str(&format!(
+                "Content-Disposition: form-data; name=\"{name}\"; filename=\"{file_name}\"\r\nContent-Type: text/plain\r\n\r\n"
+            )),
+            None => body.push_str(&format!(
+                "Content-Disposition: form-data; name=\"{name}\"\r\n\r\n"
+            )),
+        }
+        body.push_str(content);
+        body.push_str("\r\n");
+    }
+    body.push_str(&format!("--{BOUNDARY}--\r\n"));
+    body
+}
+
+#[tokio::test]
+async fn receives_a_file_and_a_text_field() {
+    let response = TestClient::new::<Routes>(())
+        .post("/upload")
+        .header(
+            CONTENT_TYPE,
+            format!("multipart/form-data; boundary={BOUNDARY}"),
+        )
+        .body(multipart(&[
+            ("file", Some("notes.txt"), "hello"),
+            ("title", None, "Notes"),
+        ]))
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.text(),
+        "file Some(\"notes.txt\") hello\ntitle None Notes"
+    );
+}
+
+#[tokio::test]
+async fn applies_the_body_limit() {
+    let response = TestClient::new::<Routes>(())
+        .post("/upload")
+        .header(
+            CONTENT_TYPE,
+            format!("multipart/form-data; boundary={BOUNDARY}"),
+        )
+        .body(multipart(&[("file", Some("big.txt"), &"a".repeat(4096))]))
+        .await;
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
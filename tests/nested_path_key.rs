@@ -0,0 +1,54 @@
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, route, route_group};
+
+route_group!(Routes, ());
+route_group!(Api, (), Routes, "/api");
+route_group!(Inner, (), Api, "/inner");
+
+// aide doesn't document `NestedPath`
+route!(
+    group = Api,
+    path = "/prefix",
+    method = GET,
+    nested_path = prefix,
+    aide = false,
+    async api_prefix -> String { prefix.as_str().to_owned() }
+);
+
+route!(
+    group = Inner,
+    path = "/prefix",
+    method = GET,
+    nested_path = prefix,
+    aide = false,
+    async inner_prefix -> String { prefix.as_str().to_owned() }
+);
+
+route!(
+    group = Routes,
+    path = "/prefix",
+    method = GET,
+    nested_path = prefix,
+    aide = false,
+    async root_prefix -> String { prefix.as_str().to_owned() }
+);
+
+#[tokio::test]
+async fn extracts_the_prefix_of_the_group() {
+    let client = TestClient::new::<Routes>(());
+
+    let response = client.get("/api/prefix").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "/api");
+
+    let response = client.get("/api/inner/prefix").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.text().starts_with("/api"));
+    assert_eq!(response.text(), "/api/inner");
+}
+
+#[tokio::test]
+async fn rejects_the_routes_of_the_root_group() {
+    let response = TestClient::new::<Routes>(()).get("/prefix").await;
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
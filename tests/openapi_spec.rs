@@ -0,0 +1,86 @@
+use axum::Json;
+use axum_distributed_routing::{openapi_spec, route, route_group};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+route_group!(Routes, ());
+route_group!(Api, (), Routes, "/api");
+route_group!(Users, (), Api, "/users/{user_id:u64}");
+
+#[derive(Deserialize)]
+#[cfg_attr(
+    feature = "utoipa",
+    derive(utoipa::IntoParams),
+    into_params(parameter_in = Query)
+)]
+#[cfg_attr(feature = "aide", derive(schemars::JsonSchema))]
+struct Filter {
+    author: String,
+}
+
+#[derive(Deserialize, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "aide", derive(schemars::JsonSchema))]
+struct Post {
+    title: String,
+}
+
+route!(
+    group = Users,
+    path = "/posts",
+    method = POST,
+    inherit = [user_id: u64],
+    query = Filter,
+    body = Json<Post>,
+    status = 201,
+    summary = "Creates a post",
+    async create_post -> Json<Post> {
+        Json(Post {
+            title: format!("{} by {} ({user_id})", body.title, query.author),
+        })
+    }
+);
+
+route!(
+    group = Routes,
+    path = "/tunnel",
+    method = CONNECT,
+    async tunnel { }
+);
+
+#[test]
+fn joins_the_prefixes_of_nested_groups() {
+    let spec = openapi_spec!(Routes, "Blog", "2.0.0");
+    assert_eq!(spec["openapi"], "3.1.0");
+    assert_eq!(spec["info"], json!({ "title": "Blog", "version": "2.0.0" }));
+
+    let paths = spec["paths"].as_object().unwrap();
+    assert_eq!(
+        paths.keys().collect::<Vec<_>>(),
+        ["/api/users/{user_id}/posts"]
+    );
+}
+
+#[test]
+fn describes_the_operation_of_a_route() {
+    let spec = openapi_spec!(Routes, "Blog", "2.0.0");
+    let operation = &spec["paths"]["/api/users/{user_id}/posts"]["post"];
+    assert_eq!(operation["operationId"], "create_post");
+    assert_eq!(operation["summary"], "Creates a post");
+    assert_eq!(
+        operation["parameters"][0],
+        json!({
+            "name": "user_id",
+            "in": "path",
+            "required": true,
+            "schema": { "type": "integer", "format": "int64" },
+        })
+    );
+    assert_eq!(operation["parameters"][1]["in"], "query");
+    assert_eq!(operation["parameters"][1]["schema"]["title"], "Filter");
+    assert_eq!(
+        operation["requestBody"]["content"]["application/json"]["schema"],
+        json!({ "title": "Post" })
+    );
+    assert!(operation["responses"]["201"].is_object());
+}
@@ -0,0 +1,79 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::http::header::CONTENT_TYPE;
+use axum_distributed_routing::{TestClient, route, route_group};
+use serde_json::json;
+
+route_group!(Routes, ());
+
+#[derive(serde::Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "aide", derive(schemars::JsonSchema))]
+struct Note {
+    text: String,
+}
+
+route!(
+    group = Routes,
+    path = "/notes",
+    method = POST,
+    body = Option<Json<Note>>,
+    async create_note -> String {
+        match body {
+            Some(Json(note)) => note.text,
+            None => "empty".to_owned(),
+        }
+    }
+);
+
+#[tokio::test]
+async fn extracts_the_body_of_json_requests() {
+    let response = TestClient::new::<Routes>(())
+        .post("/notes")
+        .json(&json!({ "text": "hello" }))
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "hello");
+}
+
+#[tokio::test]
+async fn extracts_none_without_a_content_type() {
+    let client = TestClient::new::<Routes>(());
+
+    let response = client.post("/notes").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "empty");
+
+    // The body is ignored without a content type
+    let response = client.post("/notes").body(r#"{"text":"hello"}"#).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "empty");
+}
+
+#[tokio::test]
+async fn rejects_empty_and_invalid_json() {
+    let client = TestClient::new::<Routes>(());
+
+    let response = client
+        .post("/notes")
+        .header(CONTENT_TYPE, "application/json")
+        .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = client
+        .post("/notes")
+        .header(CONTENT_TYPE, "application/json")
+        .body("{")
+        .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn rejects_other_content_types() {
+    let response = TestClient::new::<Routes>(())
+        .post("/notes")
+        .header(CONTENT_TYPE, "text/plain")
+        .body("hello")
+        .await;
+    assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+}
@@ -0,0 +1,39 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, route, route_group};
+
+route_group!(Routes, ());
+
+static PINGS: AtomicUsize = AtomicUsize::new(0);
+
+route!(
+    group = Routes,
+    path = "/ping",
+    method = POST,
+    async ping {
+        PINGS.fetch_add(1, Ordering::SeqCst);
+    }
+);
+
+route!(
+    group = Routes,
+    path = "/pong",
+    method = GET,
+    async pong { "pong" }
+);
+
+#[tokio::test]
+async fn runs_a_unit_returning_handler() {
+    let response = TestClient::new::<Routes>(()).post("/ping").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "");
+    assert_eq!(PINGS.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn responds_with_the_tail_expression() {
+    let response = TestClient::new::<Routes>(()).get("/pong").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "pong");
+}
@@ -0,0 +1,74 @@
+use axum::Json;
+use axum::extract::OriginalUri;
+use axum::response::IntoResponse;
+use axum_distributed_routing::{Pagination, TestClient, route, route_group};
+
+route_group!(Routes, ());
+route_group!(Api, (), Routes, "/api");
+
+const WIDGETS: u64 = 95;
+
+route!(
+    group = Api,
+    path = "/widgets",
+    method = GET,
+    query = Pagination<50>,
+    parts = [uri: OriginalUri],
+    async list_widgets -> impl IntoResponse {
+        let widgets = (query.offset()..WIDGETS).take(query.limit() as usize).collect::<Vec<_>>();
+        (query.headers(&uri, WIDGETS), Json(widgets))
+    }
+);
+
+#[test]
+fn defaults_to_the_first_page() {
+    let page = Pagination::<100>::default();
+
+    assert_eq!((page.page, page.per_page), (1, 25));
+    assert_eq!((page.offset(), page.limit()), (0, 25));
+}
+
+#[test]
+fn clamps_the_page_and_its_size() {
+    let page: Pagination<50> = serde_urlencoded::from_str("page=0&per_page=500").unwrap();
+    assert_eq!((page.page, page.per_page), (1, 50));
+
+    let page: Pagination<50> = serde_urlencoded::from_str("per_page=0").unwrap();
+    assert_eq!((page.page, page.per_page), (1, 1));
+}
+
+#[test]
+fn links_the_neighbouring_pages() {
+    let page = Pagination::<100>::new(2, 10);
+    let headers = page.headers(&"/widgets?sort=name&page=2".parse().unwrap(), 35);
+
+    assert_eq!(headers.total, 35);
+    assert_eq!(
+        headers.link,
+        [
+            "</widgets?sort=name&page=1&per_page=10>; rel=\"first\"",
+            "</widgets?sort=name&page=1&per_page=10>; rel=\"prev\"",
+            "</widgets?sort=name&page=3&per_page=10>; rel=\"next\"",
+            "</widgets?sort=name&page=4&per_page=10>; rel=\"last\"",
+        ]
+        .join(", ")
+    );
+}
+
+#[tokio::test]
+async fn paginates_a_route() {
+    let response = TestClient::new::<Routes>(())
+        .get("/api/widgets?page=2&per_page=60")
+        .await;
+
+    assert_eq!(response.json::<Vec<u64>>(), (50..95).collect::<Vec<_>>());
+    assert_eq!(response.header("x-total-count"), Some("95"));
+    assert_eq!(
+        response.header("link"),
+        Some(
+            "</api/widgets?page=1&per_page=50>; rel=\"first\", \
+             </api/widgets?page=1&per_page=50>; rel=\"prev\", \
+             </api/widgets?page=2&per_page=50>; rel=\"last\""
+        )
+    );
+}
@@ -0,0 +1,27 @@
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, route, route_group};
+
+route_group!(Routes, ());
+
+route!(
+    group = Routes,
+    path = "/echo",
+    method = PUT,
+    parts = [headers: HeaderMap, uri: Uri, method: Method, version: Version],
+    async echo -> String {
+        format!(
+            "{method} {uri} {version:?} {}",
+            headers["x-request-id"].to_str().unwrap()
+        )
+    }
+);
+
+#[tokio::test]
+async fn extracts_the_parts_of_the_request() {
+    let response = TestClient::new::<Routes>(())
+        .put("/echo?page=2")
+        .header("x-request-id", "42")
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "PUT /echo?page=2 HTTP/1.1 42");
+}
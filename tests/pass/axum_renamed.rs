@@ -0,0 +1,46 @@
+// axum is only reachable as `web`, `axum` naming an unrelated module
+extern crate axum as web;
+
+mod axum {}
+
+use axum_distributed_routing::{route, route_group};
+use serde::Deserialize;
+
+route_group!(Routes, ());
+route_group!(Api, (), Routes, "/api");
+
+#[derive(Deserialize)]
+#[cfg_attr(
+    feature = "utoipa",
+    derive(utoipa::IntoParams),
+    into_params(parameter_in = Query)
+)]
+#[cfg_attr(feature = "aide", derive(schemars::JsonSchema))]
+struct Page {
+    page: u32,
+}
+
+route!(
+    group = Api,
+    path = "/users/{id:u64}",
+    method = GET,
+    query = Option<Page>,
+    headers = { agent: web::http::HeaderMap },
+    async get_user -> web::Json<u64> {
+        let _ = (agent, query.map(|query| query.page));
+        web::Json(id)
+    }
+);
+
+route!(
+    group = Api,
+    path = "/users",
+    method = POST,
+    body = web::Json<u64>,
+    status = 201,
+    async create_user -> String { body.0.to_string() }
+);
+
+fn main() {
+    let _ = axum_distributed_routing::create_router!(Routes);
+}
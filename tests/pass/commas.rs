@@ -0,0 +1,27 @@
+use axum_distributed_routing::{route, route_group};
+
+route_group!(Routes, ());
+
+// A trailing comma after the last key
+route!(
+    group = Routes,
+    path = "/users",
+    method = GET,
+    handler = list_users,
+);
+
+// An optional comma after the inline handler
+route!(
+    group = Routes,
+    path = "/users",
+    method = POST,
+    async create_user -> &'static str { "created" },
+);
+
+async fn list_users() -> &'static str {
+    "users"
+}
+
+fn main() {
+    let _ = axum_distributed_routing::create_router!(Routes);
+}
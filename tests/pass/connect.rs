@@ -0,0 +1,14 @@
+use axum_distributed_routing::{route, route_group};
+
+route_group!(Routes, ());
+
+route!(
+    group = Routes,
+    path = "/tunnel",
+    method = CONNECT,
+    async tunnel -> &'static str { "connected" }
+);
+
+fn main() {
+    let _ = axum_distributed_routing::create_router!(Routes);
+}
@@ -0,0 +1,17 @@
+use axum::extract::State;
+use axum_distributed_routing::{create_router, instantiate_group, route_group};
+
+// The bounds end with a generic trait, closed by `>>`
+route_group!(Named<S: Clone + Send + Sync + 'static + AsRef<str>>, S, routes = [
+    GET "/name" => name::<S>,
+]);
+
+async fn name<S: AsRef<str>>(State(state): State<S>) -> String {
+    state.as_ref().to_owned()
+}
+
+instantiate_group!(Named<String>);
+
+fn main() {
+    let _: axum::Router<String> = create_router!(Named<String>);
+}
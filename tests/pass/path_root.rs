@@ -0,0 +1,9 @@
+use axum_distributed_routing::{route, route_group};
+
+route_group!(Routes, ());
+
+route!(group = Routes, path = "/", method = GET, async index -> &'static str { "index" });
+
+fn main() {
+    let _ = axum_distributed_routing::create_router!(Routes);
+}
@@ -0,0 +1,39 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum_distributed_routing::{route, route_group};
+use serde::Serialize;
+
+route_group!(Routes, ());
+
+#[derive(Serialize)]
+struct User {
+    id: u64,
+}
+
+route!(
+    group = Routes,
+    path = "/health",
+    method = GET,
+    async health -> impl IntoResponse { (StatusCode::OK, "ok") }
+);
+
+route!(
+    group = Routes,
+    path = "/users",
+    method = POST,
+    async create_user -> (StatusCode, Json<User>) {
+        (StatusCode::CREATED, Json(User { id: 1 }))
+    }
+);
+
+route!(
+    group = Routes,
+    path = "/name",
+    method = GET,
+    async name -> String { "name".to_owned() }
+);
+
+fn main() {
+    let _ = axum_distributed_routing::create_router!(Routes);
+}
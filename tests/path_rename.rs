@@ -0,0 +1,24 @@
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, route, route_group};
+
+route_group!(Routes, ());
+
+route!(
+    group = Routes,
+    path = "/items/{type:String as kind}/{account-id:u64 as account}",
+    method = GET,
+    matched_path = mp,
+    async get_item -> String { format!("{} {kind} {account}", mp.as_str()) }
+);
+
+#[tokio::test]
+async fn binds_renamed_parameters_under_their_new_name() {
+    let response = TestClient::new::<Routes>(()).get("/items/book/7").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "/items/{type}/{account-id} book 7");
+}
+
+#[test]
+fn builds_urls_from_renamed_parameters() {
+    assert_eq!(get_item_url("book".into(), 7), "/items/book/7");
+}
@@ -0,0 +1,105 @@
+use std::sync::Mutex;
+
+use axum::Router;
+use axum::http::StatusCode;
+use axum_distributed_routing::{Route, TestClient, create_router, raw_routes, route, route_group};
+
+/// A group recording the order its routes are attached in
+struct Recorder {
+    path: &'static str,
+    priority: i32,
+}
+
+static ATTACHED: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+
+static RECORDED: [Recorder; 4] = [
+    Recorder {
+        path: "/b",
+        priority: 0,
+    },
+    Recorder {
+        path: "/files/{*path}",
+        priority: -10,
+    },
+    Recorder {
+        path: "/a",
+        priority: 0,
+    },
+    Recorder {
+        path: "/z",
+        priority: 10,
+    },
+];
+
+impl Route for Recorder {
+    type State = ();
+
+    fn attach(&self, router: Router, _level: usize) -> Router {
+        ATTACHED.lock().unwrap().push(self.path);
+        router
+    }
+
+    fn path(&self) -> &'static str {
+        self.path
+    }
+
+    fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    fn registered() -> impl Iterator<Item = &'static Self> {
+        RECORDED.iter()
+    }
+}
+
+#[test]
+fn attaches_by_descending_priority_then_path() {
+    let _ = create_router!(Recorder);
+    assert_eq!(
+        *ATTACHED.lock().unwrap(),
+        ["/z", "/a", "/b", "/files/{*path}"]
+    );
+}
+
+route_group!(Routes, ());
+route_group!(Api, (), Routes, "/api", priority = 5);
+
+route!(
+    group = Routes,
+    path = "/files/{*path:String as path}",
+    method = GET,
+    priority = -10,
+    async files -> String { path }
+);
+
+route!(
+    group = Routes,
+    path = "/files/index",
+    method = GET,
+    async files_index -> &'static str { "index" }
+);
+
+route!(
+    group = Api,
+    path = "/health",
+    method = GET,
+    async health -> &'static str { "ok" }
+);
+
+#[test]
+fn records_the_priority_of_routes_and_groups() {
+    assert_eq!(ROUTE_FILES.priority(), -10);
+    assert_eq!(ROUTE_FILES_INDEX.priority(), 0);
+    let api = raw_routes!(Routes)
+        .find(|route| route.path() == "/api")
+        .unwrap();
+    assert_eq!(api.priority(), 5);
+}
+
+#[tokio::test]
+async fn routes_specific_paths_before_catch_alls() {
+    let client = TestClient::new::<Routes>(());
+    assert_eq!(client.get("/files/index").await.text(), "index");
+    assert_eq!(client.get("/files/a/b").await.text(), "a/b");
+    assert_eq!(client.get("/api/health").await.status(), StatusCode::OK);
+}
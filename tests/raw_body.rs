@@ -0,0 +1,68 @@
+use std::convert::Infallible;
+
+use axum::body::{Body, Bytes};
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, route, route_group};
+use futures_util::StreamExt;
+
+route_group!(Routes, ());
+
+route!(
+    group = Routes,
+    path = "/stream",
+    method = POST,
+    body = stream,
+    async stream -> String {
+        let mut chunks = Vec::new();
+        while let Some(Ok(chunk)) = body.next().await {
+            chunks.push(String::from_utf8_lossy(&chunk).into_owned());
+        }
+        chunks.concat()
+    }
+);
+
+route!(
+    group = Routes,
+    path = "/bytes",
+    method = POST,
+    body = bytes,
+    body_limit = 16,
+    async bytes -> String { format!("{} {:?}", body.len(), body) }
+);
+
+fn chunked(chunks: &'static [&'static str]) -> Body {
+    Body::from_stream(futures_util::stream::iter(
+        chunks
+            .iter()
+            .map(|chunk| Ok::<_, Infallible>(Bytes::from_static(chunk.as_bytes()))),
+    ))
+}
+
+#[tokio::test]
+async fn streams_a_multi_chunk_body() {
+    let response = TestClient::new::<Routes>(())
+        .post("/stream")
+        .body(chunked(&["first ", "second ", "third"]))
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "first second third");
+}
+
+#[tokio::test]
+async fn buffers_the_full_payload() {
+    let response = TestClient::new::<Routes>(())
+        .post("/bytes")
+        .body(chunked(&["hello ", "world"]))
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "11 b\"hello world\"");
+}
+
+#[tokio::test]
+async fn applies_the_body_limit_to_bytes() {
+    let response = TestClient::new::<Routes>(())
+        .post("/bytes")
+        .body("a".repeat(32))
+        .await;
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
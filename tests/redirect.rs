@@ -0,0 +1,64 @@
+use axum::http::StatusCode;
+use axum_distributed_routing::{RouteKind, TestClient, full_paths, redirect, route_group, routes};
+
+route_group!(Routes, ());
+
+redirect!(group = Routes, from = "/docs", to = "/docs/latest");
+redirect!(
+    group = Routes,
+    from = "/maintenance",
+    to = "/status",
+    status = 307
+);
+redirect!(
+    group = Routes,
+    from = "/old-api/{id}",
+    to = "/api/v1/items/{id}",
+    status = 301
+);
+
+#[tokio::test]
+async fn redirects_permanently_by_default() {
+    let response = TestClient::new::<Routes>(()).get("/docs").await;
+
+    assert_eq!(response.status(), StatusCode::PERMANENT_REDIRECT);
+    assert_eq!(response.header("location"), Some("/docs/latest"));
+}
+
+#[tokio::test]
+async fn redirects_temporarily_with_any_method() {
+    let client = TestClient::new::<Routes>(());
+
+    for response in [
+        client.get("/maintenance").await,
+        client.post("/maintenance").await,
+    ] {
+        assert_eq!(response.status(), StatusCode::TEMPORARY_REDIRECT);
+        assert_eq!(response.header("location"), Some("/status"));
+    }
+}
+
+#[tokio::test]
+async fn forwards_path_parameters() {
+    let client = TestClient::new::<Routes>(());
+
+    let response = client.get("/old-api/42").await;
+    assert_eq!(response.status(), StatusCode::MOVED_PERMANENTLY);
+    assert_eq!(response.header("location"), Some("/api/v1/items/42"));
+
+    let response = client.get("/old-api/a%20b").await;
+    assert_eq!(response.header("location"), Some("/api/v1/items/a%20b"));
+}
+
+#[test]
+fn lists_redirects_among_the_routes() {
+    let redirects = routes!(Routes).filter(|route| route.kind == RouteKind::Leaf);
+    assert_eq!(redirects.count(), 3);
+
+    let paths = full_paths::<Routes>();
+    assert!(
+        paths
+            .iter()
+            .any(|(path, method, _)| path == "/old-api/{id}" && method.is_none())
+    );
+}
@@ -0,0 +1,64 @@
+use axum::Json;
+use axum::extract::{Path, Query};
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, route, route_group};
+use serde::Deserialize;
+use serde_json::json;
+
+route_group!(Routes, (), rejection_format = json);
+route_group!(Plain, ());
+
+#[derive(Deserialize)]
+struct Page {
+    page: u32,
+}
+
+route!(
+    group = Routes,
+    path = "/users/{id:u64}",
+    method = GET,
+    query = Page,
+    async get_user -> String { format!("user {id}, page {}", query.page) }
+);
+
+route!(
+    group = Plain,
+    path = "/users/{id:u64}",
+    method = POST,
+    body = Json<serde_json::Value>,
+    async update_user -> String { format!("user {id}: {}", body["name"]) }
+);
+
+#[tokio::test]
+async fn reports_rejections_in_the_format_of_the_group() {
+    let response = TestClient::new::<Routes>(()).get("/users/abc?page=1").await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = response.json::<serde_json::Value>();
+    assert_eq!(body["error"]["kind"], json!("path"));
+    assert_eq!(body["error"]["field"], json!("id"));
+
+    let response = TestClient::new::<Routes>(()).get("/users/7?page=first").await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = response.json::<serde_json::Value>();
+    assert_eq!(body["error"]["kind"], json!("query"));
+    assert_eq!(body["error"]["field"], json!("page"));
+}
+
+#[tokio::test]
+async fn keeps_the_rejections_of_axum_without_a_format() {
+    let response = TestClient::new::<Plain>(()).post("/users/abc").json(&json!({})).await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert!(response.text().starts_with("Invalid URL"));
+}
+
+#[tokio::test]
+async fn leaves_the_signature_of_the_handler_unchanged() {
+    let response = get_user(Path(7), Query(Page { page: 2 })).await;
+    assert_eq!(response, "user 7, page 2");
+
+    let response = update_user(Path(7), Json(json!({ "name": "Ada" }))).await;
+    assert_eq!(response, "user 7: \"Ada\"");
+}
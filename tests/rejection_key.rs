@@ -0,0 +1,47 @@
+use axum::Json;
+use axum::extract::rejection::PathRejection;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum_distributed_routing::{TestClient, route, route_group};
+use serde_json::{Value, json};
+
+route_group!(Routes, ());
+
+/// Reports extraction failures as `{ "error": ... }`
+struct ApiError(String);
+
+impl From<PathRejection> for ApiError {
+    fn from(rejection: PathRejection) -> Self {
+        ApiError(rejection.body_text())
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (StatusCode::BAD_REQUEST, Json(json!({ "error": self.0 }))).into_response()
+    }
+}
+
+route!(
+    group = Routes,
+    path = "/users/{id:i32}",
+    method = GET,
+    rejection = ApiError,
+    async get_user -> String { id.to_string() }
+);
+
+#[tokio::test]
+async fn extracts_valid_parameters() {
+    let response = TestClient::new::<Routes>(()).get("/users/-7").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "-7");
+}
+
+#[tokio::test]
+async fn reports_invalid_parameters_with_the_rejection_type() {
+    let response = TestClient::new::<Routes>(()).get("/users/abc").await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = response.json::<Value>();
+    let error = body["error"].as_str().unwrap();
+    assert!(error.contains("Cannot parse"), "{error}");
+}
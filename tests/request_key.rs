@@ -0,0 +1,31 @@
+use axum::http::StatusCode;
+use axum::http::header::CONTENT_TYPE;
+use axum_distributed_routing::{TestClient, route, route_group};
+
+route_group!(Routes, ());
+
+route!(
+    group = Routes,
+    path = "/webhook/{source:String}",
+    method = POST,
+    request,
+    async webhook -> String {
+        let content_type = request.headers()[CONTENT_TYPE].to_str().unwrap().to_owned();
+        let body = axum::body::to_bytes(request.into_body(), usize::MAX).await.unwrap();
+        format!("{source} {content_type} {body:?}")
+    }
+);
+
+#[tokio::test]
+async fn binds_the_whole_request_with_its_raw_body() {
+    let response = TestClient::new::<Routes>(())
+        .post("/webhook/github")
+        .header(CONTENT_TYPE, "application/octet-stream")
+        .body(vec![0x00, b'h', b'i', 0xff])
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.text(),
+        r#"github application/octet-stream b"\0hi\xff""#
+    );
+}
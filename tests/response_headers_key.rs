@@ -0,0 +1,48 @@
+use axum::http::header::{CACHE_CONTROL, VARY, X_FRAME_OPTIONS};
+use axum::response::IntoResponse;
+use axum_distributed_routing::{TestClient, route, route_group};
+
+route_group!(Routes, ());
+
+route!(
+    group = Routes,
+    path = "/account",
+    method = GET,
+    response_headers = {
+        "Cache-Control": "no-store",
+        "X-Frame-Options": "DENY",
+        "Vary": append "Accept",
+    },
+    async account -> impl IntoResponse {
+        ([(CACHE_CONTROL, "public"), (VARY, "Cookie")], "account")
+    }
+);
+
+route!(
+    group = Routes,
+    path = "/public",
+    method = GET,
+    async public -> &'static str { "public" }
+);
+
+#[tokio::test]
+async fn sets_the_headers_of_the_route() {
+    let response = TestClient::new::<Routes>(()).get("/account").await;
+    assert_eq!(response.header(CACHE_CONTROL.as_str()), Some("no-store"));
+    assert_eq!(response.header(X_FRAME_OPTIONS.as_str()), Some("DENY"));
+    let vary = response
+        .headers()
+        .get_all(VARY)
+        .iter()
+        .map(|value| value.to_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(vary, ["Cookie", "Accept"]);
+}
+
+#[tokio::test]
+async fn leaves_sibling_routes_alone() {
+    let response = TestClient::new::<Routes>(()).get("/public").await;
+    assert_eq!(response.header(CACHE_CONTROL.as_str()), None);
+    assert_eq!(response.header(X_FRAME_OPTIONS.as_str()), None);
+    assert_eq!(response.header(VARY.as_str()), None);
+}
@@ -0,0 +1,61 @@
+use axum_distributed_routing::{Route, raw_routes, route, route_group};
+
+route_group!(Routes, ());
+route_group!(Api, (), Routes, "/api");
+
+route!(
+    group = Api,
+    path = "/users",
+    method = GET,
+    summary = "List users",
+    description = "Lists every user of the organization",
+    tags = ["users", "admin"],
+    async list_users -> &'static str { "users" }
+);
+
+route!(
+    group = Api,
+    path = "/health",
+    method = GET,
+    /// Checks that the service is up
+    async health -> &'static str { "ok" }
+);
+
+#[test]
+fn exposes_the_metadata_of_a_route() {
+    assert_eq!(ROUTE_LIST_USERS.summary(), Some("List users"));
+    assert_eq!(
+        ROUTE_LIST_USERS.description(),
+        Some("Lists every user of the organization")
+    );
+    assert_eq!(ROUTE_LIST_USERS.tags(), ["users", "admin"]);
+}
+
+#[test]
+fn describes_a_route_with_its_doc_comment() {
+    assert_eq!(ROUTE_HEALTH.summary(), None);
+    assert_eq!(
+        ROUTE_HEALTH.description(),
+        Some("Checks that the service is up")
+    );
+    assert!(ROUTE_HEALTH.tags().is_empty());
+}
+
+#[test]
+fn lists_the_metadata_of_a_group() {
+    let mut table = raw_routes!(Api)
+        .map(|route| {
+            format!(
+                "{} {} {:?}",
+                route.path(),
+                route.summary().unwrap_or_default(),
+                route.tags()
+            )
+        })
+        .collect::<Vec<_>>();
+    table.sort();
+    assert_eq!(
+        table,
+        ["/health  []", "/users List users [\"users\", \"admin\"]"]
+    );
+}
@@ -0,0 +1,54 @@
+use axum::Router;
+use axum_distributed_routing::{Route, raw_routes, route, route_group};
+
+route_group!(Routes, ());
+route_group!(Api, (), Routes, "/api");
+
+route!(
+    group = Api,
+    path = "/health",
+    method = GET,
+    async api_health -> &'static str { "ok" }
+);
+
+route!(
+    group = Api,
+    path = "/version",
+    method = GET,
+    async version -> &'static str { "1.0.0" }
+);
+
+#[test]
+fn finds_a_route_by_name() {
+    let route = raw_routes!(Api)
+        .find(|route| route.name() == "api_health")
+        .unwrap();
+    assert_eq!(route.path(), "/health");
+}
+
+#[test]
+fn names_nested_groups_after_their_type() {
+    let api = raw_routes!(Routes)
+        .find(|route| route.path() == "/api")
+        .unwrap();
+    assert_eq!(api.name(), "Api");
+}
+
+struct Manual;
+
+impl Route for Manual {
+    type State = ();
+
+    fn attach(&self, router: Router, _level: usize) -> Router {
+        router
+    }
+
+    fn path(&self) -> &'static str {
+        "/manual"
+    }
+}
+
+#[test]
+fn defaults_the_name_to_the_path() {
+    assert_eq!(Manual.name(), "/manual");
+}
@@ -0,0 +1,35 @@
+use std::convert::Infallible;
+use std::time::Duration;
+
+use axum::http::StatusCode;
+use axum::http::header::CONTENT_TYPE;
+use axum::response::sse::Event;
+use axum_distributed_routing::{TestClient, route, route_group};
+use futures_util::StreamExt;
+
+route_group!(Routes, ());
+
+route!(
+    group = Routes,
+    path = "/ticks/{count:usize}",
+    method = GET,
+    keep_alive = Duration::from_secs(5),
+    sse ticks {
+        futures_util::stream::iter(0..count)
+            .map(|i| Ok::<_, Infallible>(Event::default().event("tick").data(i.to_string())))
+    }
+);
+
+#[tokio::test]
+async fn streams_events() {
+    let response = TestClient::new::<Routes>(()).get("/ticks/2").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.header(CONTENT_TYPE.as_str()),
+        Some("text/event-stream")
+    );
+    assert_eq!(
+        response.text(),
+        "event: tick\ndata: 0\n\nevent: tick\ndata: 1\n\n"
+    );
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use axum::extract::State;
+use axum_distributed_routing::{TestClient, route, route_group};
+
+#[derive(Clone, Default)]
+struct AppState {
+    hits: Arc<AtomicUsize>,
+}
+
+route_group!(Routes, AppState);
+
+route!(
+    group = Routes,
+    path = "/hits",
+    method = POST,
+    state,
+    async hit -> String {
+        (state.hits.fetch_add(1, Ordering::SeqCst) + 1).to_string()
+    }
+);
+
+route!(
+    group = Routes,
+    path = "/count",
+    method = GET,
+    state = app,
+    async count -> String { app.hits.load(Ordering::SeqCst).to_string() }
+);
+
+#[tokio::test]
+async fn reads_a_counter_from_the_state() {
+    let state = AppState::default();
+    let client = TestClient::new::<Routes>(state.clone());
+
+    assert_eq!(client.post("/hits").await.text(), "1");
+    assert_eq!(client.post("/hits").await.text(), "2");
+    assert_eq!(client.get("/count").await.text(), "2");
+    assert_eq!(state.hits.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn binds_the_state_by_name() {
+    let state = AppState::default();
+    state.hits.store(7, Ordering::SeqCst);
+    assert_eq!(count(State(state)).await, "7");
+}
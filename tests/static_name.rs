@@ -0,0 +1,78 @@
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, full_paths, route, route_group, uri};
+
+route_group!(Routes, ());
+
+mod users {
+    pub async fn health() -> &'static str {
+        "users"
+    }
+}
+
+mod orders {
+    pub async fn health() -> &'static str {
+        "orders"
+    }
+}
+
+route!(
+    group = Routes,
+    path = "/users/health",
+    method = GET,
+    handler = users::health,
+    static_name = USERS_HEALTH_ROUTE,
+);
+
+route!(
+    group = Routes,
+    path = "/orders/health",
+    method = GET,
+    handler = orders::health,
+    static_name = ORDERS_HEALTH_ROUTE,
+);
+
+#[tokio::test]
+async fn registers_handlers_of_the_same_name() {
+    let client = TestClient::new::<Routes>(());
+
+    let response = client.get("/users/health").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "users");
+
+    let response = client.get("/orders/health").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "orders");
+}
+
+#[test]
+fn names_the_statics_and_url_builders_after_the_override() {
+    let mut paths: Vec<_> = full_paths::<Routes>()
+        .into_iter()
+        .map(|(path, _, _)| path)
+        .collect();
+    paths.sort();
+    assert_eq!(paths, ["/orders/health", "/users/health"]);
+
+    assert_eq!(users_health_route_url(), "/users/health");
+    assert_eq!(orders_health_route_url(), "/orders/health");
+    assert_eq!(uri!(USERS_HEALTH_ROUTE), "/users/health");
+    assert_eq!(uri!(ORDERS_HEALTH_ROUTE), "/orders/health");
+}
+
+#[cfg(feature = "client")]
+#[tokio::test]
+async fn names_the_client_functions_after_the_override() {
+    use axum_distributed_routing::{Client, create_router};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, create_router!(Routes)).await.unwrap();
+    });
+
+    let client = Client::new(format!("http://{address}"));
+    let response = call_users_health_route(&client).await.unwrap();
+    assert_eq!(response.text().await.unwrap(), "users");
+    let response = call_orders_health_route(&client).await.unwrap();
+    assert_eq!(response.text().await.unwrap(), "orders");
+}
@@ -0,0 +1,55 @@
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, assert_route_exists, route_group, static_response};
+
+route_group!(Routes, ());
+
+static_response!(
+    group = Routes,
+    path = "/robots.txt",
+    content_type = "text/plain",
+    body = "User-agent: *\nDisallow:\n",
+    cache_control = "public, max-age=86400"
+);
+static_response!(
+    group = Routes,
+    path = "/favicon.ico",
+    content_type = "image/x-icon",
+    body = b"\x00\x00\x01\x00"
+);
+
+#[tokio::test]
+async fn serves_a_text_body() {
+    let response = TestClient::new::<Routes>(()).get("/robots.txt").await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.header("content-type"), Some("text/plain"));
+    assert_eq!(
+        response.header("cache-control"),
+        Some("public, max-age=86400")
+    );
+    assert_eq!(response.text(), "User-agent: *\nDisallow:\n");
+}
+
+#[tokio::test]
+async fn serves_a_binary_body() {
+    let response = TestClient::new::<Routes>(()).get("/favicon.ico").await;
+
+    assert_eq!(response.header("content-type"), Some("image/x-icon"));
+    assert_eq!(response.header("cache-control"), None);
+    assert_eq!(&response.bytes()[..], b"\x00\x00\x01\x00");
+}
+
+#[tokio::test]
+async fn answers_head_without_a_body() {
+    let response = TestClient::new::<Routes>(()).head("/robots.txt").await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.header("content-length"), Some("24"));
+    assert!(response.bytes().is_empty());
+}
+
+#[test]
+fn registers_get_routes() {
+    assert_route_exists!(Routes, GET "/robots.txt");
+    assert_route_exists!(Routes, GET "/favicon.ico");
+}
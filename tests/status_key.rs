@@ -0,0 +1,105 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, route, route_group};
+use serde::{Deserialize, Serialize};
+
+route_group!(Routes, ());
+
+#[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "aide", derive(schemars::JsonSchema))]
+struct User {
+    name: String,
+}
+
+route!(
+    group = Routes,
+    path = "/users",
+    method = POST,
+    body = Json<User>,
+    status = 201,
+    async create_user -> Json<User> { body }
+);
+
+route!(
+    group = Routes,
+    path = "/jobs",
+    method = POST,
+    status = ACCEPTED,
+    error = StatusCode,
+    async create_job -> &'static str {
+        Err(StatusCode::SERVICE_UNAVAILABLE)
+    }
+);
+
+route!(
+    group = Routes,
+    path = "/users/{name:String}",
+    method = PUT,
+    status = 201,
+    async put_user -> (StatusCode, Json<User>) {
+        // The user already exists
+        (StatusCode::OK, Json(User { name }))
+    }
+);
+
+route!(
+    group = Routes,
+    path = "/accounts",
+    method = POST,
+    body = Json<User>,
+    status = 201,
+    async create_account -> Result<Json<User>, StatusCode> {
+        if body.name.is_empty() {
+            return Err(StatusCode::BAD_REQUEST);
+        }
+        Ok(body)
+    }
+);
+
+#[tokio::test]
+async fn responds_with_the_declared_status() {
+    let response = TestClient::new::<Routes>(())
+        .post("/users")
+        .json(&User {
+            name: "alice".to_owned(),
+        })
+        .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_eq!(response.text(), r#"{"name":"alice"}"#);
+}
+
+#[tokio::test]
+async fn responds_with_the_declared_status_when_the_error_is_a_status_code() {
+    let client = TestClient::new::<Routes>(());
+
+    let response = client
+        .post("/accounts")
+        .json(&User {
+            name: "alice".to_owned(),
+        })
+        .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_eq!(response.text(), r#"{"name":"alice"}"#);
+
+    let response = client
+        .post("/accounts")
+        .json(&User {
+            name: String::new(),
+        })
+        .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn keeps_the_status_of_errors() {
+    let response = TestClient::new::<Routes>(()).post("/jobs").await;
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+}
+
+#[tokio::test]
+async fn keeps_the_status_returned_by_the_handler() {
+    let response = TestClient::new::<Routes>(()).put("/users/alice").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), r#"{"name":"alice"}"#);
+}
@@ -0,0 +1,141 @@
+use axum::Json;
+use axum::extract::Form;
+use axum::http::{Method, StatusCode};
+use axum::response::IntoResponse;
+use axum_distributed_routing::{TestClient, create_router, route, route_group};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone)]
+struct AppState {
+    greeting: &'static str,
+}
+
+route_group!(Routes, AppState);
+route_group!(Api, AppState, Routes, "/api");
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+struct User {
+    name: String,
+}
+
+route!(
+    group = Api,
+    path = "/greet/{name:String}",
+    method = GET,
+    state,
+    async greet -> String { format!("{}, {name}", state.greeting) }
+);
+
+route!(
+    group = Api,
+    path = "/users",
+    method = POST,
+    body = Json<User>,
+    async create_user -> impl IntoResponse { (StatusCode::CREATED, body) }
+);
+
+route!(
+    group = Api,
+    path = "/login",
+    method = POST,
+    body = Form<User>,
+    aide = false,
+    async login -> String { body.0.name }
+);
+
+route!(
+    group = Api,
+    path = "/agent",
+    method = GET,
+    parts = [headers: HeaderMap],
+    async agent -> String {
+        headers["user-agent"].to_str().unwrap().to_owned()
+    }
+);
+
+fn client() -> TestClient {
+    TestClient::new::<Routes>(AppState { greeting: "Hello" })
+}
+
+#[tokio::test]
+async fn calls_the_router_of_the_group_with_its_state() {
+    let response = client().get("/api/greet/world").await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "Hello, world");
+    assert_eq!(
+        response.header("content-type"),
+        Some("text/plain; charset=utf-8")
+    );
+}
+
+#[tokio::test]
+async fn sends_json_bodies() {
+    let user = User {
+        name: "alice".into(),
+    };
+    let response = client().post("/api/users").json(&user).await;
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_eq!(response.json::<User>(), user);
+}
+
+#[tokio::test]
+async fn sends_form_bodies() {
+    let user = User { name: "bob".into() };
+    let response = client().post("/api/login").form(&user).await;
+
+    assert_eq!(response.text(), "bob");
+}
+
+#[tokio::test]
+async fn sends_headers() {
+    let response = client()
+        .get("/api/agent")
+        .header("user-agent", "tests")
+        .await;
+
+    assert_eq!(response.text(), "tests");
+}
+
+#[tokio::test]
+async fn reports_unmatched_requests() {
+    let client = client();
+
+    assert_eq!(client.get("/missing").await.status(), StatusCode::NOT_FOUND);
+    assert_eq!(
+        client.request(Method::PUT, "/api/users").await.status(),
+        StatusCode::METHOD_NOT_ALLOWED
+    );
+    assert_eq!(
+        client.post("/api/users").body("{").await.status(),
+        StatusCode::UNSUPPORTED_MEDIA_TYPE
+    );
+}
+
+#[tokio::test]
+async fn calls_custom_routers() {
+    let router = create_router!(Routes)
+        .with_state(AppState { greeting: "Hi" })
+        .layer(axum::middleware::map_response(
+            |mut response: axum::response::Response| async move {
+                response
+                    .headers_mut()
+                    .insert("x-layer", "applied".parse().unwrap());
+                response
+            },
+        ));
+    let response = TestClient::from_router(router)
+        .get("/api/greet/there")
+        .await;
+
+    assert_eq!(response.text(), "Hi, there");
+    assert_eq!(response.header("x-layer"), Some("applied"));
+}
+
+#[tokio::test]
+#[should_panic(expected = "the response body is not valid JSON")]
+async fn panics_on_invalid_json_responses() {
+    client().get("/api/greet/world").await.json::<User>();
+}
@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, route, route_group, traced_router};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+/// Records the fields of the spans created while it is the default
+/// subscriber, in creation order
+#[derive(Clone, Default)]
+struct Spans(Arc<Mutex<Vec<HashMap<String, String>>>>);
+
+struct Fields<'a>(&'a mut HashMap<String, String>);
+
+impl Visit for Fields<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_owned(), value.to_owned());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0.insert(field.name().to_owned(), format!("{value:?}"));
+    }
+}
+
+impl Subscriber for Spans {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut spans = self.0.lock().unwrap();
+        let mut fields = HashMap::new();
+        span.record(&mut Fields(&mut fields));
+        spans.push(fields);
+        Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, span: &Id, values: &Record<'_>) {
+        let mut spans = self.0.lock().unwrap();
+        values.record(&mut Fields(&mut spans[span.into_u64() as usize - 1]));
+    }
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, _: &Event<'_>) {}
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+route_group!(Routes, ());
+route_group!(Api, (), Routes, "/api");
+
+route!(
+    group = Api,
+    path = "/health",
+    method = GET,
+    async api_health -> &'static str { "ok" }
+);
+
+route!(
+    group = Api,
+    path = "/users/{id:u64}",
+    method = GET,
+    async api_get_user -> Result<String, StatusCode> {
+        match id {
+            0 => Err(StatusCode::INTERNAL_SERVER_ERROR),
+            id => Ok(id.to_string()),
+        }
+    }
+);
+
+#[tokio::test]
+async fn names_the_span_after_the_route() {
+    let spans = Spans::default();
+    let _guard = tracing::subscriber::set_default(spans.clone());
+
+    let response = TestClient::from_router(traced_router!(Routes))
+        .get("/api/health")
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // The span of the request is the first one, the `tracing` feature
+    // creating the span of the handler within it
+    let spans = spans.0.lock().unwrap();
+    assert_eq!(spans[0]["otel.name"], "api_health /api/health");
+    assert_eq!(spans[0]["http.route"], "/api/health");
+    assert_eq!(spans[0]["http.status_code"], "200");
+    assert!(!spans[0].contains_key("otel.status_code"));
+}
+
+#[tokio::test]
+async fn names_the_span_after_the_path_template() {
+    let spans = Spans::default();
+    let _guard = tracing::subscriber::set_default(spans.clone());
+
+    let response = TestClient::from_router(traced_router!(Routes))
+        .get("/api/users/42")
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let spans = spans.0.lock().unwrap();
+    assert_eq!(spans[0]["otel.name"], "api_get_user /api/users/{id}");
+    assert_eq!(spans[0]["http.route"], "/api/users/{id}");
+}
+
+#[tokio::test]
+async fn marks_server_errors_as_failed() {
+    let spans = Spans::default();
+    let _guard = tracing::subscriber::set_default(spans.clone());
+
+    let response = TestClient::from_router(traced_router!(Routes))
+        .get("/api/users/0")
+        .await;
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+    let spans = spans.0.lock().unwrap();
+    assert_eq!(spans[0]["otel.name"], "api_get_user /api/users/{id}");
+    assert_eq!(spans[0]["otel.status_code"], "ERROR");
+    assert_eq!(spans[0]["http.status_code"], "500");
+}
+
+#[tokio::test]
+async fn names_unmatched_requests_after_their_method() {
+    let spans = Spans::default();
+    let _guard = tracing::subscriber::set_default(spans.clone());
+
+    let response = TestClient::from_router(traced_router!(Routes))
+        .get("/api/missing")
+        .await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let spans = spans.0.lock().unwrap();
+    assert_eq!(spans[0]["otel.name"], "GET");
+    assert!(!spans[0].contains_key("http.route"));
+}
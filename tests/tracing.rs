@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, route, route_group};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+/// The name and fields of a span
+type SpanFields = (&'static str, HashMap<String, String>);
+
+/// Records the name and fields of the spans created while it is the default
+/// subscriber
+#[derive(Clone, Default)]
+struct Spans {
+    next_id: Arc<AtomicU64>,
+    spans: Arc<Mutex<HashMap<u64, SpanFields>>>,
+}
+
+impl Spans {
+    fn named(&self, name: &str) -> Vec<HashMap<String, String>> {
+        let spans = self.spans.lock().unwrap();
+        let mut ids = spans
+            .iter()
+            .filter(|(_, (span, _))| *span == name)
+            .map(|(id, _)| *id)
+            .collect::<Vec<_>>();
+        ids.sort();
+        ids.into_iter().map(|id| spans[&id].1.clone()).collect()
+    }
+}
+
+struct Fields<'a>(&'a mut HashMap<String, String>);
+
+impl Visit for Fields<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_owned(), value.to_owned());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0.insert(field.name().to_owned(), format!("{value:?}"));
+    }
+}
+
+impl Subscriber for Spans {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed) + 1;
+        let mut fields = HashMap::new();
+        span.record(&mut Fields(&mut fields));
+        self.spans
+            .lock()
+            .unwrap()
+            .insert(id, (span.metadata().name(), fields));
+        Id::from_u64(id)
+    }
+
+    fn record(&self, span: &Id, values: &Record<'_>) {
+        if let Some((_, fields)) = self.spans.lock().unwrap().get_mut(&span.into_u64()) {
+            values.record(&mut Fields(fields));
+        }
+    }
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, _: &Event<'_>) {}
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+route_group!(Routes, ());
+
+route!(
+    group = Routes,
+    path = "/users/{id:u64}",
+    method = GET,
+    async get_user -> String { id.to_string() }
+);
+
+route!(
+    group = Routes,
+    path = "/orders/{id:u64}",
+    method = DELETE,
+    async delete_order -> StatusCode { StatusCode::NOT_FOUND }
+);
+
+route!(
+    group = Routes,
+    path = "/health",
+    method = GET,
+    instrument = false,
+    async health -> &'static str { "ok" }
+);
+
+#[tokio::test]
+async fn runs_the_handler_in_a_span_named_after_it() {
+    let spans = Spans::default();
+    let _guard = tracing::subscriber::set_default(spans.clone());
+
+    let response = TestClient::new::<Routes>(()).get("/users/42").await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let recorded = spans.named("get_user");
+    assert_eq!(recorded.len(), 1);
+    let fields = &recorded[0];
+    assert_eq!(fields["http.method"], "GET");
+    assert_eq!(fields["http.route"], "/users/{id}");
+    assert_eq!(fields["id"], "42");
+    assert_eq!(fields["http.status_code"], "200");
+}
+
+#[tokio::test]
+async fn records_the_status_returned_by_the_handler() {
+    let spans = Spans::default();
+    let _guard = tracing::subscriber::set_default(spans.clone());
+
+    let response = TestClient::new::<Routes>(()).delete("/orders/7").await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let recorded = spans.named("delete_order");
+    assert_eq!(recorded.len(), 1);
+    assert_eq!(recorded[0]["http.route"], "/orders/{id}");
+    assert_eq!(recorded[0]["id"], "7");
+    assert_eq!(recorded[0]["http.status_code"], "404");
+}
+
+#[tokio::test]
+async fn skips_the_span_of_routes_opting_out() {
+    let spans = Spans::default();
+    let _guard = tracing::subscriber::set_default(spans.clone());
+
+    let response = TestClient::new::<Routes>(()).get("/health").await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    assert!(spans.named("health").is_empty());
+}
@@ -0,0 +1,30 @@
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, route, route_group};
+
+route_group!(Merged, (), trailing_slash = merge);
+route_group!(Redirected, (), trailing_slash = redirect);
+
+route!(group = Merged, path = "/users", method = GET, async list_users -> &'static str { "list" });
+route!(group = Merged, path = "/users", method = POST, async create_user -> &'static str { "create" });
+
+route!(group = Redirected, path = "/users", method = GET, async list_redirected_users -> &'static str { "list" });
+route!(group = Redirected, path = "/users", method = POST, async create_redirected_user -> &'static str { "create" });
+
+#[tokio::test]
+async fn serves_both_methods_with_a_trailing_slash() {
+    let client = TestClient::new::<Merged>(());
+
+    assert_eq!(client.get("/users/").await.text(), "list");
+    assert_eq!(client.post("/users/").await.text(), "create");
+}
+
+#[tokio::test]
+async fn redirects_both_methods_of_a_path_once() {
+    let client = TestClient::new::<Redirected>(());
+
+    for response in [client.get("/users/").await, client.post("/users/").await] {
+        assert_eq!(response.status(), StatusCode::PERMANENT_REDIRECT);
+        assert_eq!(response.header("location"), Some("/users"));
+    }
+    assert_eq!(client.post("/users").await.text(), "create");
+}
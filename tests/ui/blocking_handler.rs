@@ -0,0 +1,9 @@
+use axum_distributed_routing::{route, route_group};
+
+route_group!(Routes, ());
+
+async fn thumbnail() {}
+
+route!(group = Routes, path = "/thumbnails", method = POST, blocking, handler = thumbnail);
+
+fn main() {}
@@ -0,0 +1,13 @@
+use axum_distributed_routing::{route, route_group};
+
+route_group!(Routes, ());
+
+route!(
+    group = Routes,
+    path = "/notes",
+    method = POST,
+    body = String,
+    async create_note(axum::Json(note): axum::Json<String>) -> String { format!("{body} {note}") }
+);
+
+fn main() {}
@@ -0,0 +1,10 @@
+use axum::extract::Path;
+use axum_distributed_routing::{crud_routes, route_group};
+
+route_group!(Routes, ());
+
+async fn get_widget(Path(_id): Path<u64>) {}
+
+crud_routes!(group = Routes, path = "/widgets", get = get_widget);
+
+fn main() {}
@@ -0,0 +1,17 @@
+use axum_distributed_routing::{route, route_group};
+
+route_group!(Routes, ());
+
+async fn list() -> &'static str {
+    "users"
+}
+
+route!(
+    group = Routes,
+    path = "/users",
+    method = GET,
+    handler = list,
+    async users -> &'static str { "users" }
+);
+
+fn main() {}
@@ -0,0 +1,22 @@
+use axum_distributed_routing::{route, route_group};
+
+route_group!(Routes, ());
+
+#[derive(serde::Deserialize)]
+struct Filter {
+    name: String,
+}
+
+async fn list() -> &'static str {
+    "users"
+}
+
+route!(
+    group = Routes,
+    path = "/users",
+    method = GET,
+    query = Filter,
+    handler = list
+);
+
+fn main() {}
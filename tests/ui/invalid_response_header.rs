@@ -0,0 +1,13 @@
+use axum_distributed_routing::{route, route_group};
+
+route_group!(Routes, ());
+
+route!(
+    group = Routes,
+    path = "/account",
+    method = GET,
+    response_headers = { "Cache Control": "no-store" },
+    async account -> &'static str { "account" }
+);
+
+fn main() {}
@@ -0,0 +1,7 @@
+use axum_distributed_routing::{route, route_group};
+
+route_group!(Routes, ());
+
+route!(group = Routes, path = "/ping", method = GET);
+
+fn main() {}
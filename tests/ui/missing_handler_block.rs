@@ -0,0 +1,12 @@
+use axum_distributed_routing::{route, route_group};
+
+route_group!(Routes, ());
+
+route!(
+    group = Routes,
+    path = "/ping",
+    method = GET,
+    async ping -> &'static str
+);
+
+fn main() {}
@@ -0,0 +1,8 @@
+use axum_distributed_routing::{mount_group, route_group};
+
+route_group!(Routes, ());
+route_group!(Billing, ());
+
+mount_group!(Routes, "/billing/{id:u64}", Billing, state = ());
+
+fn main() {}
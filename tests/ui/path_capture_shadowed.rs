@@ -0,0 +1,20 @@
+use axum_distributed_routing::{route, route_group};
+use serde::Deserialize;
+
+#[derive(Clone)]
+struct AppState;
+
+#[derive(Deserialize)]
+struct Page {
+    page: u32,
+}
+
+route_group!(Routes, AppState);
+
+route!(group = Routes, path = "/{query:u32}", method = GET, query = Page, async by_query {});
+
+route!(group = Routes, path = "/{body:u32}", method = POST, body = String, async by_body {});
+
+route!(group = Routes, path = "/{state:u32}", method = GET, state, async by_state {});
+
+fn main() {}
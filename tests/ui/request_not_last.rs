@@ -0,0 +1,14 @@
+use axum_distributed_routing::{route, route_group};
+
+route_group!(Routes, ());
+
+route!(
+    group = Routes,
+    path = "/webhook",
+    method = POST,
+    async webhook(request: axum::extract::Request, method: axum::http::Method) -> String {
+        format!("{method} {}", request.uri())
+    }
+);
+
+fn main() {}
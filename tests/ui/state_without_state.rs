@@ -0,0 +1,13 @@
+use axum_distributed_routing::{route, route_group};
+
+route_group!(Routes, ());
+
+route!(
+    group = Routes,
+    path = "/count",
+    method = GET,
+    state,
+    async count -> String { format!("{state:?}") }
+);
+
+fn main() {}
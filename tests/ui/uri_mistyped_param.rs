@@ -0,0 +1,9 @@
+use axum_distributed_routing::{route, route_group, uri};
+
+route_group!(Routes, ());
+
+route!(group = Routes, path = "/users/{id:u64}", method = GET, async get_user {});
+
+fn main() {
+    uri!(ROUTE_GET_USER, id = "42");
+}
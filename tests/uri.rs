@@ -0,0 +1,92 @@
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, route, route_group, uri};
+use serde::{Deserialize, Serialize};
+
+route_group!(Routes, ());
+route_group!(Api, (), Routes, "/api");
+route_group!(Orgs, (), Api, "/orgs/{org_id:u64}");
+
+#[derive(Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "utoipa",
+    derive(utoipa::IntoParams),
+    into_params(parameter_in = Query)
+)]
+#[cfg_attr(feature = "aide", derive(schemars::JsonSchema))]
+struct Filter {
+    sort: String,
+}
+
+mod users {
+    use axum_distributed_routing::route;
+
+    use super::{Api, Filter};
+
+    route!(
+        group = Api,
+        path = "/users/{id:u64}/posts",
+        method = GET,
+        query = Filter,
+        async user_posts -> String { format!("posts of {id} by {}", query.sort) }
+    );
+}
+
+route!(group = Routes, path = "/health", method = GET, async health {});
+
+route!(
+    group = Orgs,
+    path = "/projects/{project_id:u64}/files/{name:String}",
+    method = GET,
+    inherit = [org_id: u64],
+    async get_file -> String { format!("{name} of project {project_id} of org {org_id}") }
+);
+
+#[test]
+fn builds_static_paths() {
+    assert_eq!(uri!(ROUTE_HEALTH), "/health");
+}
+
+#[test]
+fn takes_named_parameters_in_any_order() {
+    assert_eq!(
+        uri!(
+            ROUTE_GET_FILE,
+            org_id = 1,
+            project_id = 2,
+            name = "a b".into()
+        ),
+        "/api/orgs/1/projects/2/files/a%20b"
+    );
+    assert_eq!(
+        uri!(
+            ROUTE_GET_FILE,
+            name = "a b".into(),
+            project_id = 2,
+            org_id = 1
+        ),
+        "/api/orgs/1/projects/2/files/a%20b"
+    );
+}
+
+#[test]
+fn resolves_routes_by_path() {
+    assert_eq!(
+        uri!(users::ROUTE_USER_POSTS, id = 42; &Filter { sort: "new".into() }),
+        "/api/users/42/posts?sort=new"
+    );
+}
+
+#[tokio::test]
+async fn builds_urls_matched_by_the_router() {
+    let response = TestClient::new::<Routes>(())
+        .get(&uri!(
+            ROUTE_GET_FILE,
+            org_id = 1,
+            project_id = 2,
+            name = "a".into()
+        ))
+        .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "a of project 2 of org 1");
+}
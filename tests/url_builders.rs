@@ -0,0 +1,130 @@
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, route, route_group, versioned_group};
+use serde::{Deserialize, Serialize};
+
+route_group!(Routes, ());
+route_group!(Api, (), Routes, "/api");
+route_group!(Orgs, (), Api, "/orgs/{org_id:u64}");
+versioned_group!(Versioned, (), Routes, versions = [1, 2]);
+
+#[derive(Deserialize, Serialize)]
+#[cfg_attr(
+    feature = "utoipa",
+    derive(utoipa::IntoParams),
+    into_params(parameter_in = Query)
+)]
+#[cfg_attr(feature = "aide", derive(schemars::JsonSchema))]
+struct Filter {
+    sort: String,
+    page: Option<u32>,
+}
+
+route!(group = Routes, path = "/health", method = GET, async health {});
+
+route!(
+    group = Api,
+    path = "/files/{name:String}",
+    method = GET,
+    async get_file -> String { name }
+);
+
+route!(
+    group = Api,
+    path = "/users/{id:u64}/posts",
+    method = GET,
+    query = Filter,
+    async user_posts -> String { format!("posts of {id} by {}", query.sort) }
+);
+
+route!(
+    group = Orgs,
+    path = "/projects/{project_id:u64}",
+    method = GET,
+    inherit = [org_id: u64],
+    async get_project -> String { format!("project {project_id} of org {org_id}") }
+);
+
+route!(
+    group = Versioned,
+    versions = [1, 2],
+    path = "/users/{id:u64}",
+    method = GET,
+    async get_user -> String { id.to_string() }
+);
+
+#[test]
+fn builds_static_paths() {
+    assert_eq!(health_url(), "/health");
+}
+
+#[test]
+fn prefixes_the_paths_of_nested_groups() {
+    assert_eq!(get_project_url(1, 2), "/api/orgs/1/projects/2");
+}
+
+#[test]
+fn percent_encodes_path_parameters() {
+    assert_eq!(get_file_url("a b/c".into()), "/api/files/a%20b%2Fc");
+}
+
+#[test]
+fn serializes_the_query() {
+    let filter = Filter {
+        sort: "new & hot".into(),
+        page: Some(2),
+    };
+    assert_eq!(
+        user_posts_url(42, &filter),
+        "/api/users/42/posts?sort=new+%26+hot&page=2"
+    );
+
+    let filter = Filter {
+        sort: "new".into(),
+        page: None,
+    };
+    assert_eq!(user_posts_url(42, &filter), "/api/users/42/posts?sort=new");
+}
+
+mod admin {
+    pub async fn status() -> &'static str {
+        "admin"
+    }
+}
+
+route!(
+    group = Api,
+    path = "/admin/status",
+    method = GET,
+    handler = admin::status,
+    static_name = ADMIN_STATUS_ROUTE,
+);
+
+#[test]
+fn builds_one_url_per_version() {
+    assert_eq!(get_user_v1_url(7), "/v1/users/7");
+    assert_eq!(get_user_v2_url(7), "/v2/users/7");
+}
+
+#[tokio::test]
+async fn builds_urls_matched_by_the_router() {
+    let client = TestClient::new::<Routes>(());
+
+    let response = client.get(&get_file_url("a b".into())).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "a b");
+
+    let filter = Filter {
+        sort: "new".into(),
+        page: None,
+    };
+    let response = client.get(&user_posts_url(42, &filter)).await;
+    assert_eq!(response.text(), "posts of 42 by new");
+
+    let response = client.get(&get_user_v2_url(7)).await;
+    assert_eq!(response.text(), "7");
+}
+
+#[test]
+fn names_the_url_of_renamed_routes_after_their_static() {
+    assert_eq!(admin_status_route_url(), "/api/admin/status");
+}
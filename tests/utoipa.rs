@@ -0,0 +1,123 @@
+use axum::Json;
+use axum_distributed_routing::{Pagination, route, route_group};
+use serde::Deserialize;
+use serde_json::json;
+use utoipa::{IntoParams, OpenApi, ToSchema};
+
+route_group!(Routes, ());
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+#[cfg_attr(feature = "aide", derive(schemars::JsonSchema))]
+struct ExprQuery {
+    times: i32,
+}
+
+#[derive(Deserialize, ToSchema)]
+#[cfg_attr(feature = "aide", derive(schemars::JsonSchema))]
+struct ExprBody {
+    plus: i32,
+}
+
+route!(
+    group = Routes,
+    path = "/expr/{val:i32}",
+    method = POST,
+    query = ExprQuery,
+    body = Json<ExprBody>,
+    status = 201,
+    summary = "Evaluates an expression",
+    tags = ["math"],
+    async expr -> String {
+        (val * query.times + body.plus).to_string()
+    }
+);
+
+// Opting out, the types don't need to implement the traits of utoipa
+#[derive(Deserialize)]
+struct Undocumented {
+    plus: i32,
+}
+
+route!(
+    group = Routes,
+    path = "/undocumented",
+    method = POST,
+    body = Json<Undocumented>,
+    utoipa = false,
+    aide = false,
+    async undocumented -> String { body.plus.to_string() }
+);
+
+route!(
+    group = Routes,
+    path = "/items",
+    method = GET,
+    query = Pagination<50>,
+    async list_items -> String { query.page.to_string() }
+);
+
+#[derive(OpenApi)]
+#[openapi(paths(expr, list_items))]
+struct ApiDoc;
+
+#[test]
+fn documents_the_operation_of_a_route() {
+    let spec = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let operation = &spec["paths"]["/expr/{val}"]["post"];
+
+    assert_eq!(operation["summary"], "Evaluates an expression");
+    assert_eq!(operation["tags"], json!(["math"]));
+    let parameters = operation["parameters"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|parameter| (parameter["name"].clone(), parameter["in"].clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        parameters,
+        [
+            (json!("val"), json!("path")),
+            (json!("times"), json!("query"))
+        ]
+    );
+    assert_eq!(
+        operation["parameters"][0]["schema"],
+        json!({ "type": "integer", "format": "int32" })
+    );
+    assert_eq!(
+        operation["requestBody"]["content"]["application/json"]["schema"]["$ref"],
+        "#/components/schemas/ExprBody"
+    );
+    assert!(operation["responses"]["201"].is_object());
+}
+
+#[tokio::test]
+async fn serves_the_routes_opting_out() {
+    let spec = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    assert!(spec["paths"]["/undocumented"].is_null());
+
+    let response = axum_distributed_routing::TestClient::new::<Routes>(())
+        .post("/undocumented")
+        .json(&json!({ "plus": 3 }))
+        .await;
+    assert_eq!(response.text(), "3");
+}
+
+#[test]
+fn documents_pagination_as_its_parameters() {
+    let spec = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let parameters = spec["paths"]["/items"]["get"]["parameters"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|parameter| (parameter["name"].clone(), parameter["in"].clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        parameters,
+        [
+            (json!("page"), json!("query")),
+            (json!("per_page"), json!("query"))
+        ]
+    );
+}
@@ -0,0 +1,70 @@
+use axum::Json;
+use axum::http::StatusCode;
+use axum_distributed_routing::{TestClient, route, route_group};
+use serde_json::{Value, json};
+use validator::Validate;
+
+route_group!(Routes, ());
+
+#[derive(serde::Deserialize, Validate)]
+struct CreateUser {
+    #[validate(email)]
+    email: String,
+}
+
+#[derive(serde::Deserialize, Validate)]
+struct Search {
+    #[validate(length(min = 3))]
+    term: String,
+}
+
+route!(
+    group = Routes,
+    path = "/users",
+    method = POST,
+    body = Json<CreateUser>,
+    validate,
+    async create_user -> String { body.0.email }
+);
+
+route!(
+    group = Routes,
+    path = "/search",
+    method = GET,
+    query = Search,
+    validate,
+    async search -> String { query.term }
+);
+
+#[tokio::test]
+async fn runs_the_handler_with_a_valid_body() {
+    let response = TestClient::new::<Routes>(())
+        .post("/users")
+        .json(&json!({ "email": "ada@example.com" }))
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "ada@example.com");
+}
+
+#[tokio::test]
+async fn rejects_an_invalid_body_with_the_errors() {
+    let response = TestClient::new::<Routes>(())
+        .post("/users")
+        .json(&json!({ "email": "ada" }))
+        .await;
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let errors = response.json::<Value>();
+    assert_eq!(errors["email"][0]["code"], "email");
+}
+
+#[tokio::test]
+async fn validates_the_query() {
+    let client = TestClient::new::<Routes>(());
+
+    let response = client.get("/search?term=rust").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.text(), "rust");
+
+    let response = client.get("/search?term=rs").await;
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+}
@@ -0,0 +1,37 @@
+use axum_distributed_routing::{create_router, route, route_group};
+use futures_util::{SinkExt, StreamExt};
+use tokio_tungstenite::tungstenite::Message;
+
+route_group!(Routes, ());
+
+route!(
+    group = Routes,
+    path = "/echo/{prefix:String}",
+    method = GET,
+    aide = false,
+    ws echo(socket) {
+        while let Some(Ok(msg)) = socket.recv().await {
+            let reply = match msg {
+                axum::extract::ws::Message::Text(text) => format!("{prefix}: {}", text.as_str()),
+                _ => continue,
+            };
+            if socket.send(reply.into()).await.is_err() {
+                break;
+            }
+        }
+    }
+);
+
+#[tokio::test]
+async fn echoes_a_message_over_a_websocket() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, create_router!(Routes)).await });
+
+    let (mut socket, _) = tokio_tungstenite::connect_async(format!("ws://{addr}/echo/bot"))
+        .await
+        .unwrap();
+    socket.send(Message::text("hello")).await.unwrap();
+    let reply = socket.next().await.unwrap().unwrap();
+    assert_eq!(reply, Message::text("bot: hello"));
+}